@@ -1,25 +1,44 @@
 //! Types and abstractions for performing semantic analysis of ink! smart contract code.
 
 mod actions;
+mod call_hierarchy;
+mod code_lens;
 mod completions;
+mod definition;
 mod diagnostics;
+mod document_symbols;
+mod folding_ranges;
+mod formatting;
 mod hover;
+mod implementation;
 mod inlay_hints;
+mod linked_editing;
+mod on_type_formatting;
+mod rename;
+mod semantic_tokens;
 mod signature_help;
 mod text_edit;
+mod type_definition;
 mod utils;
 
 use ink_analyzer_ir::syntax::{TextRange, TextSize};
 use ink_analyzer_ir::InkFile;
 use itertools::Itertools;
+use std::collections::HashMap;
 
-pub use actions::{Action, ActionKind};
-pub use completions::Completion;
+pub use actions::{Action, ActionKind, CodeActionContext};
+pub use call_hierarchy::{CallHierarchyIncomingCall, CallHierarchyItem};
+pub use code_lens::CodeLens;
+pub use completions::{Completion, CompletionContext, CompletionTriggerKind};
 pub use diagnostics::{Diagnostic, Severity};
+pub use document_symbols::{DocumentSymbol, SymbolKind};
+pub use folding_ranges::{FoldingRange, FoldingRangeKind};
 pub use hover::Hover;
-pub use inlay_hints::InlayHint;
+pub use inlay_hints::{InlayHint, InlayHintKind};
+pub use rename::PrepareRenameResult;
+pub use semantic_tokens::{SemanticToken, SemanticTokenModifier, SemanticTokenType};
 pub use signature_help::SignatureHelp;
-pub use text_edit::TextEdit;
+pub use text_edit::{FormatOptions, TextEdit};
 
 /// Entry point for asking for semantic information about ink! smart contract code.
 #[derive(Debug)]
@@ -46,6 +65,40 @@ impl Analysis {
         diagnostics::diagnostics(&self.file)
     }
 
+    /// Runs diagnostics for the smart contract code, and only returns diagnostics
+    /// with at least the given severity (e.g. `Severity::Error` only returns errors).
+    ///
+    /// **NOTE**: All diagnostics are currently computed before filtering,
+    /// so this is never more expensive than [`Self::diagnostics`] (plus filtering),
+    /// but it's not (yet) any cheaper either.
+    pub fn diagnostics_with_severity_filter(&self, min_severity: Severity) -> Vec<Diagnostic> {
+        diagnostics::diagnostics(&self.file)
+            .into_iter()
+            .filter(|diagnostic| diagnostic.severity >= min_severity)
+            .collect()
+    }
+
+    /// Runs cross-file diagnostics (e.g. selector compatibility of ink! trait definition implementations
+    /// across contracts) for the given `(file_id, code)` pairs.
+    ///
+    /// Returns diagnostics paired with the identifier of the file they apply to.
+    pub fn workspace_validate(sources: &[(&str, &str)]) -> Vec<(String, Diagnostic)> {
+        diagnostics::workspace_diagnostics(sources)
+    }
+
+    /// Runs per-file and cross-file diagnostics for the given `(file_id, code)` pairs.
+    ///
+    /// Returns diagnostics grouped by the identifier of the file they apply to
+    /// (see [`WorkspaceAnalysis::diagnostics`]).
+    pub fn workspace_diagnostics(files: &[(&str, &str)]) -> HashMap<String, Vec<Diagnostic>> {
+        WorkspaceAnalysis::new(files).diagnostics()
+    }
+
+    /// Prepares the context for computing ink! attribute completions at the given position.
+    pub fn prepare_completions(&self, position: TextSize) -> Option<CompletionContext> {
+        completions::prepare_completions(&self.file, position)
+    }
+
     /// Computes ink! attribute completions at the given position.
     pub fn completions(&self, position: TextSize) -> Vec<Completion> {
         completions::completions(&self.file, position)
@@ -53,28 +106,194 @@ impl Analysis {
 
     /// Computes ink! attribute code/intent actions for the given text range.
     pub fn actions(&self, range: TextRange) -> Vec<Action> {
-        // Returns quickfixes (for diagnostics) + generic code actions.
+        self.actions_with_context(&self.prepare_code_action(range))
+    }
+
+    /// Computes ink! attribute code/intent actions of the given kinds for the given text range.
+    pub fn actions_with_kinds(&self, range: TextRange, kinds: &[ActionKind]) -> Vec<Action> {
+        self.actions_with_context(&CodeActionContext {
+            only: Some(kinds.to_vec()),
+            ..self.prepare_code_action(range)
+        })
+    }
+
+    /// Returns a code/intent action context (with no diagnostics and no kind filter) for the given text range.
+    pub fn prepare_code_action(&self, range: TextRange) -> CodeActionContext {
+        CodeActionContext {
+            range,
+            diagnostics: Vec::new(),
+            only: None,
+        }
+    }
+
+    /// Computes ink! attribute code/intent actions for the given context.
+    ///
+    /// See [`CodeActionContext`] doc for details about how diagnostics and kind filters are applied.
+    pub fn actions_with_context(&self, context: &CodeActionContext) -> Vec<Action> {
+        let is_wanted_kind = |kind: ActionKind| {
+            context
+                .only
+                .as_ref()
+                .map_or(true, |kinds| kinds.contains(&kind))
+        };
+        let quickfixes = is_wanted_kind(ActionKind::QuickFix)
+            .then(|| self.quickfixes(context.range, &context.diagnostics))
+            .into_iter()
+            .flatten();
+        // Generic code actions don't address any diagnostics,
+        // so they're only computed if the context has no diagnostics.
+        let generic_actions = context
+            .diagnostics
+            .is_empty()
+            .then(|| actions::actions(&self.file, context.range))
+            .into_iter()
+            .flatten();
+        // Combines quickfixes and generic actions (with quickfixes taking priority).
+        quickfixes
+            .chain(generic_actions)
+            // Filters by action kind (if any).
+            .filter(|action| is_wanted_kind(action.kind))
+            // Deduplicate by edits.
+            .unique_by(|item| item.edits.clone())
+            // Sorts by priority (see `ActionKind` doc).
+            .sorted_by_key(|item| item.kind)
+            .collect()
+    }
+
+    /// Computes quickfixes that apply to the given text range for diagnostics that match
+    /// the given diagnostics by range (or for all diagnostics if none are given).
+    fn quickfixes(&self, range: TextRange, diagnostics: &[Diagnostic]) -> Vec<Action> {
         diagnostics::diagnostics(&self.file)
             .into_iter()
+            // Filters out diagnostics not addressed by the context (if any).
+            .filter(|diagnostic| {
+                diagnostics.is_empty() || diagnostics.iter().any(|it| it.range == diagnostic.range)
+            })
             .filter_map(|it| it.quickfixes)
             .flatten()
-            // Filters out diagnostics that apply to the given text range.
+            // Filters out quickfixes that don't apply to the given text range.
             .filter(|action| {
                 range.contains_range(action.range) || action.range.contains_range(range)
             })
-            // Combines quickfixes and generic actions (with quickfixes taking priority).
-            .chain(actions::actions(&self.file, range))
-            // Deduplicate by edits.
-            .unique_by(|item| item.edits.clone())
             .collect()
     }
 
+    /// Resolves the edits of a (lightweight) ink! attribute code/intent action (i.e. one with empty edits).
+    ///
+    /// **NOTE**: Only the pass that produces actions of the given kind is re-run
+    /// (i.e. diagnostics for quickfixes, and generic code actions for refactors),
+    /// the resolved action is then identified by its label and range,
+    /// and the given action is returned unchanged if no matching action is found.
+    pub fn code_action_resolve(&self, action: Action) -> Action {
+        let candidates = match action.kind {
+            ActionKind::QuickFix => self.quickfixes(action.range, &[]),
+            ActionKind::Refactor => actions::actions(&self.file, action.range),
+        };
+        candidates
+            .into_iter()
+            .find(|resolved| resolved.label == action.label && resolved.range == action.range)
+            .unwrap_or(action)
+    }
+
+    /// Computes formatting edits for the ink! attributes in the given text range.
+    pub fn range_format(&self, range: TextRange) -> Vec<TextEdit> {
+        formatting::range_format(&self.file, range)
+    }
+
+    /// Computes formatting edits for the entire file
+    /// (i.e. ink! attribute whitespace and indenting, and empty lines between top-level ink! items).
+    pub fn formatting(&self) -> Vec<TextEdit> {
+        formatting::format(&self.file)
+    }
+
+    /// Formats the text edit (i.e. adds indenting and new lines based on context).
+    pub fn format_edit(&self, edit: TextEdit) -> TextEdit {
+        text_edit::format_edit(edit, &self.file)
+    }
+
+    /// Formats the text edit (i.e. adds indenting and new lines based on context)
+    /// using the given formatting options.
+    pub fn format_edit_with_options(&self, edit: TextEdit, options: FormatOptions) -> TextEdit {
+        text_edit::format_edit_with_options(edit, &self.file, options)
+    }
+
+    /// Computes formatting edits (e.g. indenting inside ink! blocks)
+    /// after the given character is typed at the given position.
+    pub fn on_type_formatting(&self, position: TextSize, typed_char: char) -> Vec<TextEdit> {
+        on_type_formatting::on_type_formatting(&self.file, position, typed_char)
+    }
+
+    /// Returns the text ranges of all names that should be edited simultaneously
+    /// with the name at the given position (if any).
+    pub fn linked_editing_ranges(&self, position: TextSize) -> Option<Vec<TextRange>> {
+        linked_editing::linked_editing_ranges(&self.file, position)
+    }
+
+    /// Returns the range and current text of the name at the given position (if any)
+    /// if it can be renamed (i.e. a pre-rename check).
+    pub fn prepare_rename(&self, position: TextSize) -> Option<PrepareRenameResult> {
+        rename::prepare_rename(&self.file, position)
+    }
+
+    /// Returns the text edits for renaming the ink! entity whose name is at the given position (if any)
+    /// to the new name (including references in the same file).
+    pub fn rename(&self, position: TextSize, new_name: &str) -> Vec<TextEdit> {
+        rename::rename(&self.file, position, new_name)
+    }
+
+    /// Computes the ink! document symbol tree (i.e. an outline of ink! entities).
+    pub fn document_symbols(&self) -> Vec<DocumentSymbol> {
+        document_symbols::document_symbols(&self.file)
+    }
+
+    /// Computes ink! code lenses (i.e. ink! callable and ink! test counts).
+    pub fn code_lens(&self) -> Vec<CodeLens> {
+        code_lens::code_lens(&self.file)
+    }
+
+    /// Computes folding ranges for collapsible ink! blocks (i.e. bodies of ink! entities).
+    pub fn folding_ranges(&self) -> Vec<FoldingRange> {
+        folding_ranges::folding_ranges(&self.file)
+    }
+
+    /// Returns the text range of the definition of the ink! trait definition or ink! environment
+    /// referenced at the given position (if any).
+    pub fn goto_definition(&self, position: TextSize) -> Option<TextRange> {
+        definition::goto_definition(&self.file, position)
+    }
+
+    /// Returns the text ranges of all `impl` items (in the same file) that implement
+    /// the ink! trait definition at the given position (if any).
+    pub fn goto_implementation(&self, position: TextSize) -> Vec<TextRange> {
+        implementation::goto_implementation(&self.file, position)
+    }
+
+    /// Returns the text range of the declaration of the ink! storage field type
+    /// at the given position (if any).
+    pub fn type_definition(&self, position: TextSize) -> Option<TextRange> {
+        type_definition::type_definition(&self.file, position)
+    }
+
+    /// Returns the call hierarchy item for the ink! constructor or ink! message
+    /// at the given position (if any).
+    pub fn prepare_call_hierarchy(&self, position: TextSize) -> Option<CallHierarchyItem> {
+        call_hierarchy::prepare_call_hierarchy(&self.file, position)
+    }
+
+    /// Returns the incoming calls (in the same ink! contract) to the ink! constructor or ink! message
+    /// at the given position (if any).
+    ///
+    /// **NOTE**: This is a best-effort static analysis, cross-contract calls are not tracked.
+    pub fn call_hierarchy_incoming(&self, position: TextSize) -> Vec<CallHierarchyIncomingCall> {
+        call_hierarchy::call_hierarchy_incoming(&self.file, position)
+    }
+
     /// Returns descriptive/informational text for the ink! attribute at the given text range (if any).
     pub fn hover(&self, range: TextRange) -> Option<Hover> {
         hover::hover(&self.file, range)
     }
 
-    /// Computes ink! attribute argument inlay hints for the given text range (if any).
+    /// Computes ink! attribute argument and selector inlay hints for the given text range (if any).
     pub fn inlay_hints(&self, range: Option<TextRange>) -> Vec<InlayHint> {
         inlay_hints::inlay_hints(&self.file, range)
     }
@@ -83,4 +302,67 @@ impl Analysis {
     pub fn signature_help(&self, position: TextSize) -> Vec<SignatureHelp> {
         signature_help::signature_help(&self.file, position)
     }
+
+    /// Computes semantic tokens (for syntax highlighting) for all ink! attributes in the file.
+    pub fn semantic_tokens(&self) -> Vec<SemanticToken> {
+        semantic_tokens::semantic_tokens(&self.file)
+    }
+}
+
+/// Entry point for asking for semantic information about multiple files (i.e. a workspace)
+/// of ink! smart contract code.
+#[derive(Debug)]
+pub struct WorkspaceAnalysis {
+    /// The `(file_id, file)` pairs being analyzed.
+    files: Vec<(String, InkFile)>,
+}
+
+impl WorkspaceAnalysis {
+    /// Creates a workspace analysis instance from `(file_id, code)` pairs.
+    pub fn new(files: &[(&str, &str)]) -> Self {
+        Self {
+            files: files
+                .iter()
+                .map(|(file_id, code)| (file_id.to_string(), InkFile::parse(code)))
+                .collect(),
+        }
+    }
+
+    /// Returns the intermediate representation (IR) of the file with the given identifier (if any).
+    pub fn file(&self, file_id: &str) -> Option<&InkFile> {
+        self.files
+            .iter()
+            .find_map(|(id, file)| (id == file_id).then_some(file))
+    }
+
+    /// Runs per-file and cross-file diagnostics for the file with the given identifier.
+    ///
+    /// Returns an empty list if there's no file with the given identifier in the workspace.
+    pub fn diagnostics_for(&self, file_id: &str) -> Vec<Diagnostic> {
+        let Some(file) = self.file(file_id) else {
+            return Vec::new();
+        };
+        let mut results = diagnostics::diagnostics(file);
+        results.extend(
+            diagnostics::workspace_files_diagnostics(&self.files)
+                .into_iter()
+                .filter_map(|(id, diagnostic)| (id == file_id).then_some(diagnostic)),
+        );
+        results
+    }
+
+    /// Runs per-file and cross-file diagnostics for all files in the workspace.
+    ///
+    /// Returns diagnostics grouped by file identifier (files without diagnostics map to an empty list).
+    pub fn diagnostics(&self) -> HashMap<String, Vec<Diagnostic>> {
+        let mut results: HashMap<String, Vec<Diagnostic>> = self
+            .files
+            .iter()
+            .map(|(file_id, file)| (file_id.clone(), diagnostics::diagnostics(file)))
+            .collect();
+        for (file_id, diagnostic) in diagnostics::workspace_files_diagnostics(&self.files) {
+            results.entry(file_id).or_default().push(diagnostic);
+        }
+        results
+    }
 }
@@ -9,8 +9,8 @@ use ink_analyzer_ir::{InkAttribute, InkFile};
 use itertools::Itertools;
 
 use super::utils;
-use crate::analysis::text_edit;
-use crate::TextEdit;
+use crate::analysis::text_edit::{self, FormatOptions};
+use crate::{Diagnostic, TextEdit};
 
 /// An ink! attribute code/intent action.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -26,13 +26,33 @@ pub struct Action {
 }
 
 /// The kind of the action (e.g quickfix or refactor).
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///
+/// **NOTE**: Variants are declared (and ordered) by descending priority.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 #[non_exhaustive]
 pub enum ActionKind {
     QuickFix,
     Refactor,
 }
 
+/// The context in which ink! attribute code/intent actions are computed
+/// (aligned with the LSP `CodeActionContext` object).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CodeActionContext {
+    /// Range for which actions are computed.
+    pub range: TextRange,
+    /// Diagnostics (if any) that quickfixes should address (matched by range).
+    ///
+    /// **NOTE**: If not empty, only quickfixes for these diagnostics are returned
+    /// (i.e. generic code actions aren't returned),
+    /// otherwise, quickfixes for all diagnostics in the range and generic code actions are returned.
+    pub diagnostics: Vec<Diagnostic>,
+    /// Kinds of actions (if any) to return.
+    ///
+    /// **NOTE**: Actions of all kinds are returned if `None`.
+    pub only: Option<Vec<ActionKind>>,
+}
+
 /// Computes ink! attribute actions for the text range.
 pub fn actions(file: &InkFile, range: TextRange) -> Vec<Action> {
     let mut results = Vec::new();
@@ -49,7 +69,8 @@ pub fn actions(file: &InkFile, range: TextRange) -> Vec<Action> {
         .unique_by(|item| item.edits.clone())
         // Format edits.
         .map(|item| Action {
-            edits: text_edit::format_edits(item.edits.into_iter(), file).collect(),
+            edits: text_edit::format_edits(item.edits.into_iter(), file, &FormatOptions::default())
+                .collect(),
             ..item
         })
         .collect()
@@ -62,7 +83,7 @@ impl Action {
             label: format!("Remove `{}` attribute.", attr.syntax()),
             kind: ActionKind::QuickFix,
             range: attr.syntax().text_range(),
-            edits: vec![TextEdit::delete(attr.syntax().text_range())],
+            edits: vec![TextEdit::remove_attribute(attr)],
         }
     }
 
@@ -128,4 +149,376 @@ impl Action {
             ],
         }
     }
+
+    /// Converts the action into an LSP workspace edit for the given file.
+    ///
+    /// **NOTE**: Positions are computed from `source` using the UTF-16 position encoding
+    /// (i.e. the default LSP position encoding), and an invalid `file_uri` results in
+    /// a workspace edit with no changes.
+    #[cfg(feature = "lsp-types")]
+    pub fn to_workspace_edit(&self, file_uri: &str, source: &str) -> lsp_types::WorkspaceEdit {
+        let changes = lsp_types::Url::parse(file_uri).ok().map(|uri| {
+            let line_starts = ink_analyzer_ir::line_starts(source);
+            let edits = self
+                .edits
+                .iter()
+                .map(|edit| {
+                    lsp_types::TextEdit::new(
+                        lsp_types::Range::new(
+                            lsp_position(source, &line_starts, edit.range.start()),
+                            lsp_position(source, &line_starts, edit.range.end()),
+                        ),
+                        edit.text.clone(),
+                    )
+                })
+                .collect();
+            std::collections::HashMap::from([(uri, edits)])
+        });
+        lsp_types::WorkspaceEdit::new(changes.unwrap_or_default())
+    }
+}
+
+/// Converts an offset to an LSP position (using the UTF-16 position encoding).
+///
+/// **NOTE**: Offsets past the end of the source are clamped to the end of the source.
+#[cfg(feature = "lsp-types")]
+fn lsp_position(source: &str, line_starts: &[TextSize], offset: TextSize) -> lsp_types::Position {
+    let offset = offset.min(TextSize::of(source));
+    let line_col = ink_analyzer_ir::offset_to_line_col(offset, line_starts);
+    let line_start = usize::from(offset) - line_col.col as usize;
+    let line_prefix = source
+        .get(line_start..usize::from(offset))
+        .unwrap_or_default();
+    lsp_types::Position::new(line_col.line, line_prefix.encode_utf16().count() as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_utils::parse_offset_at;
+
+    #[test]
+    fn actions_combines_item_and_attr_actions() {
+        let code = r#"
+            #[ink::contract]
+            mod my_contract {
+                #[ink(storage)]
+                pub struct MyContract {}
+            }
+        "#;
+        let file = InkFile::parse(code);
+
+        for pat in [Some("<-#[ink::contract]"), Some("<-mod")] {
+            let offset = TextSize::from(parse_offset_at(code, pat).unwrap() as u32);
+            let range = TextRange::new(offset, offset);
+
+            // Computes the results of both passes.
+            let mut sub_pass_results = Vec::new();
+            item::actions(&mut sub_pass_results, &file, range);
+            attr::actions(&mut sub_pass_results, &file, range);
+
+            let results = actions(&file, range);
+
+            // Verifies that the combined list matches the (deduplicated) union of both passes.
+            assert_eq!(
+                results
+                    .iter()
+                    .map(|action| action.label.as_str())
+                    .sorted()
+                    .collect::<Vec<&str>>(),
+                sub_pass_results
+                    .iter()
+                    .unique_by(|action| action.edits.clone())
+                    .map(|action| action.label.as_str())
+                    .sorted()
+                    .collect::<Vec<&str>>(),
+                "pat: {pat:?}"
+            );
+
+            // Verifies that the combined list isn't empty.
+            assert!(!results.is_empty(), "pat: {pat:?}");
+        }
+    }
+
+    #[test]
+    fn analysis_actions_are_sorted_by_priority() {
+        // Missing ink! storage, constructor and message (i.e. diagnostics with quickfixes).
+        let code = r#"
+            #[ink::contract]
+            mod my_contract {
+            }
+        "#;
+        let offset = TextSize::from(parse_offset_at(code, Some("<-mod")).unwrap() as u32);
+        let results = crate::Analysis::new(code).actions(TextRange::new(offset, offset));
+
+        let kinds: Vec<ActionKind> = results.iter().map(|action| action.kind).collect();
+        // Verifies that both quickfixes and refactors are returned, with quickfixes first.
+        assert!(kinds.contains(&ActionKind::QuickFix));
+        assert!(kinds.contains(&ActionKind::Refactor));
+        assert!(kinds.iter().tuple_windows().all(|(a, b)| a <= b));
+    }
+
+    #[test]
+    fn actions_with_context_works() {
+        // Missing ink! storage, constructor and message (i.e. diagnostics with quickfixes).
+        let code = r#"
+            #[ink::contract]
+            mod my_contract {
+            }
+        "#;
+        let analysis = crate::Analysis::new(code);
+        let offset = TextSize::from(parse_offset_at(code, Some("<-mod")).unwrap() as u32);
+        let range = TextRange::new(offset, offset);
+        let has_quickfixes = |results: &[Action]| {
+            results
+                .iter()
+                .any(|action| action.kind == ActionKind::QuickFix)
+        };
+
+        // Default context is equivalent to `Analysis::actions`.
+        let context = analysis.prepare_code_action(range);
+        assert_eq!(
+            analysis.actions_with_context(&context),
+            analysis.actions(range)
+        );
+
+        // Filters by action kind.
+        let results = analysis.actions_with_context(&CodeActionContext {
+            only: Some(vec![ActionKind::Refactor]),
+            ..context.clone()
+        });
+        assert!(!results.is_empty());
+        assert!(results
+            .iter()
+            .all(|action| action.kind == ActionKind::Refactor));
+
+        // Only returns quickfixes for diagnostics with matching ranges.
+        let diagnostics = analysis.diagnostics();
+        assert!(!diagnostics.is_empty());
+        let results = analysis.actions_with_context(&CodeActionContext {
+            diagnostics: diagnostics.clone(),
+            ..context.clone()
+        });
+        assert!(has_quickfixes(&results));
+        // Generic code actions aren't returned when diagnostics are given.
+        assert!(results
+            .iter()
+            .all(|action| action.kind == ActionKind::QuickFix));
+        let results = analysis.actions_with_context(&CodeActionContext {
+            diagnostics: diagnostics
+                .into_iter()
+                .map(|diagnostic| Diagnostic {
+                    range: TextRange::new(TextSize::from(0), TextSize::from(1)),
+                    ..diagnostic
+                })
+                .collect(),
+            ..context
+        });
+        assert!(results.is_empty());
+    }
+
+    #[test]
+    fn code_action_resolve_works() {
+        let code = r#"
+            #[ink::contract]
+            mod my_contract {
+                #[ink(storage)]
+                pub struct MyContract {}
+
+                impl MyContract {
+                    #[ink(constructor)]
+                    pub fn new() -> Self {}
+                }
+            }
+        "#;
+        let analysis = crate::Analysis::new(code);
+        let offset = TextSize::from(parse_offset_at(code, Some("<-impl")).unwrap() as u32);
+        let range = TextRange::new(offset, offset);
+
+        // Eagerly computed "Add ink! message" action.
+        let eager_action = analysis
+            .actions(range)
+            .into_iter()
+            .find(|action| action.label.starts_with("Add ink! message"))
+            .unwrap();
+        assert!(!eager_action.edits.is_empty());
+
+        // Resolves a lightweight version (i.e. with empty edits) of the same action.
+        let resolved_action = analysis.code_action_resolve(Action {
+            edits: Vec::new(),
+            ..eager_action.clone()
+        });
+        assert_eq!(resolved_action, eager_action);
+
+        // Resolves a lightweight quickfix (i.e. re-runs only diagnostics).
+        let code = r#"
+            #[ink::contract]
+            mod my_contract {
+                #[ink(storage)]
+                pub struct MyContract {}
+
+                impl MyContract {
+                    #[ink(constructor)]
+                    pub fn new() -> Self {}
+
+                    #[ink(message)]
+                    pub fn minimal() {}
+                }
+            }
+        "#;
+        let quickfix_analysis = crate::Analysis::new(code);
+        let eager_quickfix = quickfix_analysis
+            .actions_with_kinds(
+                TextRange::up_to(TextSize::of(code)),
+                &[ActionKind::QuickFix],
+            )
+            .into_iter()
+            .next()
+            .unwrap();
+        assert!(!eager_quickfix.edits.is_empty());
+        let resolved_quickfix = quickfix_analysis.code_action_resolve(Action {
+            edits: Vec::new(),
+            ..eager_quickfix.clone()
+        });
+        assert_eq!(resolved_quickfix, eager_quickfix);
+
+        // Unknown actions are returned unchanged.
+        let unknown_action = Action {
+            label: "Unknown action.".to_string(),
+            kind: ActionKind::Refactor,
+            range,
+            edits: Vec::new(),
+        };
+        assert_eq!(
+            analysis.code_action_resolve(unknown_action.clone()),
+            unknown_action
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "lsp-types")]
+    fn to_workspace_edit_works() {
+        // Applies LSP text edits (with UTF-16 positions) to the source code.
+        fn apply_lsp_edits(source: &str, edits: &[lsp_types::TextEdit]) -> String {
+            let to_offset = |position: lsp_types::Position| -> usize {
+                let line_start: usize = source
+                    .split_inclusive('\n')
+                    .take(position.line as usize)
+                    .map(str::len)
+                    .sum();
+                let mut utf16_len = 0;
+                let col = source[line_start..]
+                    .char_indices()
+                    .find(|(_, c)| {
+                        let is_target = utf16_len >= position.character as usize;
+                        utf16_len += c.len_utf16();
+                        is_target
+                    })
+                    .map_or(source.len() - line_start, |(idx, _)| idx);
+                line_start + col
+            };
+            let mut output = source.to_string();
+            // Applies edits in reverse order so that offsets of subsequent edits remain valid.
+            for edit in edits
+                .iter()
+                .sorted_by_key(|edit| (edit.range.start.line, edit.range.start.character))
+                .rev()
+            {
+                output.replace_range(
+                    to_offset(edit.range.start)..to_offset(edit.range.end),
+                    &edit.new_text,
+                );
+            }
+            output
+        }
+
+        let code = r#"#[ink::contract]
+mod my_contract {
+    // 🦑 Non-ASCII comment.
+    #[ink(storage)]
+    pub struct MyContract {}
+
+    #[ink(message)]
+    pub fn my_message(&self) {}
+}"#;
+        let file_uri = "file:///tmp/lib.rs";
+        for (action, expected_output) in [
+            (
+                Action {
+                    label: "Remove item.".to_string(),
+                    kind: ActionKind::QuickFix,
+                    range: TextRange::default(),
+                    edits: vec![TextEdit::delete(TextRange::new(
+                        TextSize::from(
+                            parse_offset_at(code, Some("<-#[ink(message)]")).unwrap() as u32
+                        ),
+                        TextSize::from(parse_offset_at(code, Some("&self) {}")).unwrap() as u32),
+                    ))],
+                },
+                r#"#[ink::contract]
+mod my_contract {
+    // 🦑 Non-ASCII comment.
+    #[ink(storage)]
+    pub struct MyContract {}
+
+    
+}"#,
+            ),
+            (
+                Action {
+                    label: "Add payable.".to_string(),
+                    kind: ActionKind::QuickFix,
+                    range: TextRange::default(),
+                    edits: vec![
+                        TextEdit::insert(
+                            ", payable".to_string(),
+                            TextSize::from(parse_offset_at(code, Some("(message")).unwrap() as u32),
+                        ),
+                        TextEdit::replace(
+                            "Contract".to_string(),
+                            TextRange::new(
+                                TextSize::from(
+                                    parse_offset_at(code, Some("<-MyContract")).unwrap() as u32,
+                                ),
+                                TextSize::from(
+                                    parse_offset_at(code, Some("MyContract")).unwrap() as u32
+                                ),
+                            ),
+                        ),
+                    ],
+                },
+                r#"#[ink::contract]
+mod my_contract {
+    // 🦑 Non-ASCII comment.
+    #[ink(storage)]
+    pub struct Contract {}
+
+    #[ink(message, payable)]
+    pub fn my_message(&self) {}
+}"#,
+            ),
+        ] {
+            let workspace_edit = action.to_workspace_edit(file_uri, code);
+            let changes = workspace_edit.changes.unwrap();
+            assert_eq!(changes.len(), 1);
+            let edits = changes
+                .get(&lsp_types::Url::parse(file_uri).unwrap())
+                .unwrap();
+            assert_eq!(edits.len(), action.edits.len());
+            assert_eq!(apply_lsp_edits(code, edits), expected_output);
+        }
+
+        // Invalid URI.
+        let action = Action {
+            label: "Remove item.".to_string(),
+            kind: ActionKind::QuickFix,
+            range: TextRange::default(),
+            edits: vec![TextEdit::delete(TextRange::default())],
+        };
+        assert!(action
+            .to_workspace_edit("not a uri", code)
+            .changes
+            .unwrap_or_default()
+            .is_empty());
+    }
 }
@@ -1,7 +1,7 @@
 //! ink! attribute code/intent actions.
 
 use ink_analyzer_ir::syntax::TextRange;
-use ink_analyzer_ir::InkFile;
+use ink_analyzer_ir::{InkArgKind, InkAttribute, InkFile};
 
 use super::Action;
 use crate::analysis::utils;
@@ -14,6 +14,9 @@ pub fn actions(results: &mut Vec<Action>, file: &InkFile, range: TextRange) {
         // Only computes actions for closed attributes because
         // unclosed attributes are too tricky for useful contextual edits.
         if ink_attr.ast().r_brack_token().is_some() {
+            // Suggests converting the focused selector value (if any) between decimal and hexadecimal notation.
+            toggle_selector_format(results, &ink_attr, range);
+
             // No ink! attribute argument suggestions for trait definition implementation messages.
             if ink_attr
                 .syntax()
@@ -71,10 +74,47 @@ pub fn actions(results: &mut Vec<Action>, file: &InkFile, range: TextRange) {
     }
 }
 
+/// Computes an action for converting the value of the focused ink! selector argument (if any)
+/// from decimal to hexadecimal notation (e.g. `selector=167772160` to `selector=0x0a000000`) and vice versa.
+///
+/// **NOTE**: Wildcard selectors (i.e. `selector=_` and `selector=@`) are ignored.
+pub fn toggle_selector_format(
+    results: &mut Vec<Action>,
+    ink_attr: &InkAttribute,
+    range: TextRange,
+) {
+    let Some((arg, value)) = ink_attr.args().iter().find_map(|arg| {
+        (*arg.kind() == InkArgKind::Selector && arg.text_range().contains_range(range))
+            .then(|| arg.value().map(|value| (arg, value)))
+            .flatten()
+    }) else {
+        return;
+    };
+    let Some(selector) = arg.value_as_u32() else {
+        return;
+    };
+
+    let (label, text) = if value.to_string().starts_with("0x") {
+        ("Convert selector to decimal.", format!("{selector}"))
+    } else {
+        (
+            "Convert selector to hexadecimal.",
+            ink_analyzer_ir::format_selector(selector),
+        )
+    };
+    results.push(Action {
+        label: label.to_string(),
+        kind: ActionKind::Refactor,
+        range: arg.text_range(),
+        edits: vec![TextEdit::replace(text, value.text_range())],
+    });
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use ink_analyzer_ir::syntax::TextSize;
+    use ink_analyzer_ir::InkEntity;
     use test_utils::{parse_offset_at, remove_whitespace};
 
     #[test]
@@ -439,4 +479,112 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn toggle_selector_format_works() {
+        for (code, pat, expected_result) in [
+            // (code, pat, Option<(label, edit, pat_start, pat_end)>) where:
+            // code = source code,
+            // pat = substring used to find the cursor offset (see `test_utils::parse_offset_at` doc),
+            // label = a substring of the action label,
+            // edit = the text that will replace the selector value,
+            // pat_start = substring used to find the start of the edit range (see `test_utils::parse_offset_at` doc),
+            // pat_end = substring used to find the end of the edit range (see `test_utils::parse_offset_at` doc).
+
+            // Decimal to hex.
+            (
+                "#[ink(message, selector=167772160)]",
+                Some("selector"),
+                Some(("hexadecimal", "0x0a000000", "<-167772160", "167772160")),
+            ),
+            (
+                "#[ink(message, selector = 1)]",
+                Some("1"),
+                Some(("hexadecimal", "0x00000001", "<-1)", "<-)]")),
+            ),
+            (
+                "#[ink(message, selector=0)]",
+                Some("<-selector"),
+                Some(("hexadecimal", "0x00000000", "<-0)", "<-)]")),
+            ),
+            (
+                "#[ink(message, selector=4294967295)]",
+                Some("selector="),
+                Some(("hexadecimal", "0xffffffff", "<-4294967295", "4294967295")),
+            ),
+            // Hex to decimal.
+            (
+                "#[ink(message, selector=0x0A000000)]",
+                Some("selector"),
+                Some(("decimal", "167772160", "<-0x0A000000", "0x0A000000")),
+            ),
+            (
+                "#[ink(constructor, selector = 0xCAFEBABE)]",
+                Some("0xCAFE"),
+                Some(("decimal", "3405691582", "<-0xCAFEBABE", "0xCAFEBABE")),
+            ),
+            (
+                "#[ink(message, selector=0x0)]",
+                Some("selector"),
+                Some(("decimal", "0", "<-0x0", "0x0")),
+            ),
+            (
+                "#[ink(message, selector=0xFFFFFFFF)]",
+                Some("selector"),
+                Some(("decimal", "4294967295", "<-0xFFFFFFFF", "0xFFFFFFFF")),
+            ),
+            // Ignored.
+            ("#[ink(message, selector=_)]", Some("selector"), None),
+            ("#[ink(message, selector=@)]", Some("selector"), None),
+            ("#[ink(message, selector=)]", Some("selector"), None),
+            ("#[ink(message, selector)]", Some("selector"), None),
+            (
+                "#[ink(message, selector=4294967296)]",
+                Some("selector"),
+                None,
+            ),
+            (
+                "#[ink(message, selector=0x100000000)]",
+                Some("selector"),
+                None,
+            ),
+            // Selector not in focus.
+            ("#[ink(message, selector=1)]", Some("<-message"), None),
+            ("#[ink(extension=1)]", Some("extension"), None),
+        ] {
+            let offset = TextSize::from(parse_offset_at(code, pat).unwrap() as u32);
+            let range = TextRange::new(offset, offset);
+            let ink_attr = InkFile::parse(code)
+                .tree()
+                .ink_attrs_in_scope()
+                .next()
+                .unwrap();
+
+            let mut results = Vec::new();
+            toggle_selector_format(&mut results, &ink_attr, range);
+
+            assert_eq!(
+                results
+                    .iter()
+                    .map(|action| (
+                        action.label.contains(expected_result.map_or("", |it| it.0)),
+                        action.edits[0].text.as_str(),
+                        action.edits[0].range
+                    ))
+                    .collect::<Vec<_>>(),
+                expected_result
+                    .into_iter()
+                    .map(|(_, edit, pat_start, pat_end)| (
+                        true,
+                        edit,
+                        TextRange::new(
+                            TextSize::from(parse_offset_at(code, Some(pat_start)).unwrap() as u32),
+                            TextSize::from(parse_offset_at(code, Some(pat_end)).unwrap() as u32)
+                        )
+                    ))
+                    .collect::<Vec<_>>(),
+                "code: {code}"
+            );
+        }
+    }
 }
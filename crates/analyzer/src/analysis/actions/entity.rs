@@ -1,9 +1,13 @@
 //! ink! entity code/intent actions.
 
-use ink_analyzer_ir::syntax::{AstNode, TextRange};
+use ink_analyzer_ir::ast::{HasAttrs, HasName, HasVisibility};
+use ink_analyzer_ir::syntax::{AstNode, SyntaxKind, TextRange};
 use ink_analyzer_ir::{
-    ast, ChainExtension, Contract, Event, InkEntity, IsInkStruct, IsInkTrait, TraitDefinition,
+    ast, ChainExtension, Constructor, Contract, Event, InkEntity, InkImpl, InkVersionHints,
+    IsInkFn, IsInkStruct, IsInkTrait, TraitDefinition,
 };
+use itertools::Itertools;
+use std::collections::HashSet;
 
 use super::{Action, ActionKind};
 use crate::analysis::utils;
@@ -12,9 +16,10 @@ use crate::codegen::snippets::{
     CONTRACT_PLAIN, CONTRACT_SNIPPET, ENVIRONMENT_DEF, ENVIRONMENT_IMPL_PLAIN,
     ENVIRONMENT_IMPL_SNIPPET, ERROR_CODE_PLAIN, ERROR_CODE_SNIPPET, EVENT_PLAIN, EVENT_SNIPPET,
     EXTENSION_PLAIN, EXTENSION_SNIPPET, INK_E2E_TEST_PLAIN, INK_E2E_TEST_SNIPPET, INK_TEST_PLAIN,
-    INK_TEST_SNIPPET, MESSAGE_PLAIN, MESSAGE_SNIPPET, STORAGE_ITEM_PLAIN, STORAGE_ITEM_SNIPPET,
-    STORAGE_PLAIN, STORAGE_SNIPPET, TOPIC_PLAIN, TOPIC_SNIPPET, TRAIT_DEFINITION_PLAIN,
-    TRAIT_DEFINITION_SNIPPET, TRAIT_MESSAGE_PLAIN, TRAIT_MESSAGE_SNIPPET,
+    INK_TEST_SNIPPET, MESSAGE_PLAIN, MESSAGE_SNIPPET, SCALE_DERIVE_PLAIN, SCALE_DERIVE_SNIPPET,
+    STORAGE_ITEM_PLAIN, STORAGE_ITEM_SNIPPET, STORAGE_PLAIN, STORAGE_SNIPPET, TOPIC_PLAIN,
+    TOPIC_SNIPPET, TRAIT_DEFINITION_PLAIN, TRAIT_DEFINITION_SNIPPET, TRAIT_MESSAGE_PLAIN,
+    TRAIT_MESSAGE_SNIPPET,
 };
 use crate::TextEdit;
 
@@ -163,6 +168,51 @@ pub fn add_topic(
     })
 }
 
+/// Adds an `#[ink::scale_derive(Encode, Decode, TypeInfo)]` attribute to an ink! event `struct` item
+/// (i.e. as required by ink! 5.x), if the file (probably) targets ink! 5.x (based on the ink! version hints)
+/// and the `struct` doesn't already derive any of the SCALE codec traits.
+pub fn add_scale_derive(
+    event: &Event,
+    kind: ActionKind,
+    version: InkVersionHints,
+) -> Option<Action> {
+    if version.likely_major != Some(5) {
+        return None;
+    }
+    let struct_item = event.struct_item()?;
+
+    // Bails if any of `Encode`, `Decode` or `TypeInfo` is already derived
+    // (either via `#[ink::scale_derive(..)]` or `#[derive(..)]`/`#[cfg_attr(.., derive(..))]`).
+    let has_scale_derives = struct_item.attrs().any(|attr| {
+        attr.token_tree().is_some_and(|token_tree| {
+            token_tree
+                .syntax()
+                .descendants_with_tokens()
+                .filter_map(|elem| elem.into_token())
+                .any(|token| matches!(token.text(), "Encode" | "Decode" | "TypeInfo"))
+        })
+    });
+    if has_scale_derives {
+        return None;
+    }
+
+    let declaration_range =
+        utils::ast_item_declaration_range(&ast::Item::Struct(struct_item.clone()))
+            .unwrap_or(struct_item.syntax().text_range());
+    let insert_offset = declaration_range.start();
+    let indent = utils::item_indenting(struct_item.syntax()).unwrap_or_default();
+    Some(Action {
+        label: "Add `#[ink::scale_derive(Encode, Decode, TypeInfo)]`.".to_string(),
+        kind,
+        range: declaration_range,
+        edits: vec![TextEdit::insert_with_snippet(
+            format!("{SCALE_DERIVE_PLAIN}\n{indent}"),
+            insert_offset,
+            Some(format!("{SCALE_DERIVE_SNIPPET}\n{indent}")),
+        )],
+    })
+}
+
 /// Adds an ink! callable `fn` to the first non-trait `impl` block or
 /// creates a new `impl` block if necessary.
 fn add_callable_to_contract(
@@ -304,6 +354,335 @@ fn add_callable_to_impl(
         })
 }
 
+/// Adds getter ink! message `fn`s for all named fields of the ink! storage `struct`
+/// to the first non-trait `impl` block or creates a new `impl` block if necessary.
+///
+/// Fields that already have a getter (i.e. an ink! message named `get_<field_name>`)
+/// and fields with lazy storage types (e.g. `Mapping`) are skipped.
+pub fn add_getters_for_storage(contract: &Contract, kind: ActionKind) -> Option<Action> {
+    let struct_item = contract.storage()?.struct_item()?;
+    let ast::FieldList::RecordFieldList(field_list) = struct_item.field_list()? else {
+        return None;
+    };
+
+    // Collects names of existing ink! messages.
+    let message_names: HashSet<String> = contract
+        .messages()
+        .iter()
+        .filter_map(|message| message.fn_item()?.name())
+        .map(|name| name.to_string())
+        .collect();
+    // Composes getters for fields without existing getters.
+    let getters: Vec<String> = field_list
+        .fields()
+        .filter_map(|field| {
+            let name = field.name()?.to_string();
+            let ty = field.ty()?;
+            let ty_name = match &ty {
+                ast::Type::PathType(path_type) => path_type
+                    .path()
+                    .and_then(|path| path.segment())
+                    .and_then(|segment| segment.name_ref())
+                    .map(|name_ref| name_ref.to_string()),
+                _ => None,
+            };
+            if matches!(ty_name.as_deref(), Some("Mapping" | "Lazy" | "StorageVec")) {
+                return None;
+            }
+            let getter_name = format!("get_{name}");
+            if message_names.contains(&getter_name) {
+                return None;
+            }
+            // Only primitive and known `Copy` ink! types are returned by value without cloning.
+            let is_copy = matches!(
+                ty_name.as_deref(),
+                Some(
+                    "bool"
+                        | "u8"
+                        | "u16"
+                        | "u32"
+                        | "u64"
+                        | "u128"
+                        | "i8"
+                        | "i16"
+                        | "i32"
+                        | "i64"
+                        | "i128"
+                        | "AccountId"
+                        | "Balance"
+                        | "BlockNumber"
+                        | "Hash"
+                        | "Timestamp"
+                )
+            );
+            Some(format!(
+                "#[ink(message)]\npub fn {getter_name}(&self) -> {ty} {{\n    self.{name}{}\n}}",
+                if is_copy { "" } else { ".clone()" }
+            ))
+        })
+        .collect();
+    if getters.is_empty() {
+        return None;
+    }
+
+    // Inserts in the first non-trait `impl` block or creates a new `impl` block if necessary.
+    let (offset, indent, prefix, suffix) =
+        utils::callable_insert_offset_indent_and_affixes(contract)?;
+    let n_getters = getters.len();
+    Some(Action {
+        label: "Add getter ink! message `fn`s for storage fields.".to_string(),
+        kind,
+        range: utils::ast_item_declaration_range(&ast::Item::Struct(struct_item.clone()))
+            .unwrap_or(struct_item.syntax().text_range()),
+        edits: getters
+            .iter()
+            .enumerate()
+            .map(|(idx, getter)| {
+                TextEdit::insert(
+                    format!(
+                        "{}{}{}",
+                        if idx == 0 {
+                            prefix.as_deref().unwrap_or_default()
+                        } else {
+                            ""
+                        },
+                        utils::apply_indenting(getter, &indent),
+                        if idx + 1 == n_getters {
+                            suffix.as_deref().unwrap_or_default()
+                        } else {
+                            ""
+                        }
+                    ),
+                    offset,
+                )
+            })
+            .collect(),
+    })
+}
+
+/// Replaces an implicit ink! storage initialization in an ink! constructor body
+/// (e.g. `Self::new(value)` or an empty body) with an explicit `Self { .. }` struct expression
+/// (i.e. as required by ink! 4.x) based on the fields of the ink! storage `struct`.
+///
+/// Fields with the same name as a constructor parameter are initialized from the parameter,
+/// all other fields are initialized with `Default::default()`.
+pub fn migrate_constructor_to_ink4(constructor: &Constructor, kind: ActionKind) -> Option<Action> {
+    let fn_item = constructor.fn_item()?;
+    let returns_self = fn_item
+        .ret_type()
+        .and_then(|ret_type| ret_type.ty())
+        .is_some_and(|ty| ty.to_string() == "Self");
+    let stmt_list = fn_item.body()?.stmt_list()?;
+    // Only applies if the body doesn't already contain a struct expression.
+    let has_struct_expr = stmt_list
+        .syntax()
+        .descendants()
+        .any(|node| ast::RecordExpr::can_cast(node.kind()));
+    if !returns_self || has_struct_expr {
+        return None;
+    }
+
+    let contract = ink_analyzer_ir::ink_ancestor_of_kind::<Contract>(constructor.syntax())?;
+    let struct_item = contract.storage()?.struct_item()?;
+    let field_names: Vec<String> = match struct_item.field_list() {
+        Some(ast::FieldList::RecordFieldList(field_list)) => field_list
+            .fields()
+            .filter_map(|field| field.name())
+            .map(|name| name.to_string())
+            .collect(),
+        Some(ast::FieldList::TupleFieldList(_)) => return None,
+        None => Vec::new(),
+    };
+    let param_names = ink_analyzer_ir::fn_param_names(fn_item);
+
+    // Composes the struct expression.
+    let (fields_plain, fields_snippet): (Vec<String>, Vec<String>) = field_names
+        .iter()
+        .enumerate()
+        .map(|(idx, name)| {
+            if param_names.contains(name) {
+                (name.to_string(), name.to_string())
+            } else {
+                (
+                    format!("{name}: Default::default()"),
+                    format!("{name}: ${{{}:Default::default()}}", idx + 1),
+                )
+            }
+        })
+        .unzip();
+    let compose_struct_expr = |fields: &[String]| {
+        if fields.is_empty() {
+            "Self {}".to_string()
+        } else {
+            format!("Self {{\n    {},\n}}", fields.join(",\n    "))
+        }
+    };
+    let struct_expr_plain = compose_struct_expr(&fields_plain);
+    let struct_expr_snippet = compose_struct_expr(&fields_snippet);
+
+    // Replaces the tail expression (if any), or otherwise appends to the body.
+    let fn_indent = utils::item_indenting(fn_item.syntax()).unwrap_or_default();
+    let body_indent = format!("{fn_indent}    ");
+    let (range, prefix, suffix) = match (stmt_list.tail_expr(), stmt_list.statements().last()) {
+        (Some(tail_expr), _) => (
+            tail_expr.syntax().text_range(),
+            String::new(),
+            String::new(),
+        ),
+        (None, Some(stmt)) => {
+            let end = stmt.syntax().text_range().end();
+            (
+                TextRange::new(end, end),
+                format!("\n{body_indent}"),
+                String::new(),
+            )
+        }
+        (None, None) => (
+            stmt_list.syntax().text_range(),
+            format!("{{\n{body_indent}"),
+            format!("\n{fn_indent}}}"),
+        ),
+    };
+    let compose_edit_text = |struct_expr: &str| {
+        format!(
+            "{prefix}{}{suffix}",
+            utils::apply_indenting(struct_expr, &body_indent).trim_start()
+        )
+    };
+
+    Some(Action {
+        label: "Initialize ink! storage fields explicitly.".to_string(),
+        kind,
+        range: utils::ast_item_declaration_range(&ast::Item::Fn(fn_item.clone()))
+            .unwrap_or(fn_item.syntax().text_range()),
+        edits: vec![TextEdit::replace_with_snippet(
+            compose_edit_text(&struct_expr_plain),
+            range,
+            Some(compose_edit_text(&struct_expr_snippet)),
+        )],
+    })
+}
+
+/// Extracts the ink! messages of an `impl` block into a new ink! trait definition
+/// (i.e. declarations without bodies), and converts the `impl` block into
+/// an implementation of the new trait (i.e. bodies are kept in the `impl` block).
+///
+/// **NOTE**: Only `impl` blocks that contain only ink! messages and have no ink! namespace argument
+/// are supported (i.e. because trait implementation blocks can't contain ink! constructors
+/// or other items, nor have an ink! namespace argument).
+pub fn extract_trait_definition(ink_impl: &InkImpl, kind: ActionKind) -> Option<Action> {
+    let impl_item = ink_impl.impl_item()?;
+    if impl_item.trait_().is_some()
+        || ink_impl.messages().is_empty()
+        || !ink_impl.constructors().is_empty()
+        || ink_impl.namespace_arg().is_some()
+    {
+        return None;
+    }
+    let fn_items: Vec<&ast::Fn> = ink_impl
+        .messages()
+        .iter()
+        .filter_map(IsInkFn::fn_item)
+        .collect();
+    let n_assoc_items = impl_item.assoc_item_list()?.assoc_items().count();
+    if fn_items.len() != n_assoc_items {
+        return None;
+    }
+
+    // Composes a unique trait name based on the `Self` type.
+    let self_ty = impl_item.self_ty()?;
+    let ast::Type::PathType(path_type) = &self_ty else {
+        return None;
+    };
+    let self_ty_name = path_type.path()?.segment()?.name_ref()?.to_string();
+    let root = impl_item.syntax().ancestors().last()?;
+    let trait_names: HashSet<String> = root
+        .descendants()
+        .filter_map(ast::Trait::cast)
+        .filter_map(|trait_item| trait_item.name().map(|name| name.to_string()))
+        .collect();
+    let mut trait_name = format!("{self_ty_name}Trait");
+    let mut suffix = 1;
+    while trait_names.contains(&trait_name) {
+        suffix += 1;
+        trait_name = format!("{self_ty_name}Trait{suffix}");
+    }
+
+    // Composes message declarations (i.e. attributes and signatures without bodies).
+    let mut declarations = Vec::new();
+    for fn_item in &fn_items {
+        let fn_range = fn_item.syntax().text_range();
+        let fn_text = fn_item.syntax().to_string();
+        let signature_start = fn_item.fn_token()?.text_range().start() - fn_range.start();
+        let signature_end = fn_item
+            .body()
+            .map_or(fn_range.end(), |body| body.syntax().text_range().start())
+            - fn_range.start();
+        let attrs = fn_item.attrs().map(|attr| attr.to_string()).join("\n");
+        let signature =
+            fn_text[usize::from(signature_start)..usize::from(signature_end)].trim_end();
+        declarations.push(format!(
+            "{attrs}{}{signature};",
+            if attrs.is_empty() { "" } else { "\n" }
+        ));
+    }
+
+    // Inserts the ink! trait definition before the top-level item that contains the `impl` block
+    // (and refers to it relative to the module of the `impl` block).
+    let top_level_item = impl_item
+        .syntax()
+        .ancestors()
+        .filter(|node| ast::Item::can_cast(node.kind()))
+        .last()?;
+    let indent = utils::item_indenting(&top_level_item).unwrap_or_default();
+    let trait_definition = format!(
+        "#[ink::trait_definition]\npub trait {trait_name} {{\n{}\n}}",
+        utils::apply_indenting(&declarations.join("\n\n"), "    ")
+    );
+    let n_parent_modules = impl_item
+        .syntax()
+        .ancestors()
+        .filter(|node| ast::Module::can_cast(node.kind()))
+        .count();
+    let trait_path = format!("{}{trait_name}", "super::".repeat(n_parent_modules));
+
+    let mut edits = vec![
+        TextEdit::insert(
+            format!(
+                "{}\n\n{indent}",
+                utils::apply_indenting(&trait_definition, &indent).trim_start()
+            ),
+            top_level_item.text_range().start(),
+        ),
+        TextEdit::insert(
+            format!("{trait_path} for "),
+            self_ty.syntax().text_range().start(),
+        ),
+    ];
+    // Trait implementation methods must have inherited visibility.
+    edits.extend(fn_items.iter().filter_map(|fn_item| {
+        let visibility = fn_item.visibility()?;
+        let end = visibility
+            .syntax()
+            .next_sibling_or_token()
+            .filter(|elem| elem.kind() == SyntaxKind::WHITESPACE)
+            .map_or(visibility.syntax().text_range().end(), |elem| {
+                elem.text_range().end()
+            });
+        Some(TextEdit::delete(TextRange::new(
+            visibility.syntax().text_range().start(),
+            end,
+        )))
+    }));
+
+    Some(Action {
+        label: "Extract ink! messages into an ink! trait definition.".to_string(),
+        kind,
+        range: utils::ink_impl_declaration_range(ink_impl),
+        edits,
+    })
+}
+
 /// Adds an ink! constructor `fn` to an `impl` block.
 pub fn add_constructor_to_impl(
     impl_item: &ast::Impl,
@@ -3,8 +3,8 @@
 use ink_analyzer_ir::ast::HasAttrs;
 use ink_analyzer_ir::syntax::{AstNode, SyntaxKind, SyntaxNode, SyntaxToken, TextRange};
 use ink_analyzer_ir::{
-    ast, ChainExtension, Contract, Event, InkArgKind, InkAttribute, InkAttributeKind, InkEntity,
-    InkFile, InkImpl, InkMacroKind, TraitDefinition,
+    ast, ChainExtension, Constructor, Contract, Event, HasInkImplParent, InkArgKind, InkAttribute,
+    InkAttributeKind, InkEntity, InkFile, InkImpl, InkMacroKind, Message, TraitDefinition,
 };
 use itertools::Itertools;
 
@@ -85,6 +85,7 @@ pub fn actions(results: &mut Vec<Action>, file: &InkFile, range: TextRange) {
                             // Suggests ink! entities based on item context.
                             item_ink_entity_actions(
                                 results,
+                                file,
                                 &ast_item,
                                 is_focused_on_body.then_some(focused_elem_edit_range()),
                             );
@@ -141,7 +142,10 @@ fn ink_macro_actions(results: &mut Vec<Action>, target: &SyntaxNode, range: Text
             // Add ink! attribute macro actions to accumulator.
             for macro_kind in ink_macro_suggestions {
                 results.push(Action {
-                    label: format!("Add ink! {macro_kind} attribute macro."),
+                    label: format!(
+                        "Add `{}` attribute macro.",
+                        InkAttributeKind::Macro(macro_kind)
+                    ),
                     kind: ActionKind::Refactor,
                     range,
                     edits: vec![TextEdit::insert(
@@ -268,11 +272,48 @@ fn ink_arg_actions(results: &mut Vec<Action>, target: &SyntaxNode, range: TextRa
             });
         }
     }
+
+    // Suggests adding an ink! `impl` attribute to the parent `impl` item of
+    // an ink! constructor or ink! message that has no ink! contract parent (if necessary).
+    add_ink_impl_to_parent_impl(results, target, range);
+}
+
+/// Computes an action for adding an ink! `impl` attribute to the parent `impl` item of
+/// an ink! constructor or ink! message, if the `impl` item has neither an ink! `impl` attribute
+/// nor an ink! contract parent.
+fn add_ink_impl_to_parent_impl(results: &mut Vec<Action>, target: &SyntaxNode, range: TextRange) {
+    let impl_item_option = Constructor::cast(target.clone())
+        .as_ref()
+        .and_then(HasInkImplParent::parent_impl_item)
+        .or_else(|| {
+            Message::cast(target.clone())
+                .as_ref()
+                .and_then(HasInkImplParent::parent_impl_item)
+        });
+    let Some(impl_item) = impl_item_option else {
+        return;
+    };
+    let has_contract_parent = ink_analyzer_ir::ink_parent::<Contract>(impl_item.syntax()).is_some();
+    if InkImpl::can_cast_strict(impl_item.syntax()) || has_contract_parent {
+        return;
+    }
+
+    let indent = utils::item_indenting(impl_item.syntax()).unwrap_or_default();
+    results.push(Action {
+        label: "Add `#[ink(impl)]` attribute to the enclosing `impl` block.".to_string(),
+        kind: ActionKind::Refactor,
+        range,
+        edits: vec![TextEdit::insert(
+            format!("#[ink(impl)]\n{indent}"),
+            utils::ink_attribute_insert_offset(impl_item.syntax()),
+        )],
+    });
 }
 
 /// Computes AST item-based ink! entity macro actions.
 fn item_ink_entity_actions(
     results: &mut Vec<Action>,
+    file: &InkFile,
     item: &ast::Item,
     range_option: Option<TextRange>,
 ) {
@@ -382,6 +423,17 @@ fn item_ink_entity_actions(
                     ActionKind::Refactor,
                     range_option,
                 ));
+
+                // Extracts ink! messages into an ink! trait definition
+                // (only if focused on the `impl` declaration).
+                if range_option.is_none() {
+                    if let Some(ink_impl) = InkImpl::cast(impl_item.syntax().clone()) {
+                        add_result(entity::extract_trait_definition(
+                            &ink_impl,
+                            ActionKind::Refactor,
+                        ));
+                    }
+                }
             }
         }
         ast::Item::Trait(trait_item) => {
@@ -440,6 +492,43 @@ fn item_ink_entity_actions(
                     ActionKind::Refactor,
                     range_option,
                 ));
+
+                // Adds SCALE codec derives (only if focused on the `struct` declaration).
+                if range_option.is_none() {
+                    add_result(entity::add_scale_derive(
+                        &event,
+                        ActionKind::Refactor,
+                        file.used_ink_version_features(),
+                    ));
+                }
+            }
+
+            // Adds getters for ink! storage fields (only if focused on the `struct` declaration).
+            let is_storage = ink_analyzer_ir::ink_attrs(struct_item.syntax())
+                .any(|attr| *attr.kind() == InkAttributeKind::Arg(InkArgKind::Storage));
+            if is_storage && range_option.is_none() {
+                if let Some(contract) =
+                    ink_analyzer_ir::ink_ancestor_of_kind::<Contract>(struct_item.syntax())
+                {
+                    add_result(entity::add_getters_for_storage(
+                        &contract,
+                        ActionKind::Refactor,
+                    ));
+                }
+            }
+        }
+        ast::Item::Fn(fn_item) => {
+            // Migrates implicit ink! storage initialization (only if focused on the `fn` declaration).
+            if range_option.is_none() {
+                if let Some(constructor) = ink_analyzer_ir::ink_attrs(fn_item.syntax())
+                    .find(|attr| *attr.kind() == InkAttributeKind::Arg(InkArgKind::Constructor))
+                    .and_then(ink_analyzer_ir::ink_attr_to_entity::<Constructor>)
+                {
+                    add_result(entity::migrate_constructor_to_ink4(
+                        &constructor,
+                        ActionKind::Refactor,
+                    ));
+                }
             }
         }
         // Ignores other items.
@@ -1294,6 +1383,304 @@ mod tests {
                     },
                 ],
             ),
+            (
+                r#"
+                    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+                    pub struct MyType {}
+
+                    #[ink(event)]
+                    struct MyEvent {
+                    }
+                "#,
+                Some("<-struct MyEvent"),
+                vec![
+                    TestResultAction {
+                        label: "Add",
+                        edits: vec![TestResultTextRange {
+                            text: ", anonymous",
+                            start_pat: Some("#[ink(event"),
+                            end_pat: Some("#[ink(event"),
+                        }],
+                    },
+                    // Adds ink! topic `field`.
+                    TestResultAction {
+                        label: "Add",
+                        edits: vec![TestResultTextRange {
+                            text: "#[ink(topic)]",
+                            start_pat: Some("struct MyEvent {"),
+                            end_pat: Some("struct MyEvent {"),
+                        }],
+                    },
+                    // Adds SCALE codec derives (i.e. ink! 5.x is detected).
+                    TestResultAction {
+                        label: "scale_derive",
+                        edits: vec![TestResultTextRange {
+                            text: "#[ink::scale_derive(Encode, Decode, TypeInfo)]",
+                            start_pat: Some("<-struct MyEvent"),
+                            end_pat: Some("<-struct MyEvent"),
+                        }],
+                    },
+                ],
+            ),
+            (
+                r#"
+                    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+                    pub struct MyType {}
+
+                    #[ink(event)]
+                    #[derive(scale::Encode, scale::Decode)]
+                    struct MyEvent {
+                    }
+                "#,
+                Some("<-struct MyEvent"),
+                vec![
+                    TestResultAction {
+                        label: "Add",
+                        edits: vec![TestResultTextRange {
+                            text: ", anonymous",
+                            start_pat: Some("#[ink(event"),
+                            end_pat: Some("#[ink(event"),
+                        }],
+                    },
+                    // Adds ink! topic `field`.
+                    TestResultAction {
+                        label: "Add",
+                        edits: vec![TestResultTextRange {
+                            text: "#[ink(topic)]",
+                            start_pat: Some("struct MyEvent {"),
+                            end_pat: Some("struct MyEvent {"),
+                        }],
+                    },
+                ],
+            ),
+            (
+                r#"
+                    #[ink::contract]
+                    mod my_contract {
+                        #[ink(storage)]
+                        pub struct MyContract {
+                        }
+                    }
+                "#,
+                Some("<-struct"),
+                vec![],
+            ),
+            (
+                r#"
+                    #[ink::contract]
+                    mod my_contract {
+                        #[ink(storage)]
+                        pub struct MyContract {
+                            value: bool,
+                        }
+                    }
+                "#,
+                Some("<-struct"),
+                vec![
+                    // Adds getter ink! message `fn`s.
+                    TestResultAction {
+                        label: "Add getter",
+                        edits: vec![TestResultTextRange {
+                            text: "pub fn get_value(&self) -> bool {",
+                            start_pat: Some("value: bool,\n                        }"),
+                            end_pat: Some("value: bool,\n                        }"),
+                        }],
+                    },
+                ],
+            ),
+            (
+                r#"
+                    #[ink::contract]
+                    mod my_contract {
+                        #[ink(storage)]
+                        pub struct MyContract {
+                            value: bool,
+                            owner: AccountId,
+                            name: String,
+                            balances: Mapping<AccountId, Balance>,
+                        }
+
+                        impl MyContract {
+                            #[ink(message)]
+                            pub fn get_owner(&self) -> AccountId {
+                                self.owner
+                            }
+                        }
+                    }
+                "#,
+                Some("<-struct"),
+                vec![
+                    // Adds getter ink! message `fn`s.
+                    TestResultAction {
+                        label: "Add getter",
+                        edits: vec![
+                            TestResultTextRange {
+                                text: "pub fn get_value(&self) -> bool {",
+                                start_pat: Some("self.owner\n                            }"),
+                                end_pat: Some("self.owner\n                            }"),
+                            },
+                            TestResultTextRange {
+                                text: "self.name.clone()",
+                                start_pat: Some("self.owner\n                            }"),
+                                end_pat: Some("self.owner\n                            }"),
+                            },
+                        ],
+                    },
+                ],
+            ),
+            (
+                r#"
+                    #[ink::contract]
+                    mod my_contract {
+                        #[ink(storage)]
+                        pub struct MyContract {
+                            value: bool,
+                        }
+
+                        impl MyContract {
+                            #[ink(constructor)]
+                            pub fn new(value: bool) -> Self {
+                                Self::new_default(value)
+                            }
+                        }
+                    }
+                "#,
+                Some("<-pub fn new"),
+                vec![
+                    TestResultAction {
+                        label: "Add",
+                        edits: vec![TestResultTextRange {
+                            text: ", default",
+                            start_pat: Some("#[ink(constructor"),
+                            end_pat: Some("#[ink(constructor"),
+                        }],
+                    },
+                    TestResultAction {
+                        label: "Add",
+                        edits: vec![TestResultTextRange {
+                            text: ", payable",
+                            start_pat: Some("#[ink(constructor"),
+                            end_pat: Some("#[ink(constructor"),
+                        }],
+                    },
+                    TestResultAction {
+                        label: "Add",
+                        edits: vec![TestResultTextRange {
+                            text: ", selector = 1",
+                            start_pat: Some("#[ink(constructor"),
+                            end_pat: Some("#[ink(constructor"),
+                        }],
+                    },
+                    // Initializes ink! storage fields explicitly.
+                    TestResultAction {
+                        label: "Initialize",
+                        edits: vec![TestResultTextRange {
+                            text: "Self {\n                                    value,\n                                }",
+                            start_pat: Some("<-Self::new_default(value)"),
+                            end_pat: Some("Self::new_default(value)"),
+                        }],
+                    },
+                ],
+            ),
+            (
+                r#"
+                    #[ink::contract]
+                    mod my_contract {
+                        #[ink(storage)]
+                        pub struct MyContract {
+                            value: bool,
+                            owner: AccountId,
+                            total_supply: Balance,
+                        }
+
+                        impl MyContract {
+                            #[ink(constructor)]
+                            pub fn new(total_supply: Balance) -> Self {}
+                        }
+                    }
+                "#,
+                Some("<-pub fn new"),
+                vec![
+                    TestResultAction {
+                        label: "Add",
+                        edits: vec![TestResultTextRange {
+                            text: ", default",
+                            start_pat: Some("#[ink(constructor"),
+                            end_pat: Some("#[ink(constructor"),
+                        }],
+                    },
+                    TestResultAction {
+                        label: "Add",
+                        edits: vec![TestResultTextRange {
+                            text: ", payable",
+                            start_pat: Some("#[ink(constructor"),
+                            end_pat: Some("#[ink(constructor"),
+                        }],
+                    },
+                    TestResultAction {
+                        label: "Add",
+                        edits: vec![TestResultTextRange {
+                            text: ", selector = 1",
+                            start_pat: Some("#[ink(constructor"),
+                            end_pat: Some("#[ink(constructor"),
+                        }],
+                    },
+                    // Initializes ink! storage fields explicitly.
+                    TestResultAction {
+                        label: "Initialize",
+                        edits: vec![TestResultTextRange {
+                            text: "{\n                                Self {\n                                    value: Default::default(),\n                                    owner: Default::default(),\n                                    total_supply,\n                                }\n                            }",
+                            start_pat: Some("<-{}"),
+                            end_pat: Some("{}"),
+                        }],
+                    },
+                ],
+            ),
+            (
+                r#"
+                    #[ink::contract]
+                    mod my_contract {
+                        #[ink(storage)]
+                        pub struct MyContract {
+                            value: bool,
+                        }
+
+                        impl MyContract {
+                            #[ink(constructor)]
+                            pub fn new(value: bool) -> Self {
+                                Self { value }
+                            }
+                        }
+                    }
+                "#,
+                Some("<-pub fn new"),
+                vec![
+                    TestResultAction {
+                        label: "Add",
+                        edits: vec![TestResultTextRange {
+                            text: ", default",
+                            start_pat: Some("#[ink(constructor"),
+                            end_pat: Some("#[ink(constructor"),
+                        }],
+                    },
+                    TestResultAction {
+                        label: "Add",
+                        edits: vec![TestResultTextRange {
+                            text: ", payable",
+                            start_pat: Some("#[ink(constructor"),
+                            end_pat: Some("#[ink(constructor"),
+                        }],
+                    },
+                    TestResultAction {
+                        label: "Add",
+                        edits: vec![TestResultTextRange {
+                            text: ", selector = 1",
+                            start_pat: Some("#[ink(constructor"),
+                            end_pat: Some("#[ink(constructor"),
+                        }],
+                    },
+                ],
+            ),
             (
                 r#"
                     #[ink(anonymous)]
@@ -1892,6 +2279,132 @@ mod tests {
                     }],
                 }],
             ),
+            // ink! message in an `impl` block without an ink! contract parent.
+            (
+                r#"
+                    impl MyContract {
+                        #[ink(message)]
+                        pub fn my_message(&self) {}
+                    }
+                "#,
+                Some("<-pub fn my_message"),
+                vec![
+                    TestResultAction {
+                        label: "Add",
+                        edits: vec![TestResultTextRange {
+                            text: ", default",
+                            start_pat: Some("#[ink(message"),
+                            end_pat: Some("#[ink(message"),
+                        }],
+                    },
+                    TestResultAction {
+                        label: "Add",
+                        edits: vec![TestResultTextRange {
+                            text: ", payable",
+                            start_pat: Some("#[ink(message"),
+                            end_pat: Some("#[ink(message"),
+                        }],
+                    },
+                    TestResultAction {
+                        label: "Add",
+                        edits: vec![TestResultTextRange {
+                            text: ", selector = 1",
+                            start_pat: Some("#[ink(message"),
+                            end_pat: Some("#[ink(message"),
+                        }],
+                    },
+                    TestResultAction {
+                        label: "Add `#[ink(impl)]` attribute to the enclosing `impl` block",
+                        edits: vec![TestResultTextRange {
+                            text: "#[ink(impl)]\n                    ",
+                            start_pat: Some("<-impl MyContract {"),
+                            end_pat: Some("<-impl MyContract {"),
+                        }],
+                    },
+                ],
+            ),
+            (
+                r#"
+                    /// An `impl` block.
+                    #[cfg(feature = "foo")]
+                    impl MyContract {
+                        #[ink(constructor)]
+                        pub fn new() -> Self {}
+                    }
+                "#,
+                Some("<-pub fn new"),
+                vec![
+                    TestResultAction {
+                        label: "Add",
+                        edits: vec![TestResultTextRange {
+                            text: ", default",
+                            start_pat: Some("#[ink(constructor"),
+                            end_pat: Some("#[ink(constructor"),
+                        }],
+                    },
+                    TestResultAction {
+                        label: "Add",
+                        edits: vec![TestResultTextRange {
+                            text: ", payable",
+                            start_pat: Some("#[ink(constructor"),
+                            end_pat: Some("#[ink(constructor"),
+                        }],
+                    },
+                    TestResultAction {
+                        label: "Add",
+                        edits: vec![TestResultTextRange {
+                            text: ", selector = 1",
+                            start_pat: Some("#[ink(constructor"),
+                            end_pat: Some("#[ink(constructor"),
+                        }],
+                    },
+                    TestResultAction {
+                        label: "Add `#[ink(impl)]` attribute to the enclosing `impl` block",
+                        edits: vec![TestResultTextRange {
+                            text: "#[ink(impl)]\n                    ",
+                            start_pat: Some("<-impl MyContract {"),
+                            end_pat: Some("<-impl MyContract {"),
+                        }],
+                    },
+                ],
+            ),
+            // ink! message in an ink! `impl` block (i.e. no ink! `impl` attribute action).
+            (
+                r#"
+                    #[ink(impl)]
+                    impl MyContract {
+                        #[ink(message)]
+                        pub fn my_message(&self) {}
+                    }
+                "#,
+                Some("<-pub fn my_message"),
+                vec![
+                    TestResultAction {
+                        label: "Add",
+                        edits: vec![TestResultTextRange {
+                            text: ", default",
+                            start_pat: Some("#[ink(message"),
+                            end_pat: Some("#[ink(message"),
+                        }],
+                    },
+                    TestResultAction {
+                        label: "Add",
+                        edits: vec![TestResultTextRange {
+                            text: ", payable",
+                            start_pat: Some("#[ink(message"),
+                            end_pat: Some("#[ink(message"),
+                        }],
+                    },
+                    TestResultAction {
+                        label: "Add",
+                        edits: vec![TestResultTextRange {
+                            text: ", selector = 1",
+                            start_pat: Some("#[ink(message"),
+                            end_pat: Some("#[ink(message"),
+                        }],
+                    },
+                ],
+            ),
             (
                 r#"
                     #[ink(impl)]
@@ -1946,6 +2459,115 @@ mod tests {
         }
     }
 
+    #[test]
+    fn extract_trait_definition_works() {
+        let code = r#"
+#[ink::contract]
+mod my_contract {
+    #[ink(storage)]
+    pub struct MyContract {
+        value: bool,
+    }
+
+    impl MyContract {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self { value: false }
+        }
+    }
+
+    impl MyContract {
+        #[ink(message)]
+        pub fn get(&self) -> bool {
+            self.value
+        }
+
+        #[ink(message, payable)]
+        pub fn set(&mut self, value: bool) {
+            self.value = value;
+        }
+    }
+}
+"#;
+        let expected_code = r#"
+#[ink::trait_definition]
+pub trait MyContractTrait {
+    #[ink(message)]
+    fn get(&self) -> bool;
+
+    #[ink(message, payable)]
+    fn set(&mut self, value: bool);
+}
+
+#[ink::contract]
+mod my_contract {
+    #[ink(storage)]
+    pub struct MyContract {
+        value: bool,
+    }
+
+    impl MyContract {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self { value: false }
+        }
+    }
+
+    impl super::MyContractTrait for MyContract {
+        #[ink(message)]
+        fn get(&self) -> bool {
+            self.value
+        }
+
+        #[ink(message, payable)]
+        fn set(&mut self, value: bool) {
+            self.value = value;
+        }
+    }
+}
+"#;
+        let label = "Extract ink! messages";
+        let extract_actions = |pat: &str| {
+            let offset = TextSize::from(parse_offset_at(code, Some(pat)).unwrap() as u32);
+            let mut results = Vec::new();
+            actions(
+                &mut results,
+                &InkFile::parse(code),
+                TextRange::new(offset, offset),
+            );
+            results
+                .into_iter()
+                .filter(|action| action.label.starts_with(label))
+                .collect::<Vec<Action>>()
+        };
+
+        // Not offered for `impl` blocks with ink! constructors, nor when focused on the `impl` body.
+        assert!(extract_actions("<-impl MyContract {\n        #[ink(constructor)]").is_empty());
+        assert!(extract_actions("<-pub fn get").is_empty());
+
+        let results = extract_actions("<-impl MyContract {\n        #[ink(message)]");
+        assert_eq!(results.len(), 1);
+
+        // Applies edits (in reverse order so that offsets of preceding edits remain valid).
+        let mut output = code.to_string();
+        for edit in results[0].edits.iter().rev() {
+            output.replace_range(
+                usize::from(edit.range.start())..usize::from(edit.range.end()),
+                &edit.text,
+            );
+        }
+        assert_eq!(output, expected_code);
+
+        // Verifies that the resulting code is syntactically valid and a valid ink! contract.
+        assert!(ink_analyzer_ir::syntax::SourceFile::parse(&output)
+            .errors()
+            .is_empty());
+        assert!(crate::Analysis::new(&output)
+            .diagnostics()
+            .iter()
+            .all(|diagnostic| diagnostic.severity != crate::Severity::Error));
+    }
+
     #[test]
     fn is_focused_on_item_declaration_and_body_works() {
         for (code, test_cases) in [
@@ -0,0 +1,303 @@
+//! ink! call hierarchy.
+
+use ink_analyzer_ir::ast::HasName;
+use ink_analyzer_ir::syntax::{AstNode, TextRange, TextSize};
+use ink_analyzer_ir::{ast, Constructor, Contract, InkEntity, InkFile, Message};
+
+use super::SymbolKind;
+use crate::analysis::utils as analysis_utils;
+
+/// An ink! call hierarchy item (i.e. an ink! constructor or ink! message).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CallHierarchyItem {
+    /// Name of the ink! callable.
+    pub name: String,
+    /// Kind of the ink! callable (i.e. `SymbolKind::Constructor` or `SymbolKind::Message`).
+    pub kind: SymbolKind,
+    /// Range of the entire `fn` item (including attributes and rustdoc).
+    pub range: TextRange,
+    /// Range of the name of the `fn` item.
+    pub selection_range: TextRange,
+}
+
+/// An incoming call to an ink! callable (i.e. from another ink! constructor or ink! message).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CallHierarchyIncomingCall {
+    /// The calling ink! callable.
+    pub from: CallHierarchyItem,
+    /// Ranges of the names of the called function in the body of the calling ink! callable.
+    pub from_ranges: Vec<TextRange>,
+}
+
+/// Returns the call hierarchy item for the ink! constructor or ink! message at the given offset (if any).
+pub fn prepare_call_hierarchy(file: &InkFile, offset: TextSize) -> Option<CallHierarchyItem> {
+    let item_at_offset = file.item_at_offset(offset);
+    let fn_item = item_at_offset
+        .focused_token()?
+        .parent_ancestors()
+        .find_map(ast::Fn::cast)?;
+    let kind = callable_kind(&fn_item)?;
+    call_hierarchy_item(&fn_item, kind)
+}
+
+/// Returns the incoming calls (in the same ink! contract) to the ink! constructor or ink! message
+/// at the given offset (if any).
+///
+/// **NOTE**: This is a best-effort static analysis that only considers calls from
+/// the bodies of ink! constructors and ink! messages that match the callee by name,
+/// i.e. `self.name(..)`, `Self::name(..)` and `<StorageName>::name(..)`.
+/// Cross-contract calls (e.g. via contract references or call builders) are not tracked.
+pub fn call_hierarchy_incoming(file: &InkFile, offset: TextSize) -> Vec<CallHierarchyIncomingCall> {
+    let Some(target) = prepare_call_hierarchy(file, offset) else {
+        return Vec::new();
+    };
+    let Some(contract) = file
+        .syntax()
+        .covering_element(target.range)
+        .into_node()
+        .and_then(|node| ink_analyzer_ir::ink_ancestor_of_kind::<Contract>(&node))
+    else {
+        return Vec::new();
+    };
+
+    // Groups references by the calling ink! constructor or ink! message.
+    let mut results: Vec<CallHierarchyIncomingCall> = Vec::new();
+    for name_ref in analysis_utils::callable_references(&contract, &target.name) {
+        let Some(fn_item) = name_ref.syntax().ancestors().find_map(ast::Fn::cast) else {
+            continue;
+        };
+        let is_in_body = fn_item.body().is_some_and(|body| {
+            body.syntax()
+                .text_range()
+                .contains_range(name_ref.syntax().text_range())
+        });
+        let Some(kind) = callable_kind(&fn_item).filter(|_| is_in_body) else {
+            continue;
+        };
+        let range = name_ref.syntax().text_range();
+        let fn_range = fn_item.syntax().text_range();
+        match results.iter_mut().find(|call| call.from.range == fn_range) {
+            Some(call) => call.from_ranges.push(range),
+            None => {
+                if let Some(from) = call_hierarchy_item(&fn_item, kind) {
+                    results.push(CallHierarchyIncomingCall {
+                        from,
+                        from_ranges: vec![range],
+                    });
+                }
+            }
+        }
+    }
+    results.sort_by_key(|call| call.from.range.start());
+    results
+}
+
+/// Returns the symbol kind of an ink! callable `fn` item (if any).
+fn callable_kind(fn_item: &ast::Fn) -> Option<SymbolKind> {
+    if Message::can_cast(fn_item.syntax()) {
+        Some(SymbolKind::Message)
+    } else if Constructor::can_cast(fn_item.syntax()) {
+        Some(SymbolKind::Constructor)
+    } else {
+        None
+    }
+}
+
+/// Returns the call hierarchy item for an ink! callable `fn` item.
+fn call_hierarchy_item(fn_item: &ast::Fn, kind: SymbolKind) -> Option<CallHierarchyItem> {
+    let name = fn_item.name()?;
+    Some(CallHierarchyItem {
+        name: name.to_string(),
+        kind,
+        range: fn_item.syntax().text_range(),
+        selection_range: name.syntax().text_range(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_utils::parse_offset_at;
+
+    const CODE: &str = r#"
+        #[ink::contract]
+        mod my_contract {
+            #[ink(storage)]
+            pub struct MyContract {
+                value: bool,
+            }
+
+            impl MyContract {
+                #[ink(constructor)]
+                pub fn new() -> Self {
+                    let mut contract = Self { value: false };
+                    contract.flip();
+                    contract
+                }
+
+                #[ink(message)]
+                pub fn flip(&mut self) {
+                    self.value = !self.get();
+                }
+
+                #[ink(message)]
+                pub fn get(&self) -> bool {
+                    self.value
+                }
+
+                #[ink(message)]
+                pub fn double_flip(&mut self) {
+                    self.flip();
+                    Self::flip(self);
+                    MyContract::flip(self);
+                    other::flip();
+                }
+            }
+        }
+
+        #[ink::contract]
+        mod other_contract {
+            #[ink(storage)]
+            pub struct OtherContract {}
+
+            impl OtherContract {
+                #[ink(message)]
+                pub fn flip(&mut self) {}
+
+                #[ink(message)]
+                pub fn flip_twice(&mut self) {
+                    self.flip();
+                    self.flip();
+                }
+            }
+        }
+    "#;
+
+    fn range(code: &str, start_pat: &str, end_pat: &str) -> TextRange {
+        TextRange::new(
+            TextSize::from(parse_offset_at(code, Some(start_pat)).unwrap() as u32),
+            TextSize::from(parse_offset_at(code, Some(end_pat)).unwrap() as u32),
+        )
+    }
+
+    #[test]
+    fn prepare_call_hierarchy_works() {
+        let file = InkFile::parse(CODE);
+
+        for (pat, expected_result) in [
+            // (pat, Option<(name, kind, range_start_pat, range_end_pat, name_start_pat)>) where:
+            // pat = substring used to find the cursor offset (see `test_utils::parse_offset_at` doc),
+            // name = name of the ink! callable,
+            // kind = kind of the ink! callable,
+            // range_start_pat/range_end_pat = substrings used to find the range of the `fn` item,
+            // name_start_pat = substring used to find the start of the name of the `fn` item.
+            (
+                "pub fn fl",
+                Some((
+                    "flip",
+                    SymbolKind::Message,
+                    "<-#[ink(message)]\n                pub fn flip",
+                    "self.value = !self.get();\n                }",
+                    "<-flip(&mut self)",
+                )),
+            ),
+            (
+                "self.value = !",
+                Some((
+                    "flip",
+                    SymbolKind::Message,
+                    "<-#[ink(message)]\n                pub fn flip",
+                    "self.value = !self.get();\n                }",
+                    "<-flip(&mut self)",
+                )),
+            ),
+            (
+                "pub fn ne",
+                Some((
+                    "new",
+                    SymbolKind::Constructor,
+                    "<-#[ink(constructor)]",
+                    "contract\n                }",
+                    "<-new()",
+                )),
+            ),
+            // Not an ink! callable.
+            ("pub struct My", None),
+            ("<-#[ink::contract]", None),
+        ] {
+            let offset = TextSize::from(parse_offset_at(CODE, Some(pat)).unwrap() as u32);
+            assert_eq!(
+                prepare_call_hierarchy(&file, offset),
+                expected_result.map(|(name, kind, range_start, range_end, name_start)| {
+                    CallHierarchyItem {
+                        name: name.to_string(),
+                        kind,
+                        range: range(CODE, range_start, range_end),
+                        selection_range: TextRange::at(
+                            TextSize::from(parse_offset_at(CODE, Some(name_start)).unwrap() as u32),
+                            TextSize::of(name),
+                        ),
+                    }
+                }),
+                "pat: {pat}"
+            );
+        }
+    }
+
+    #[test]
+    fn call_hierarchy_incoming_works() {
+        let file = InkFile::parse(CODE);
+        let results_for = |pat: &str| {
+            let offset = TextSize::from(parse_offset_at(CODE, Some(pat)).unwrap() as u32);
+            call_hierarchy_incoming(&file, offset)
+                .into_iter()
+                .map(|call| (call.from.name, call.from_ranges))
+                .collect::<Vec<(String, Vec<TextRange>)>>()
+        };
+
+        // Calls via `self`, `Self` and the storage name (but not via other receivers or qualifiers),
+        // and only from the same ink! contract.
+        assert_eq!(
+            results_for("pub fn fl"),
+            vec![(
+                "double_flip".to_string(),
+                vec![
+                    range(CODE, "<-flip();\n                    Self", "self.flip"),
+                    range(CODE, "<-flip(self);", "Self::flip"),
+                    range(
+                        CODE,
+                        "<-flip(self);\n                    other",
+                        "MyContract::flip"
+                    ),
+                ]
+            )]
+        );
+        // Multiple calls from the same ink! message.
+        assert_eq!(
+            results_for("pub fn flip(&mut self) {}"),
+            vec![(
+                "flip_twice".to_string(),
+                vec![
+                    range(
+                        CODE,
+                        "<-flip();\n                    self.flip();",
+                        "flip_twice(&mut self) {\n                    self.flip"
+                    ),
+                    range(CODE, "<-flip();->", "self.flip->"),
+                ]
+            )]
+        );
+        // Calls from other ink! messages.
+        assert_eq!(
+            results_for("pub fn ge"),
+            vec![(
+                "flip".to_string(),
+                vec![range(CODE, "<-get();", "!self.get")]
+            )]
+        );
+        // No incoming calls.
+        assert!(results_for("pub fn doub").is_empty());
+        // Not an ink! callable.
+        assert!(results_for("pub struct My").is_empty());
+    }
+}
@@ -0,0 +1,187 @@
+//! ink! code lenses.
+
+use ink_analyzer_ir::ast::HasAttrs;
+use ink_analyzer_ir::syntax::{AstNode, TextRange};
+use ink_analyzer_ir::{ast, Contract, InkE2ETest, InkEntity, InkFile, InkImpl, InkTest};
+
+use crate::analysis::utils;
+
+/// An ink! code lens (i.e. a summary shown inline above an ink! entity).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CodeLens {
+    /// Range of the item the code lens is shown for.
+    pub range: TextRange,
+    /// Text of the code lens.
+    pub title: String,
+}
+
+/// Computes ink! code lenses for the file.
+///
+/// Shows constructor and message counts for ink! contracts (above the `#[ink::contract]` attribute)
+/// and for ink! impls (above the `impl` declaration), and ink! test counts for
+/// `#[cfg(test)]` modules (above the `cfg` attribute).
+pub fn code_lens(file: &InkFile) -> Vec<CodeLens> {
+    let contract_lenses = file.contracts().iter().filter_map(contract_lens);
+    let impl_lenses = file
+        .syntax()
+        .descendants()
+        .filter_map(InkImpl::cast)
+        .filter_map(|ink_impl| impl_lens(&ink_impl));
+    let test_module_lenses = file
+        .syntax()
+        .descendants()
+        .filter_map(ast::Module::cast)
+        .filter_map(|module| test_module_lens(&module));
+
+    let mut results: Vec<CodeLens> = contract_lenses
+        .chain(impl_lenses)
+        .chain(test_module_lenses)
+        .collect();
+    results.sort_by_key(|lens| lens.range.start());
+    results
+}
+
+/// Computes the code lens for an ink! contract.
+fn contract_lens(contract: &Contract) -> Option<CodeLens> {
+    Some(CodeLens {
+        range: contract.ink_attr()?.syntax().text_range(),
+        title: format!(
+            "{} · {}",
+            pluralize(contract.constructors().len(), "constructor"),
+            pluralize(contract.messages().len(), "message")
+        ),
+    })
+}
+
+/// Computes the code lens for an ink! impl (if it has any ink! callables).
+fn impl_lens(ink_impl: &InkImpl) -> Option<CodeLens> {
+    let n_constructors = ink_impl.constructors().len();
+    let n_messages = ink_impl.messages().len();
+    let title = match (n_constructors, n_messages) {
+        (0, 0) => return None,
+        (0, _) => pluralize(n_messages, "message"),
+        (_, 0) => pluralize(n_constructors, "constructor"),
+        _ => format!(
+            "{} · {}",
+            pluralize(n_constructors, "constructor"),
+            pluralize(n_messages, "message")
+        ),
+    };
+    Some(CodeLens {
+        range: utils::ink_impl_declaration_range(ink_impl),
+        title,
+    })
+}
+
+/// Computes the code lens for a `#[cfg(test)]` module (if the module is a test module).
+fn test_module_lens(module: &ast::Module) -> Option<CodeLens> {
+    let cfg_test_attr = module.attrs().find(utils::is_cfg_test_attr)?;
+    let n_tests = ink_analyzer_ir::ink_descendants::<InkTest>(module.syntax()).count()
+        + ink_analyzer_ir::ink_descendants::<InkE2ETest>(module.syntax()).count();
+    Some(CodeLens {
+        range: cfg_test_attr.syntax().text_range(),
+        title: pluralize(n_tests, "test"),
+    })
+}
+
+/// Returns the count followed by the singular or plural form of the noun.
+fn pluralize(count: usize, noun: &str) -> String {
+    format!("{count} {noun}{}", if count == 1 { "" } else { "s" })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_utils::parse_offset_at;
+
+    #[test]
+    fn fixtures_works() {
+        for (location, expected_results) in [
+            (
+                "contracts/erc20",
+                vec![
+                    ("#[ink::contract]", "1 constructor · 6 messages"),
+                    ("impl Erc20", "1 constructor · 6 messages"),
+                    ("#[cfg(test)]", "7 tests"),
+                    (r#"#[cfg(all(test, feature = "e2e-tests"))]"#, "2 tests"),
+                ],
+            ),
+            (
+                "contracts/flipper",
+                vec![
+                    ("#[ink::contract]", "2 constructors · 2 messages"),
+                    ("impl Flipper", "2 constructors · 2 messages"),
+                    ("#[cfg(test)]", "2 tests"),
+                    (r#"#[cfg(all(test, feature = "e2e-tests"))]"#, "2 tests"),
+                ],
+            ),
+        ] {
+            let code = test_utils::read_source_code(location);
+            let results = code_lens(&InkFile::parse(&code));
+
+            assert_eq!(
+                results
+                    .iter()
+                    .map(|lens| lens.title.as_str())
+                    .collect::<Vec<&str>>(),
+                expected_results
+                    .iter()
+                    .map(|(_, title)| *title)
+                    .collect::<Vec<&str>>(),
+                "fixture: {location}"
+            );
+            for (lens, (start_pat, _)) in results.iter().zip(expected_results) {
+                let start = parse_offset_at(&code, Some(&format!("<-{start_pat}"))).unwrap();
+                assert_eq!(
+                    u32::from(lens.range.start()),
+                    start as u32,
+                    "fixture: {location}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn impl_lens_works() {
+        let code = r#"
+            #[ink::contract]
+            mod my_contract {
+                impl MyContract {
+                    #[ink(constructor)]
+                    pub fn new() -> Self {}
+                }
+
+                impl MyContract {
+                    #[ink(message)]
+                    pub fn my_message(&self) {}
+
+                    #[ink(message)]
+                    pub fn my_message_2(&self) {}
+
+                    #[ink(message)]
+                    pub fn my_message_3(&self) {}
+                }
+
+                #[ink(impl)]
+                impl MyContract {}
+
+                #[cfg(test)]
+                mod tests {}
+            }
+        "#;
+        let results = code_lens(&InkFile::parse(code));
+
+        assert_eq!(
+            results
+                .iter()
+                .map(|lens| lens.title.as_str())
+                .collect::<Vec<&str>>(),
+            [
+                "1 constructor · 3 messages",
+                "1 constructor",
+                "3 messages",
+                "0 tests"
+            ]
+        );
+    }
+}
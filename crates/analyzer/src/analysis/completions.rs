@@ -1,7 +1,13 @@
 //! ink! attribute completions.
 
-use ink_analyzer_ir::syntax::{AstNode, SyntaxKind, SyntaxToken, TextRange, TextSize};
-use ink_analyzer_ir::{InkArgKind, InkAttributeKind, InkEntity, InkFile, InkMacroKind};
+use ink_analyzer_ir::ast::HasName;
+use ink_analyzer_ir::syntax::{
+    AstNode, AstToken, SyntaxKind, SyntaxNode, SyntaxToken, TextRange, TextSize,
+};
+use ink_analyzer_ir::{
+    ast, Constructor, Contract, InkArg, InkArgKind, InkAttribute, InkAttributeKind, InkEntity,
+    InkFile, InkMacroKind, IsInkCallable, Message,
+};
 
 use super::utils;
 use crate::analysis::text_edit::TextEdit;
@@ -19,15 +25,132 @@ pub struct Completion {
     pub detail: Option<String>,
 }
 
+/// The context in which ink! attribute completions are computed.
+///
+/// **NOTE**: The context only depends on the file and offset,
+/// so it can be cached and reused across multiple completion requests at the same position.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CompletionContext {
+    /// How the completion was (most likely) triggered.
+    pub trigger_kind: CompletionTriggerKind,
+    /// The ink! attribute (if any) that covers the offset.
+    pub covering_attribute: Option<InkAttribute>,
+    /// The ink! attribute of the closest ink! entity (if any) that's a parent of the offset.
+    ///
+    /// **NOTE**: For offsets in an ink! attribute,
+    /// this is the parent ink! entity of the item annotated by the ink! attribute.
+    pub parent_entity: Option<InkAttribute>,
+    /// The composed selectors of all ink! constructors and ink! messages in the parent ink! contract (if any).
+    pub existing_selectors: Vec<[u8; 4]>,
+    /// The explicit (i.e. manually provided integer) selectors of all ink! constructors and ink! messages
+    /// in the parent ink! contract (if any).
+    pub explicit_selectors: Vec<u32>,
+}
+
+/// How a completion was (most likely) triggered.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CompletionTriggerKind {
+    /// Completion was invoked (e.g. by typing an identifier or explicitly by the user).
+    #[default]
+    Invoked,
+    /// Completion was triggered by typing a trigger character (i.e. `[`, `(`, `,` or `:`).
+    TriggerCharacter(char),
+}
+
+/// Prepares the context for computing ink! attribute completions at the given offset.
+///
+/// Returns `None` if there's no token at the offset.
+pub fn prepare_completions(file: &InkFile, offset: TextSize) -> Option<CompletionContext> {
+    let item_at_offset = file.item_at_offset(offset);
+    let focused_token = item_at_offset.focused_token()?;
+
+    let trigger_kind = match item_at_offset
+        .focused_token_prefix()
+        .and_then(|prefix| prefix.chars().last())
+    {
+        Some(c @ ('[' | '(' | ',' | ':')) => CompletionTriggerKind::TriggerCharacter(c),
+        _ => CompletionTriggerKind::Invoked,
+    };
+    let covering_attribute = item_at_offset
+        .normalized_parent_ink_attr()
+        .and_then(|(attr, _, is_covering)| is_covering.then_some(attr));
+    let ref_node = covering_attribute
+        .as_ref()
+        .map(|attr| attr.syntax().clone())
+        .or(focused_token.parent())?;
+    let parent_entity = match covering_attribute.as_ref() {
+        // Parent ink! entity of the item annotated by the ink! attribute.
+        Some(attr) => ink_analyzer_ir::ink_attrs_closest_ancestors(attr.syntax()).next(),
+        // Closest ink! entity that's an ancestor of (or annotates) the focused token.
+        None => focused_token
+            .parent_ancestors()
+            .find_map(|node| ink_analyzer_ir::ink_attrs(&node).next()),
+    };
+    let contract = ink_analyzer_ir::ink_ancestor_of_kind::<Contract>(&ref_node);
+    let existing_selectors = contract
+        .as_ref()
+        .map(|contract| {
+            contract
+                .constructors()
+                .iter()
+                .filter_map(IsInkCallable::composed_selector)
+                .chain(
+                    contract
+                        .messages()
+                        .iter()
+                        .filter_map(IsInkCallable::composed_selector),
+                )
+                .map(|selector| selector.to_bytes())
+                .collect()
+        })
+        .unwrap_or_default();
+    let explicit_selectors = contract
+        .as_ref()
+        .map(|contract| {
+            contract
+                .constructors()
+                .iter()
+                .filter_map(IsInkCallable::selector_arg)
+                .chain(
+                    contract
+                        .messages()
+                        .iter()
+                        .filter_map(IsInkCallable::selector_arg),
+                )
+                .filter_map(|arg| arg.as_u32())
+                .collect()
+        })
+        .unwrap_or_default();
+
+    Some(CompletionContext {
+        trigger_kind,
+        covering_attribute,
+        parent_entity,
+        existing_selectors,
+        explicit_selectors,
+    })
+}
+
 /// Computes ink! attribute completions at the given offset.
 pub fn completions(file: &InkFile, offset: TextSize) -> Vec<Completion> {
     let mut results = Vec::new();
 
+    // Only computes completions if a completion context can be prepared.
+    let Some(context) = prepare_completions(file, offset) else {
+        return results;
+    };
+
     // Compute ink! attribute macro completions.
     macro_completions(&mut results, file, offset);
 
     // Compute ink! attribute argument completions.
-    argument_completions(&mut results, file, offset);
+    argument_completions(&mut results, file, offset, &context);
+
+    // Compute ink! environment argument value completions.
+    env_value_completions(&mut results, file, offset);
+
+    // Compute ink! selector argument value completions.
+    selector_value_completions(&mut results, file, offset);
 
     results
 }
@@ -205,7 +328,10 @@ pub fn macro_completions(results: &mut Vec<Completion>, file: &InkFile, offset:
                             label: edit.clone(),
                             range: edit_range,
                             edit: TextEdit::replace(edit, edit_range),
-                            detail: Some(format!("ink! {macro_kind} attribute macro.")),
+                            detail: Some(format!(
+                                "`{}` attribute macro.",
+                                InkAttributeKind::Macro(macro_kind)
+                            )),
                         });
                     }
                 } else if prev_token_is_left_bracket && !has_other_ink_macro_siblings {
@@ -246,7 +372,15 @@ pub fn macro_completions(results: &mut Vec<Completion>, file: &InkFile, offset:
 }
 
 /// Computes ink! attribute argument completions at the given offset.
-pub fn argument_completions(results: &mut Vec<Completion>, file: &InkFile, offset: TextSize) {
+///
+/// The completion context is used to compute better default values for inserted arguments
+/// (see [`utils::ink_arg_insert_text_with_context`] doc).
+pub fn argument_completions(
+    results: &mut Vec<Completion>,
+    file: &InkFile,
+    offset: TextSize,
+    context: &CompletionContext,
+) {
     let item_at_offset = file.item_at_offset(offset);
 
     // Only computes completions if a focused token can be determined.
@@ -343,10 +477,11 @@ pub fn argument_completions(results: &mut Vec<Completion>, file: &InkFile, offse
                     } else {
                         ""
                     };
-                    let (edit, snippet) = utils::ink_arg_insert_text(
+                    let (edit, snippet) = utils::ink_arg_insert_text_with_context(
                         arg_kind,
                         Some(edit_range.end()),
                         Some(ink_attr.syntax()),
+                        context,
                     );
                     results.push(Completion {
                         label: edit.clone(),
@@ -364,6 +499,204 @@ pub fn argument_completions(results: &mut Vec<Completion>, file: &InkFile, offse
     }
 }
 
+/// Computes ink! environment argument value completions at the given offset
+/// (i.e. for values of `env` and `environment` arguments).
+///
+/// Suggests the paths of environment types that implement `ink::env::Environment` in the file
+/// (excluding those declared in ink! contracts that aren't visible from the ink! attribute),
+/// followed by `ink::env::DefaultEnvironment`.
+pub fn env_value_completions(results: &mut Vec<Completion>, file: &InkFile, offset: TextSize) {
+    // Only computes completions for ink! environment argument values (i.e. after the `=` symbol).
+    let Some((ink_attr, edit_range, prefix)) =
+        arg_value_edit_range_and_prefix(file, offset, |arg_kind| {
+            matches!(arg_kind, InkArgKind::Env | InkArgKind::Environment)
+        })
+    else {
+        return;
+    };
+
+    let mut paths = environment_paths(file, ink_attr.syntax());
+    let default_env = "ink::env::DefaultEnvironment".to_string();
+    if !paths.contains(&default_env) {
+        paths.push(default_env);
+    }
+    for path in paths {
+        if path.starts_with(&prefix) {
+            results.push(Completion {
+                label: path.clone(),
+                range: edit_range,
+                edit: TextEdit::replace(path, edit_range),
+                detail: Some("ink! environment".to_string()),
+            });
+        }
+    }
+}
+
+/// Computes ink! selector argument value completions at the given offset
+/// (i.e. for values of `selector` arguments of ink! constructors and ink! messages).
+///
+/// Suggests the selector computed from the callable's name (i.e. the implicit selector),
+/// so that it can be pinned explicitly.
+pub fn selector_value_completions(results: &mut Vec<Completion>, file: &InkFile, offset: TextSize) {
+    // Only computes completions for ink! selector argument values (i.e. after the `=` symbol).
+    let Some((ink_attr, edit_range, prefix)) =
+        arg_value_edit_range_and_prefix(file, offset, |arg_kind| *arg_kind == InkArgKind::Selector)
+    else {
+        return;
+    };
+
+    // Only ink! constructors and ink! messages have implicit selectors.
+    let computed_selector = match ink_attr.kind() {
+        InkAttributeKind::Arg(InkArgKind::Constructor) => {
+            Constructor::cast(ink_attr.syntax().clone()).and_then(|it| it.computed_selector())
+        }
+        InkAttributeKind::Arg(InkArgKind::Message) => {
+            Message::cast(ink_attr.syntax().clone()).and_then(|it| it.computed_selector())
+        }
+        _ => None,
+    };
+    let Some(computed_selector) = computed_selector else {
+        return;
+    };
+
+    let value = ink_analyzer_ir::format_selector(u32::from_be_bytes(computed_selector));
+    if value.to_lowercase().starts_with(&prefix.to_lowercase()) {
+        results.push(Completion {
+            label: value.clone(),
+            range: edit_range,
+            edit: TextEdit::replace(value, edit_range),
+            detail: Some("Current implicit selector".to_string()),
+        });
+    }
+}
+
+/// Returns the ink! attribute, the edit range and the typed prefix (without whitespace)
+/// for an ink! attribute argument value completion at the given offset (if any).
+///
+/// Only arguments of the matching kinds whose `=` symbol is before the offset are considered,
+/// and the edit range covers the current value (if any).
+fn arg_value_edit_range_and_prefix(
+    file: &InkFile,
+    offset: TextSize,
+    is_arg_kind: impl Fn(&InkArgKind) -> bool,
+) -> Option<(InkAttribute, TextRange, String)> {
+    let item_at_offset = file.item_at_offset(offset);
+    let (ink_attr, ..) = item_at_offset.normalized_parent_ink_attr()?;
+    let arg = ink_attr.args().iter().find(|arg| {
+        is_arg_kind(arg.kind())
+            && arg.text_range().contains_inclusive(offset)
+            && arg
+                .meta()
+                .eq()
+                .is_some_and(|eq| eq.syntax().text_range().end() <= offset)
+    })?;
+
+    // Replaces the current value (if any).
+    let edit_range = match arg_value_range(arg) {
+        Some(range) if range.contains_inclusive(offset) => range,
+        _ => TextRange::new(offset, offset),
+    };
+    let prefix: String = ink_attr
+        .syntax()
+        .text()
+        .slice(TextRange::new(edit_range.start(), offset) - ink_attr.syntax().text_range().start())
+        .to_string()
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect();
+    Some((ink_attr, edit_range, prefix))
+}
+
+/// Returns the text range of the value of the ink! attribute argument (if any),
+/// including invalid values (e.g. incomplete paths like `crate::`).
+fn arg_value_range(arg: &InkArg) -> Option<TextRange> {
+    match arg.meta().value().option()? {
+        Ok(value) => Some(value.text_range()),
+        Err(elems) => {
+            let mut non_trivia_elems = elems.iter().filter(|elem| !elem.kind().is_trivia());
+            let start = non_trivia_elems.next()?.text_range().start();
+            let end = non_trivia_elems
+                .last()
+                .map_or(start, |elem| elem.text_range().end());
+            Some(TextRange::new(start, end))
+        }
+    }
+}
+
+/// Returns the fully qualified paths of the environment types in the file
+/// (i.e. `struct`s and `enum`s with an `impl ink::env::Environment for ..` block)
+/// that are visible from the reference node.
+///
+/// **NOTE**: Environment types declared in ink! contracts are only included if the ink! contract
+/// is visible from the reference node (see `InkFile::contracts_visible_from` doc).
+fn environment_paths(file: &InkFile, ref_node: &SyntaxNode) -> Vec<String> {
+    let visible_contracts = file.contracts_visible_from(ref_node);
+    let mut paths = Vec::new();
+    for impl_item in file.syntax().descendants().filter_map(ast::Impl::cast) {
+        let is_env_impl = impl_item.trait_().is_some_and(|trait_type| {
+            matches!(
+                trait_type.syntax().to_string().replace(' ', "").as_str(),
+                "Environment"
+                    | "ink::env::Environment"
+                    | "::ink::env::Environment"
+                    | "ink::Environment"
+                    | "::ink::Environment"
+                    | "ink_env::Environment"
+                    | "::ink_env::Environment"
+            )
+        });
+        if !is_env_impl {
+            continue;
+        }
+        let Some(path) = impl_item.self_ty().and_then(|self_ty| match self_ty {
+            ast::Type::PathType(path_type) => path_type.path(),
+            _ => None,
+        }) else {
+            continue;
+        };
+        let Some(adt) = ink_analyzer_ir::resolve_item::<ast::Adt>(&path, impl_item.syntax()) else {
+            continue;
+        };
+        let is_visible = file
+            .contracts()
+            .iter()
+            .filter(|contract| {
+                contract
+                    .syntax()
+                    .text_range()
+                    .contains_range(adt.syntax().text_range())
+            })
+            .all(|contract| visible_contracts.contains(&contract));
+        if !is_visible {
+            continue;
+        }
+        let name = match &adt {
+            ast::Adt::Struct(it) => it.name(),
+            ast::Adt::Enum(it) => it.name(),
+            ast::Adt::Union(it) => it.name(),
+        };
+        let module_names: Option<Vec<String>> = adt
+            .syntax()
+            .ancestors()
+            .filter_map(ast::Module::cast)
+            .map(|module| module.name().map(|name| name.to_string()))
+            .collect();
+        if let Some((name, mut module_names)) = name.zip(module_names) {
+            module_names.reverse();
+            let qualified_path = ["crate".to_string()]
+                .into_iter()
+                .chain(module_names)
+                .chain([name.to_string()])
+                .collect::<Vec<String>>()
+                .join("::");
+            if !paths.contains(&qualified_path) {
+                paths.push(qualified_path);
+            }
+        }
+    }
+    paths
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1132,11 +1465,55 @@ mod tests {
                     ("selector=1", Some("("), Some("(")),
                 ],
             ),
+            // Completion context (i.e. next free selector).
+            (
+                r#"
+                    #[ink::contract]
+                    mod my_contract {
+                        impl MyContract {
+                            #[ink(message,)]
+                            pub fn my_message(&self) {}
+
+                            #[ink(message, selector = 0xCAFE0001)]
+                            pub fn my_message2(&self) {}
+                        }
+                    }
+                "#,
+                Some("#[ink(message,"),
+                vec![
+                    ("default", Some("message,"), Some("message,")),
+                    ("payable", Some("message,"), Some("message,")),
+                    ("selector=0xcafe0002", Some("message,"), Some("message,")),
+                ],
+            ),
+            (
+                r#"
+                    #[ink::contract]
+                    mod my_contract {
+                        impl MyContract {
+                            #[ink(message,)]
+                            pub fn my_message(&self) {}
+
+                            #[ink(message, selector = 0xA)]
+                            pub fn my_message2(&self) {}
+                        }
+                    }
+                "#,
+                Some("#[ink(message,"),
+                vec![
+                    ("default", Some("message,"), Some("message,")),
+                    ("payable", Some("message,"), Some("message,")),
+                    ("selector=0x0000000b", Some("message,"), Some("message,")),
+                ],
+            ),
         ] {
             let offset = TextSize::from(parse_offset_at(code, pat).unwrap() as u32);
 
+            let file = InkFile::parse(code);
+            let context = prepare_completions(&file, offset).unwrap_or_default();
+
             let mut results = Vec::new();
-            argument_completions(&mut results, &InkFile::parse(code), offset);
+            argument_completions(&mut results, &file, offset, &context);
 
             assert_eq!(
                 results
@@ -1157,4 +1534,435 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn env_value_completions_works() {
+        let env_defs = r#"
+            #[derive(Clone)]
+            pub struct MyEnvironment;
+
+            impl ink::env::Environment for MyEnvironment {}
+
+            mod envs {
+                pub enum OtherEnvironment {}
+
+                impl Environment for OtherEnvironment {}
+
+                pub struct NotAnEnvironment;
+
+                impl Clone for NotAnEnvironment {}
+            }
+        "#;
+        let hidden_env_defs = r#"
+            mod hidden {
+                #[ink::contract]
+                mod other_contract {
+                    pub struct ContractEnvironment;
+
+                    impl ink::env::Environment for ContractEnvironment {}
+                }
+
+                #[ink::contract(env=)]
+                mod my_contract {}
+            }
+        "#;
+        for (code, pat, expected_results) in [
+            // (code, pat, [(edit, pat_start, pat_end)]) where:
+            // code = source code,
+            // pat = substring used to find the cursor offset (see `test_utils::parse_offset_at` doc),
+            // edit = the text that will inserted,
+            // pat_start = substring used to find the start of the edit offset (see `test_utils::parse_offset_at` doc),
+            // pat_end = substring used to find the end of the edit offset (see `test_utils::parse_offset_at` doc).
+
+            // No custom environments.
+            (
+                "#[ink::contract(env=)]".to_string(),
+                Some("env="),
+                vec![("ink::env::DefaultEnvironment", Some("env="), Some("env="))],
+            ),
+            // Custom environments.
+            (
+                format!("{env_defs}#[ink::contract(env=)]"),
+                Some("env="),
+                vec![
+                    ("crate::MyEnvironment", Some("env="), Some("env=")),
+                    ("crate::envs::OtherEnvironment", Some("env="), Some("env=")),
+                    ("ink::env::DefaultEnvironment", Some("env="), Some("env=")),
+                ],
+            ),
+            (
+                format!("{env_defs}#[ink_e2e::test(environment = )]"),
+                Some("environment = "),
+                vec![
+                    (
+                        "crate::MyEnvironment",
+                        Some("environment = "),
+                        Some("environment = "),
+                    ),
+                    (
+                        "crate::envs::OtherEnvironment",
+                        Some("environment = "),
+                        Some("environment = "),
+                    ),
+                    (
+                        "ink::env::DefaultEnvironment",
+                        Some("environment = "),
+                        Some("environment = "),
+                    ),
+                ],
+            ),
+            // Filters by the current value.
+            (
+                format!("{env_defs}#[ink::contract(env = crate::)]"),
+                Some("env = crate::"),
+                vec![
+                    (
+                        "crate::MyEnvironment",
+                        Some("<-crate::)"),
+                        Some("env = crate::"),
+                    ),
+                    (
+                        "crate::envs::OtherEnvironment",
+                        Some("<-crate::)"),
+                        Some("env = crate::"),
+                    ),
+                ],
+            ),
+            (
+                format!("{env_defs}#[ink::contract(env = crate::My)]"),
+                Some("env = crate::My"),
+                vec![(
+                    "crate::MyEnvironment",
+                    Some("<-crate::My)"),
+                    Some("env = crate::My"),
+                )],
+            ),
+            // Ignores environments in ink! contracts that aren't visible.
+            (
+                format!("{env_defs}{hidden_env_defs}#[ink::contract(env=)]"),
+                Some("<-)]->"),
+                vec![
+                    ("crate::MyEnvironment", Some("<-)]->"), Some("<-)]->")),
+                    (
+                        "crate::envs::OtherEnvironment",
+                        Some("<-)]->"),
+                        Some("<-)]->"),
+                    ),
+                    (
+                        "ink::env::DefaultEnvironment",
+                        Some("<-)]->"),
+                        Some("<-)]->"),
+                    ),
+                ],
+            ),
+            (
+                format!("{env_defs}{hidden_env_defs}"),
+                Some("<-)]\n                mod my_contract"),
+                vec![
+                    (
+                        "crate::MyEnvironment",
+                        Some("<-)]\n                mod my_contract"),
+                        Some("<-)]\n                mod my_contract"),
+                    ),
+                    (
+                        "crate::envs::OtherEnvironment",
+                        Some("<-)]\n                mod my_contract"),
+                        Some("<-)]\n                mod my_contract"),
+                    ),
+                    (
+                        "crate::hidden::other_contract::ContractEnvironment",
+                        Some("<-)]\n                mod my_contract"),
+                        Some("<-)]\n                mod my_contract"),
+                    ),
+                    (
+                        "ink::env::DefaultEnvironment",
+                        Some("<-)]\n                mod my_contract"),
+                        Some("<-)]\n                mod my_contract"),
+                    ),
+                ],
+            ),
+            // Not an environment argument value.
+            (
+                format!("{env_defs}#[ink::contract(env=)]"),
+                Some("#[ink::contract("),
+                vec![],
+            ),
+            (
+                format!("{env_defs}#[ink::contract(keep_attr=)]"),
+                Some("keep_attr="),
+                vec![],
+            ),
+        ] {
+            let offset = TextSize::from(parse_offset_at(&code, pat).unwrap() as u32);
+
+            let mut results = Vec::new();
+            env_value_completions(&mut results, &InkFile::parse(&code), offset);
+
+            assert_eq!(
+                results
+                    .into_iter()
+                    .map(|completion| (completion.edit.text, completion.range))
+                    .collect::<Vec<(String, TextRange)>>(),
+                expected_results
+                    .into_iter()
+                    .map(|(edit, pat_start, pat_end)| (
+                        edit.to_string(),
+                        TextRange::new(
+                            TextSize::from(parse_offset_at(&code, pat_start).unwrap() as u32),
+                            TextSize::from(parse_offset_at(&code, pat_end).unwrap() as u32)
+                        )
+                    ))
+                    .collect::<Vec<(String, TextRange)>>(),
+                "code: {code}"
+            );
+        }
+    }
+
+    #[test]
+    fn selector_value_completions_works() {
+        for (code, pat, expected_results) in [
+            // (code, pat, [(edit, pat_start, pat_end)]) where:
+            // code = source code,
+            // pat = substring used to find the cursor offset (see `test_utils::parse_offset_at` doc),
+            // edit = the text that will inserted,
+            // pat_start = substring used to find the start of the edit offset (see `test_utils::parse_offset_at` doc),
+            // pat_end = substring used to find the end of the edit offset (see `test_utils::parse_offset_at` doc).
+            (
+                r#"
+                    #[ink(message, selector=)]
+                    pub fn transfer(&mut self) {}
+                "#,
+                Some("selector="),
+                vec![("0x84a15da1", Some("selector="), Some("selector="))],
+            ),
+            (
+                r#"
+                    #[ink(message, selector = )]
+                    pub fn flip(&mut self) {}
+                "#,
+                Some("selector = "),
+                vec![("0x633aa551", Some("selector = "), Some("selector = "))],
+            ),
+            (
+                r#"
+                    #[ink(constructor, selector=)]
+                    pub fn new() -> Self {}
+                "#,
+                Some("selector="),
+                vec![("0x9bae9d5e", Some("selector="), Some("selector="))],
+            ),
+            // Filters by the current value.
+            (
+                r#"
+                    #[ink(message, selector = 0x63)]
+                    pub fn flip(&mut self) {}
+                "#,
+                Some("selector = 0x63"),
+                vec![("0x633aa551", Some("<-0x63"), Some("selector = 0x63"))],
+            ),
+            (
+                r#"
+                    #[ink(message, selector = 0xCA)]
+                    pub fn flip(&mut self) {}
+                "#,
+                Some("selector = 0xCA"),
+                vec![],
+            ),
+            // Not an ink! constructor or ink! message.
+            (
+                r#"
+                    #[ink(selector=)]
+                    pub fn transfer(&mut self) {}
+                "#,
+                Some("selector="),
+                vec![],
+            ),
+            // Not a selector argument value.
+            (
+                r#"
+                    #[ink(message, selector=)]
+                    pub fn transfer(&mut self) {}
+                "#,
+                Some("message"),
+                vec![],
+            ),
+        ] {
+            let offset = TextSize::from(parse_offset_at(code, pat).unwrap() as u32);
+
+            let mut results = Vec::new();
+            selector_value_completions(&mut results, &InkFile::parse(code), offset);
+
+            assert_eq!(
+                results
+                    .into_iter()
+                    .map(|completion| (completion.edit.text, completion.range))
+                    .collect::<Vec<(String, TextRange)>>(),
+                expected_results
+                    .into_iter()
+                    .map(|(edit, pat_start, pat_end)| (
+                        edit.to_string(),
+                        TextRange::new(
+                            TextSize::from(parse_offset_at(code, pat_start).unwrap() as u32),
+                            TextSize::from(parse_offset_at(code, pat_end).unwrap() as u32)
+                        )
+                    ))
+                    .collect::<Vec<(String, TextRange)>>(),
+                "code: {code}"
+            );
+        }
+    }
+
+    #[test]
+    fn ink_arg_insert_text_with_context_works() {
+        let code = r#"
+            #[ink::contract(env = crate::MyEnvironment)]
+            mod my_contract {
+                #[ink(storage)]
+                pub struct MyContract {}
+
+                impl MyContract {
+                    #[ink(constructor, selector = 0xCAFE0000)]
+                    pub fn new() -> Self {}
+
+                    #[ink(message, selector = 0xCAFE0001)]
+                    pub fn my_message(&self) {}
+                }
+
+                #[ink(namespace)]
+                impl MyTrait for MyContract {}
+            }
+        "#;
+        let file = InkFile::parse(code);
+
+        for (pat, arg_kind, expected_text, expected_snippet) in [
+            // Next free selector.
+            (
+                "#[ink(message",
+                InkArgKind::Selector,
+                "selector = 0xcafe0002",
+                "selector = ${1:0xcafe0002}",
+            ),
+            // Existing environment.
+            (
+                "#[ink(message",
+                InkArgKind::Env,
+                "env = crate::MyEnvironment",
+                "env = ${1:crate::MyEnvironment}",
+            ),
+            // Trait name.
+            (
+                "#[ink(namespace",
+                InkArgKind::Namespace,
+                r#"namespace = "MyTrait""#,
+                r#"namespace = ${1:"MyTrait"}"#,
+            ),
+        ] {
+            let offset = TextSize::from(parse_offset_at(code, Some(pat)).unwrap() as u32);
+            let context = prepare_completions(&file, offset).unwrap();
+
+            let (text, snippet) =
+                utils::ink_arg_insert_text_with_context(arg_kind, None, None, &context);
+            assert_eq!(text, expected_text, "pat: {pat}");
+            assert_eq!(snippet.as_deref(), Some(expected_snippet), "pat: {pat}");
+        }
+
+        // Default (i.e. empty) context.
+        for (arg_kind, expected_text) in [
+            (InkArgKind::Selector, "selector = 1"),
+            (InkArgKind::Env, "env = crate::"),
+            (InkArgKind::Namespace, r#"namespace = "my_namespace""#),
+        ] {
+            let (text, _) = utils::ink_arg_insert_text_with_context(
+                arg_kind,
+                None,
+                None,
+                &CompletionContext::default(),
+            );
+            assert_eq!(text, expected_text);
+            assert_eq!(utils::ink_arg_insert_text(arg_kind, None, None).0, text);
+        }
+
+        // Next free selector wraps around after `u32::MAX`.
+        let context = CompletionContext {
+            existing_selectors: vec![u32::MAX.to_be_bytes(), 1u32.to_be_bytes()],
+            explicit_selectors: vec![u32::MAX, 1],
+            ..CompletionContext::default()
+        };
+        let (text, _) =
+            utils::ink_arg_insert_text_with_context(InkArgKind::Selector, None, None, &context);
+        assert_eq!(text, "selector = 0x00000002");
+    }
+
+    #[test]
+    fn prepare_completions_works() {
+        let code = r#"
+            #[ink::contract]
+            mod my_contract {
+                #[ink(storage)]
+                pub struct MyContract {}
+
+                impl MyContract {
+                    #[ink(constructor, selector=1)]
+                    pub fn new() -> Self {}
+
+                    #[ink(message, )]
+                    pub fn my_message(&self) {}
+
+                    #[ink(message, selector=2)]
+                    pub fn my_message2(&self) {}
+                }
+            }
+        "#;
+        let file = InkFile::parse(code);
+
+        // Cursor inside an ink! attribute (after a trigger character).
+        let offset = TextSize::from(parse_offset_at(code, Some("#[ink(message,")).unwrap() as u32);
+        let context = prepare_completions(&file, offset).unwrap();
+        assert_eq!(
+            context.trigger_kind,
+            CompletionTriggerKind::TriggerCharacter(',')
+        );
+        assert_eq!(
+            context
+                .covering_attribute
+                .as_ref()
+                .map(|attr| attr.syntax().to_string()),
+            Some("#[ink(message, )]".to_string())
+        );
+        assert_eq!(
+            context.parent_entity.as_ref().map(InkAttribute::kind),
+            Some(&InkAttributeKind::Macro(InkMacroKind::Contract))
+        );
+        assert_eq!(context.existing_selectors.len(), 3);
+        assert!(context.existing_selectors.contains(&[0, 0, 0, 1]));
+        assert!(context.existing_selectors.contains(&[0, 0, 0, 2]));
+        assert_eq!(context.explicit_selectors.len(), 2);
+        assert!(context.explicit_selectors.contains(&1));
+        assert!(context.explicit_selectors.contains(&2));
+
+        // Cursor at the start of an ink! attribute argument.
+        let offset = TextSize::from(parse_offset_at(code, Some("<-selector=2")).unwrap() as u32);
+        let context = prepare_completions(&file, offset).unwrap();
+        assert_eq!(context.trigger_kind, CompletionTriggerKind::Invoked);
+        assert_eq!(
+            context
+                .covering_attribute
+                .as_ref()
+                .map(|attr| attr.syntax().to_string()),
+            Some("#[ink(message, selector=2)]".to_string())
+        );
+
+        // Cursor outside an ink! attribute.
+        let offset = TextSize::from(parse_offset_at(code, Some("pub struct")).unwrap() as u32);
+        let context = prepare_completions(&file, offset).unwrap();
+        assert!(context.covering_attribute.is_none());
+        assert_eq!(
+            context.parent_entity.as_ref().map(InkAttribute::kind),
+            Some(&InkAttributeKind::Arg(InkArgKind::Storage))
+        );
+        assert_eq!(context.existing_selectors.len(), 3);
+
+        // No token at offset.
+        assert!(prepare_completions(&InkFile::parse(""), TextSize::from(0)).is_none());
+    }
 }
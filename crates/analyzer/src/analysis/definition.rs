@@ -0,0 +1,157 @@
+//! ink! entity and environment definitions.
+
+use ink_analyzer_ir::syntax::{AstNode, TextRange, TextSize};
+use ink_analyzer_ir::{ast, EnvArg, InkArgKind, InkEntity, InkFile, TraitDefinition};
+
+use crate::analysis::utils as analysis_utils;
+
+/// Returns the text range of the definition of the item at the given offset (if any).
+///
+/// Currently handles:
+/// - trait paths in `impl` items (e.g. `MyTrait` in `impl MyTrait for MyContract`) that resolve to an ink! trait definition.
+/// - ink! environment argument values (e.g. `crate::MyEnvironment` in `#[ink::contract(env = crate::MyEnvironment)]`).
+///
+/// **NOTE**: Only items declared in the same file can be resolved.
+pub fn goto_definition(file: &InkFile, offset: TextSize) -> Option<TextRange> {
+    let item_at_offset = file.item_at_offset(offset);
+    let focused_token = item_at_offset.focused_token()?;
+
+    // Handles ink! environment argument values.
+    if let Some(ink_attr) = item_at_offset.parent_ink_attr() {
+        let focused_range = focused_token.text_range();
+        let path = ink_attr
+            .args()
+            .iter()
+            .filter(|arg| matches!(arg.kind(), InkArgKind::Env | InkArgKind::Environment))
+            .find(|arg| {
+                arg.value()
+                    .is_some_and(|value| value.text_range().contains_range(focused_range))
+            })
+            .cloned()
+            .and_then(EnvArg::cast)
+            .as_ref()
+            .and_then(EnvArg::as_path_with_inaccurate_text_range)?;
+        return analysis_utils::resolve_type_item(&path, ink_attr.syntax())
+            .map(|item| item.syntax().text_range());
+    }
+
+    // Handles trait paths in `impl` items.
+    let path_type = focused_token
+        .parent_ancestors()
+        .take_while(|node| !ast::Item::can_cast(node.kind()))
+        .find_map(ast::PathType::cast)?;
+    let impl_item = path_type.syntax().parent().and_then(ast::Impl::cast)?;
+    let is_trait_path = impl_item
+        .trait_()
+        .is_some_and(|trait_type| trait_type.syntax() == path_type.syntax());
+    if !is_trait_path {
+        return None;
+    }
+    ink_analyzer_ir::resolve_item::<ast::Trait>(&path_type.path()?, impl_item.syntax())
+        .and_then(|trait_item| TraitDefinition::cast(trait_item.syntax().clone()))
+        .map(|trait_definition| trait_definition.syntax().text_range())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_utils::parse_offset_at;
+
+    #[test]
+    fn goto_definition_works() {
+        let code = r#"
+            #[ink::trait_definition]
+            pub trait MyTrait {
+                #[ink(message)]
+                fn my_message(&self);
+            }
+
+            pub trait OtherTrait {
+                fn other_method(&self);
+            }
+
+            #[derive(Clone)]
+            pub enum MyEnvironment {}
+
+            pub type MyAlias = MyEnvironment;
+
+            #[ink::contract(env = crate::MyEnvironment)]
+            mod my_contract {
+                #[ink(storage)]
+                pub struct MyContract {}
+
+                impl MyContract {
+                    #[ink(constructor)]
+                    pub fn new() -> Self {}
+                }
+
+                impl super::MyTrait for MyContract {
+                    #[ink(message)]
+                    fn my_message(&self) {}
+                }
+
+                impl super::OtherTrait for MyContract {
+                    fn other_method(&self) {}
+                }
+            }
+
+            #[ink_e2e::test(environment = MyAlias)]
+            async fn it_works(mut client: ::ink_e2e::Client<C, E>) -> E2EResult<()> {}
+        "#;
+        let file = InkFile::parse(code);
+
+        for (pat, expected_start_pat, expected_end_pat) in [
+            // ink! trait definition.
+            (
+                "super::My",
+                Some("<-#[ink::trait_definition]"),
+                Some("fn my_message(&self);\n            }"),
+            ),
+            (
+                "super::MyTrait",
+                Some("<-#[ink::trait_definition]"),
+                Some("fn my_message(&self);\n            }"),
+            ),
+            // ink! environment (as an enum).
+            (
+                "env = crate::My",
+                Some("<-#[derive(Clone)]"),
+                Some("pub enum MyEnvironment {}"),
+            ),
+            // ink! environment (as a type alias).
+            (
+                "environment = My",
+                Some("<-pub type MyAlias"),
+                Some("pub type MyAlias = MyEnvironment;"),
+            ),
+        ] {
+            let offset = TextSize::from(parse_offset_at(code, Some(pat)).unwrap() as u32);
+            let expected_range = TextRange::new(
+                TextSize::from(parse_offset_at(code, expected_start_pat).unwrap() as u32),
+                TextSize::from(parse_offset_at(code, expected_end_pat).unwrap() as u32),
+            );
+            assert_eq!(
+                goto_definition(&file, offset),
+                Some(expected_range),
+                "pat: {pat}"
+            );
+        }
+
+        for pat in [
+            // Not an ink! trait definition.
+            "super::Other",
+            // Self type of `impl` item.
+            "for MyCon",
+            "impl MyCon",
+            // Not an ink! environment argument.
+            "#[ink::con",
+            "#[ink(con",
+            // Not an item reference.
+            "// Nothing",
+            "fn my_mes",
+        ] {
+            let offset = TextSize::from(parse_offset_at(code, Some(pat)).unwrap_or(0) as u32);
+            assert_eq!(goto_definition(&file, offset), None, "pat: {pat}");
+        }
+    }
+}
@@ -27,6 +27,7 @@
 
 mod file;
 mod utils;
+mod workspace;
 
 mod chain_extension;
 mod constructor;
@@ -47,7 +48,7 @@ use ink_analyzer_ir::syntax::TextRange;
 use ink_analyzer_ir::InkFile;
 use itertools::Itertools;
 
-use crate::analysis::text_edit;
+use crate::analysis::text_edit::{self, FormatOptions};
 use crate::{Action, TextEdit};
 
 /// A diagnostic error or warning.
@@ -64,12 +65,16 @@ pub struct Diagnostic {
 }
 
 /// The severity level of a diagnostic.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+///
+/// Severity levels are ordered from least to most severe (i.e. `Hint < Warning < Error`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Severity {
-    /// A diagnostic error.
-    Error,
+    /// A diagnostic hint (e.g. for lints about potential inefficiencies).
+    Hint,
     /// A diagnostic warning.
     Warning,
+    /// A diagnostic error.
+    Error,
 }
 
 /// Runs diagnostics for the source file.
@@ -92,7 +97,12 @@ pub fn diagnostics(file: &InkFile) -> Vec<Diagnostic> {
                 fixes
                     .into_iter()
                     .map(|action| Action {
-                        edits: text_edit::format_edits(action.edits.into_iter(), file).collect(),
+                        edits: text_edit::format_edits(
+                            action.edits.into_iter(),
+                            file,
+                            &FormatOptions::default(),
+                        )
+                        .collect(),
                         ..action
                     })
                     .collect()
@@ -101,3 +111,17 @@ pub fn diagnostics(file: &InkFile) -> Vec<Diagnostic> {
         })
         .collect()
 }
+
+/// Runs cross-file diagnostics for the given `(file_id, code)` pairs (i.e. a workspace).
+///
+/// Returns diagnostics paired with the identifier of the file they apply to.
+pub fn workspace_diagnostics(sources: &[(&str, &str)]) -> Vec<(String, Diagnostic)> {
+    workspace::diagnostics(sources)
+}
+
+/// Runs cross-file diagnostics for the given `(file_id, file)` pairs (i.e. an already parsed workspace).
+///
+/// Returns diagnostics paired with the identifier of the file they apply to.
+pub fn workspace_files_diagnostics(files: &[(String, InkFile)]) -> Vec<(String, Diagnostic)> {
+    workspace::files_diagnostics(files)
+}
@@ -5,7 +5,8 @@ mod error_code;
 use ink_analyzer_ir::ast::{AstNode, HasName};
 use ink_analyzer_ir::meta::MetaValue;
 use ink_analyzer_ir::{
-    ast, ChainExtension, Extension, InkArg, InkArgKind, InkAttributeKind, InkEntity, IsInkTrait,
+    ast, ChainExtension, Extension, InkArg, InkArgKind, InkAttributeKind, InkEntity,
+    InkVersionHints, IsInkTrait,
 };
 use std::collections::HashSet;
 
@@ -24,7 +25,13 @@ const CHAIN_EXTENSION_SCOPE_NAME: &str = "chain extension";
 /// Ref: <https://github.com/paritytech/ink/blob/v4.1.0/crates/ink/ir/src/ir/chain_extension.rs#L201-L211>.
 ///
 /// Ref: <https://github.com/paritytech/ink/blob/v4.1.0/crates/ink/ir/src/ir/chain_extension.rs#L188-L197>.
-pub fn diagnostics(results: &mut Vec<Diagnostic>, chain_extension: &ChainExtension) {
+///
+/// `version` is the hints about the ink! version used by the file (see `InkFile::used_ink_version_features` doc).
+pub fn diagnostics(
+    results: &mut Vec<Diagnostic>,
+    chain_extension: &ChainExtension,
+    version: InkVersionHints,
+) {
     // Runs generic diagnostics, see `utils::run_generic_diagnostics` doc.
     utils::run_generic_diagnostics(results, chain_extension);
 
@@ -43,11 +50,11 @@ pub fn diagnostics(results: &mut Vec<Diagnostic>, chain_extension: &ChainExtensi
 
     // Ensures that ink! chain extension `trait` item's associated items satisfy all invariants,
     // see `ensure_trait_item_invariants` doc.
-    ensure_trait_item_invariants(results, chain_extension);
+    ensure_trait_item_invariants(results, chain_extension, version);
 
     // Runs ink! extension diagnostics, see `extension::diagnostics` doc.
     for item in chain_extension.extensions() {
-        extension::diagnostics(results, item);
+        extension::diagnostics(results, item, version);
     }
 
     // Ensures that exactly one `ErrorCode` associated type is defined, see `ensure_error_code_quantity` doc.
@@ -72,7 +79,11 @@ pub fn diagnostics(results: &mut Vec<Diagnostic>, chain_extension: &ChainExtensi
 ///
 /// See `utils::ensure_trait_item_invariants` doc for common invariants for all trait-based ink! entities that are handled by that utility.
 /// This utility also runs `extension::diagnostics` on trait functions with a ink! extension attribute.
-fn ensure_trait_item_invariants(results: &mut Vec<Diagnostic>, chain_extension: &ChainExtension) {
+fn ensure_trait_item_invariants(
+    results: &mut Vec<Diagnostic>,
+    chain_extension: &ChainExtension,
+    version: InkVersionHints,
+) {
     // Tracks already used and suggested ids for quickfixes.
     let mut unavailable_ids = init_unavailable_ids(chain_extension);
     if let Some(trait_item) = chain_extension.trait_item() {
@@ -88,7 +99,9 @@ fn ensure_trait_item_invariants(results: &mut Vec<Diagnostic>, chain_extension:
                     .find_map(ink_analyzer_ir::ink_attr_to_entity::<Extension>)
                 {
                     // Runs ink! extension diagnostics, see `extension::diagnostics` doc.
-                    Some(extension_item) => extension::diagnostics(results, &extension_item),
+                    Some(extension_item) => {
+                        extension::diagnostics(results, &extension_item, version)
+                    }
                     // Add diagnostic if function isn't an ink! extension.
                     None => {
                         // Determines quickfix insertion offset and affixes.
@@ -249,7 +262,12 @@ fn ensure_error_code_type_quantity(
                 results.push(Diagnostic {
                     message: "Missing `ErrorCode` associated type for ink! chain extension."
                         .to_string(),
-                    range: analysis_utils::ink_trait_declaration_range(chain_extension),
+                    // Highlights the trait name (if any), so that the diagnostic is shown
+                    // on the declaration regardless of the attributes of the trait.
+                    range: trait_item.name().map_or(
+                        analysis_utils::ink_trait_declaration_range(chain_extension),
+                        |name| name.syntax().text_range(),
+                    ),
                     severity: Severity::Error,
                     quickfixes: entity_actions::add_error_code(
                         chain_extension,
@@ -656,7 +674,11 @@ mod tests {
             });
 
             let mut results = Vec::new();
-            ensure_trait_item_invariants(&mut results, &chain_extension);
+            ensure_trait_item_invariants(
+                &mut results,
+                &chain_extension,
+                InkVersionHints::default(),
+            );
             assert!(results.is_empty(), "chain extension: {code}");
         }
     }
@@ -1021,6 +1043,20 @@ mod tests {
                     }],
                 }],
             ),
+            (
+                quote! {
+                    #[ink(extension=1)]
+                    fn has_self_receiver(&mut self, a: u32);
+                },
+                vec![TestResultAction {
+                    label: "Remove self",
+                    edits: vec![TestResultTextRange {
+                        text: "",
+                        start_pat: Some("<-&mut self"),
+                        end_pat: Some("<-a: u32"),
+                    }],
+                }],
+            ),
         ] {
             let code = quote_as_pretty_string! {
                 #[ink::chain_extension]
@@ -1031,7 +1067,11 @@ mod tests {
             let chain_extension = parse_first_chain_extension(&code);
 
             let mut results = Vec::new();
-            ensure_trait_item_invariants(&mut results, &chain_extension);
+            ensure_trait_item_invariants(
+                &mut results,
+                &chain_extension,
+                InkVersionHints::default(),
+            );
 
             // Verifies diagnostics.
             assert_eq!(results.len(), 1, "chain extension: {items}");
@@ -1128,6 +1168,46 @@ mod tests {
         );
     }
 
+    #[test]
+    fn missing_error_code_type_range_works() {
+        for code in [
+            quote_as_pretty_string! {
+                #[ink::chain_extension]
+                pub trait MyChainExtension {
+                }
+            },
+            quote_as_pretty_string! {
+                /// Docs.
+                #[ink::chain_extension]
+                #[allow(dead_code)]
+                #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+                #[cfg_attr(feature = "std", allow(clippy::all, unused_variables, unused_imports))]
+                pub trait MyChainExtension {
+                    #[ink(extension = 1)]
+                    fn my_extension();
+                }
+            },
+        ] {
+            let chain_extension = parse_first_chain_extension(&code);
+
+            let mut results = Vec::new();
+            ensure_error_code_type_quantity(&mut results, &chain_extension);
+
+            // Verifies that the diagnostic range is the trait name.
+            assert_eq!(results.len(), 1);
+            assert_eq!(
+                results[0].range,
+                TextRange::new(
+                    TextSize::from(
+                        parse_offset_at(&code, Some("<-MyChainExtension")).unwrap() as u32
+                    ),
+                    TextSize::from(parse_offset_at(&code, Some("MyChainExtension")).unwrap() as u32)
+                ),
+                "code: {code}"
+            );
+        }
+    }
+
     #[test]
     fn non_overlapping_ids_works() {
         for code in valid_chain_extensions!() {
@@ -1196,6 +1276,74 @@ mod tests {
         }
     }
 
+    #[test]
+    fn multiple_overlapping_ids_fails() {
+        for (code, n_overlapping) in [
+            // 3 extensions sharing the same id.
+            (
+                quote! {
+                    #[ink(extension=5)]
+                    fn my_extension();
+
+                    #[ink(extension=5)]
+                    fn my_extension2();
+
+                    #[ink(extension=5)]
+                    fn my_extension3();
+                },
+                2,
+            ),
+            // 5 extensions sharing the same id (across decimal and hex representations).
+            (
+                quote! {
+                    #[ink(extension=5)]
+                    fn my_extension();
+
+                    #[ink(extension=0x5)]
+                    fn my_extension2();
+
+                    #[ink(extension=5)]
+                    fn my_extension3();
+
+                    #[ink(extension=2)]
+                    fn my_extension4();
+
+                    #[ink(extension=0x5)]
+                    fn my_extension5();
+
+                    #[ink(extension=5)]
+                    fn my_extension6();
+                },
+                4,
+            ),
+        ] {
+            let chain_extension = parse_first_chain_extension(quote_as_str! {
+                #[ink::chain_extension]
+                pub trait MyChainExtension {
+                    #code
+                }
+            });
+
+            let mut results = Vec::new();
+            ensure_no_overlapping_ids(&mut results, &chain_extension);
+            // 1 error for each extension whose id overlaps with a preceding extension.
+            assert_eq!(results.len(), n_overlapping, "chain extension: {code}");
+            // Suggested ids are unique and don't overlap with any existing ids.
+            let mut suggested_ids: HashSet<u32> = init_unavailable_ids(&chain_extension);
+            for result in &results {
+                assert_eq!(result.severity, Severity::Error, "chain extension: {code}");
+                let suggested_id: u32 = result.quickfixes.as_ref().unwrap()[0].edits[0]
+                    .text
+                    .parse()
+                    .unwrap();
+                assert!(
+                    suggested_ids.insert(suggested_id),
+                    "chain extension: {code}"
+                );
+            }
+        }
+    }
+
     #[test]
     fn valid_quasi_direct_descendant_works() {
         for code in valid_chain_extensions!() {
@@ -1245,7 +1393,7 @@ mod tests {
                     edits: vec![TestResultTextRange {
                         text: "",
                         start_pat: Some("<-#[ink(constructor)]"),
-                        end_pat: Some("#[ink(constructor)]"),
+                        end_pat: Some("#[ink(constructor)]\n    "),
                     }],
                 },
                 TestResultAction {
@@ -1263,7 +1411,7 @@ mod tests {
                     edits: vec![TestResultTextRange {
                         text: "",
                         start_pat: Some("<-#[ink(message)]"),
-                        end_pat: Some("#[ink(message)]"),
+                        end_pat: Some("#[ink(message)]\n    "),
                     }],
                 },
                 TestResultAction {
@@ -1290,7 +1438,7 @@ mod tests {
             });
 
             let mut results = Vec::new();
-            diagnostics(&mut results, &chain_extension);
+            diagnostics(&mut results, &chain_extension, InkVersionHints::default());
             assert!(results.is_empty(), "chain extension: {code}");
         }
     }
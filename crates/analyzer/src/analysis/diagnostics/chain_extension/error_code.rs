@@ -43,7 +43,7 @@ pub fn diagnostics(results: &mut Vec<Diagnostic>, chain_extension: &ChainExtensi
 fn ensure_resolvable(chain_extension: &ChainExtension) -> Option<Diagnostic> {
     // Only continue if there's an `ErrorCode` type.
     let error_code_type = chain_extension.error_code()?.ty()?;
-    match error_code_adt(chain_extension) {
+    match chain_extension.resolved_error_code_type() {
         // Handles no resolved `ErrorCode` type.
         None => {
             // Determines text range for the `ErrorCode` type value.
@@ -88,7 +88,7 @@ fn ensure_resolvable(chain_extension: &ChainExtension) -> Option<Diagnostic> {
 // Ensures that the ink! chain extension `ErrorCode` type implements the `ink::env::chain_extension::FromStatusCode` trait.
 fn ensure_impl_from_status_code(chain_extension: &ChainExtension) -> Option<Diagnostic> {
     // Only continue if there's a named `ErrorCode` type.
-    let adt = error_code_adt(chain_extension)?;
+    let adt = chain_extension.resolved_error_code_type()?;
     let name = adt.name()?.to_string();
 
     utils::ensure_external_trait_impl(
@@ -112,7 +112,8 @@ fn ensure_impl_from_status_code(chain_extension: &ChainExtension) -> Option<Diag
 // Ref: <https://github.com/paritytech/ink/blob/v4.3.0/crates/ink/macro/src/lib.rs#L1092-L1094>.
 fn ensure_impl_scale_codec_traits(chain_extension: &ChainExtension) -> Option<Diagnostic> {
     // Only continue if there's an `ErrorCode` type.
-    error_code_adt(chain_extension)
+    chain_extension
+        .resolved_error_code_type()
         .and_then(|adt| utils::ensure_impl_scale_codec_traits(&adt, "`ErrorCode` associated type"))
 }
 
@@ -123,7 +124,8 @@ fn ensure_no_self_error_code_usage(
     results: &mut Vec<Diagnostic>,
     chain_extension: &ChainExtension,
 ) {
-    let error_code_path_option = error_code_adt(chain_extension)
+    let error_code_path_option = chain_extension
+        .resolved_error_code_type()
         .as_ref()
         .and_then(resolution::item_path);
     for self_error_code_path in chain_extension.syntax().descendants().filter_map(|node| {
@@ -155,14 +157,6 @@ fn ensure_no_self_error_code_usage(
     }
 }
 
-// Returns the error code ADT (struct, enum or union) (if any).
-fn error_code_adt(chain_extension: &ChainExtension) -> Option<ast::Adt> {
-    ink_analyzer_ir::resolve_item(
-        &ink_analyzer_ir::path_from_type(&chain_extension.error_code()?.ty()?)?,
-        chain_extension.syntax(),
-    )
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -523,21 +517,40 @@ mod tests {
 
     #[test]
     fn no_impl_from_status_code_fails() {
-        for (type_def, type_alias, expected_quickfixes) in [(
-            quote! {
-                #[derive(scale::Encode, scale::Decode, scale_info::TypeInfo)]
-                pub struct MyErrorCode;
-            },
-            quote! { type ErrorCode = crate::MyErrorCode; },
-            vec![TestResultAction {
-                label: "Add `ink::env::chain_extension::FromStatusCode`",
-                edits: vec![TestResultTextRange {
-                    text: "impl ink::env::chain_extension::FromStatusCode for ",
-                    start_pat: Some("pub struct MyErrorCode;"),
-                    end_pat: Some("pub struct MyErrorCode;"),
+        for (type_def, type_alias, expected_quickfixes) in [
+            (
+                quote! {
+                    #[derive(scale::Encode, scale::Decode, scale_info::TypeInfo)]
+                    pub struct MyErrorCode;
+                },
+                quote! { type ErrorCode = crate::MyErrorCode; },
+                vec![TestResultAction {
+                    label: "Add `ink::env::chain_extension::FromStatusCode`",
+                    edits: vec![TestResultTextRange {
+                        text: "impl ink::env::chain_extension::FromStatusCode for ",
+                        start_pat: Some("pub struct MyErrorCode;"),
+                        end_pat: Some("pub struct MyErrorCode;"),
+                    }],
                 }],
-            }],
-        )] {
+            ),
+            (
+                quote! {
+                    #[derive(scale::Encode, scale::Decode, scale_info::TypeInfo)]
+                    pub enum MyErrorCode {
+                        InvalidKey,
+                    }
+                },
+                quote! { type ErrorCode = crate::MyErrorCode; },
+                vec![TestResultAction {
+                    label: "Add `ink::env::chain_extension::FromStatusCode`",
+                    edits: vec![TestResultTextRange {
+                        text: "impl ink::env::chain_extension::FromStatusCode for ",
+                        start_pat: Some("InvalidKey,\n}"),
+                        end_pat: Some("InvalidKey,\n}"),
+                    }],
+                }],
+            ),
+        ] {
             let code = quote_as_pretty_string! {
                 #[ink::chain_extension]
                 pub trait my_chain_extension {
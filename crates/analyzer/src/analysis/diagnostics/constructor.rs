@@ -1,7 +1,7 @@
 //! ink! constructor diagnostics.
 
 use ink_analyzer_ir::ast::AstNode;
-use ink_analyzer_ir::{ast, Constructor, IsInkFn};
+use ink_analyzer_ir::{ast, Constructor, HasInkImplParent, IsInkFn};
 
 use super::utils;
 use crate::analysis::text_edit::TextEdit;
@@ -25,11 +25,21 @@ pub fn diagnostics(results: &mut Vec<Diagnostic>, constructor: &Constructor) {
         results.push(diagnostic);
     }
 
+    // Ensures that ink! constructor selector (if any) is not a reserved value, see `utils::ensure_no_reserved_selector` doc.
+    if let Some(diagnostic) =
+        utils::ensure_no_reserved_selector(constructor, CONSTRUCTOR_SCOPE_NAME)
+    {
+        results.push(diagnostic);
+    }
+
     if let Some(fn_item) = constructor.fn_item() {
         // Ensures that ink! constructor `fn` item satisfies all common invariants of externally callable ink! entities,
         // see `utils::ensure_callable_invariants` doc.
         // Ref: <https://github.com/paritytech/ink/blob/v4.1.0/crates/ink/ir/src/ir/item_impl/constructor.rs#L156>.
         // Ref: <https://github.com/paritytech/ink/blob/v4.1.0/crates/ink/ir/src/ir/item_impl/callable.rs#L355-L440>.
+        // NOTE: This flags restricted visibility (e.g. `pub(crate)` and `pub(super)`),
+        // while inherited visibility is flagged by `ink_impl::ensure_impl_invariants`
+        // (because it's required for ink! constructors in trait ink! impl blocks).
         utils::ensure_callable_invariants(results, fn_item, CONSTRUCTOR_SCOPE_NAME);
 
         // Ensures that ink! constructor `fn` item has no self receiver, see `utils::ensure_no_self_receiver` doc.
@@ -43,6 +53,12 @@ pub fn diagnostics(results: &mut Vec<Diagnostic>, constructor: &Constructor) {
         if let Some(diagnostic) = ensure_return_type(fn_item) {
             results.push(diagnostic);
         }
+
+        // Ensures that ink! constructor `fn` item returns `Self` (or `Result<Self, E>`),
+        // see `ensure_valid_return_type` doc.
+        if let Some(diagnostic) = ensure_valid_return_type(constructor, fn_item) {
+            results.push(diagnostic);
+        }
     }
 
     // Ensures that ink! constructor has no ink! descendants, see `utils::ensure_no_ink_descendants` doc.
@@ -86,10 +102,67 @@ fn ensure_return_type(fn_item: &ast::Fn) -> Option<Diagnostic> {
     })
 }
 
+/// Ensures that ink! constructor returns `Self` (or `Result<Self, E>` for fallible constructors).
+///
+/// **NOTE**: The name of the ink! storage struct (i.e. the self type of the parent `impl` block) is also accepted
+/// in place of `Self`.
+fn ensure_valid_return_type(constructor: &Constructor, fn_item: &ast::Fn) -> Option<Diagnostic> {
+    let return_type = fn_item.ret_type()?.ty()?;
+    let self_ty_name = constructor
+        .parent_impl_item()
+        .and_then(|impl_item| impl_item.self_ty())
+        .map(|self_ty| self_ty.to_string());
+    let is_self_type = |ty: &ast::Type| {
+        let name = ty.to_string();
+        name == "Self" || self_ty_name.as_ref() == Some(&name)
+    };
+    let is_valid = is_self_type(&return_type)
+        || match &return_type {
+            // Fallible constructors return `Result<Self, E>`.
+            ast::Type::PathType(path_type) => path_type
+                .path()
+                .and_then(|path| path.segment())
+                .filter(|segment| {
+                    segment
+                        .name_ref()
+                        .is_some_and(|name_ref| name_ref.to_string() == "Result")
+                })
+                .and_then(|segment| segment.generic_arg_list())
+                .and_then(|generic_arg_list| generic_arg_list.generic_args().next())
+                .is_some_and(|generic_arg| match generic_arg {
+                    ast::GenericArg::TypeArg(type_arg) => {
+                        type_arg.ty().is_some_and(|ty| is_self_type(&ty))
+                    }
+                    _ => false,
+                }),
+            _ => false,
+        };
+
+    let range = return_type.syntax().text_range();
+    (!is_valid).then_some(Diagnostic {
+        message:
+            "ink! constructor must return `Self` (or `Result<Self, E>` for fallible constructors)."
+                .to_string(),
+        range,
+        severity: Severity::Error,
+        quickfixes: Some(vec![Action {
+            label: "Change return type to `Self`.".to_string(),
+            kind: ActionKind::QuickFix,
+            range,
+            edits: vec![TextEdit::replace_with_snippet(
+                "Self".to_string(),
+                range,
+                Some("${1:Self}".to_string()),
+            )],
+        }]),
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::test_utils::*;
+    use ink_analyzer_ir::InkImpl;
     use quote::quote;
     use test_utils::{quote_as_pretty_string, quote_as_str, TestResultAction, TestResultTextRange};
 
@@ -469,6 +542,49 @@ mod tests {
         }
     }
 
+    #[test]
+    fn visibility_works() {
+        // Restricted visibility is flagged by `utils::ensure_callable_invariants`,
+        // while inherited visibility (in inherent impl blocks) is flagged by `ink_impl::ensure_impl_invariants`.
+        for (visibility, expected_quickfix_text) in [
+            (quote! { pub }, None),
+            (quote! {}, Some("pub ")),
+            (quote! { pub(crate) }, Some("pub")),
+            (quote! { pub(super) }, Some("pub")),
+        ] {
+            let code = quote_as_pretty_string! {
+                impl MyContract {
+                    #[ink(constructor)]
+                    #visibility fn new() -> Self {}
+                }
+            };
+            let constructor = parse_first_constructor(&code);
+            let ink_impl: InkImpl = parse_first_ink_entity_of_type(&code);
+
+            let mut results = Vec::new();
+            diagnostics(&mut results, &constructor);
+            super::super::ink_impl::ensure_impl_invariants(&mut results, &ink_impl);
+            let errors: Vec<_> = results
+                .iter()
+                .filter(|diagnostic| diagnostic.severity == Severity::Error)
+                .collect();
+
+            match expected_quickfix_text {
+                None => assert!(errors.is_empty(), "code: {code}"),
+                Some(text) => {
+                    assert!(!errors.is_empty(), "code: {code}");
+                    assert!(
+                        errors
+                            .iter()
+                            .flat_map(|diagnostic| diagnostic.quickfixes.iter().flatten())
+                            .any(|action| action.edits.iter().any(|edit| edit.text == text)),
+                        "code: {code}"
+                    );
+                }
+            }
+        }
+    }
+
     #[test]
     // Ref: <https://github.com/paritytech/ink/blob/v4.1.0/crates/ink/ir/src/ir/item_impl/constructor.rs#L424-L450>.
     fn self_receiver_fails() {
@@ -549,6 +665,115 @@ mod tests {
         }
     }
 
+    #[test]
+    fn valid_return_type_works() {
+        for code in valid_constructors!() {
+            let constructor = parse_first_constructor(quote_as_str! {
+                #code
+            });
+
+            let result = ensure_valid_return_type(&constructor, constructor.fn_item().unwrap());
+            assert!(result.is_none(), "constructor: {code}");
+        }
+
+        for code in [
+            // Self type of the parent impl.
+            quote! {
+                pub fn my_constructor() -> MyContract {}
+            },
+            quote! {
+                pub fn my_constructor() -> Result<MyContract, ()> {}
+            },
+            quote! {
+                pub fn my_constructor() -> core::result::Result<Self, MyError> {}
+            },
+            // Missing return type is handled by `ensure_return_type`.
+            quote! {
+                pub fn my_constructor() {}
+            },
+        ] {
+            let constructor = parse_first_constructor(quote_as_str! {
+                impl MyContract {
+                    #[ink(constructor)]
+                    #code
+                }
+            });
+
+            let result = ensure_valid_return_type(&constructor, constructor.fn_item().unwrap());
+            assert!(result.is_none(), "constructor: {code}");
+        }
+    }
+
+    #[test]
+    fn invalid_return_type_fails() {
+        for (code, start_pat, end_pat) in [
+            (
+                quote! {
+                    fn my_constructor() -> bool {}
+                },
+                "<-bool",
+                "bool",
+            ),
+            (
+                quote! {
+                    fn my_constructor(a: i32) -> i32 {}
+                },
+                "<-i32 {}",
+                "-> i32",
+            ),
+            (
+                quote! {
+                    fn my_constructor() -> Option<Self> {}
+                },
+                "<-Option<Self>",
+                "Option<Self>",
+            ),
+            (
+                quote! {
+                    fn my_constructor() -> Result<bool, ()> {}
+                },
+                "<-Result<bool, ()>",
+                "Result<bool, ()>",
+            ),
+            (
+                quote! {
+                    fn my_constructor() -> OtherContract {}
+                },
+                "<-OtherContract",
+                "OtherContract",
+            ),
+        ] {
+            let code = quote_as_pretty_string! {
+                impl MyContract {
+                    #[ink(constructor)]
+                    #code
+                }
+            };
+            let constructor = parse_first_constructor(&code);
+
+            let result = ensure_valid_return_type(&constructor, constructor.fn_item().unwrap());
+
+            // Verifies diagnostics.
+            assert!(result.is_some(), "constructor: {code}");
+            assert_eq!(
+                result.as_ref().unwrap().severity,
+                Severity::Error,
+                "constructor: {code}"
+            );
+            // Verifies quickfixes.
+            let expected_quickfixes = vec![TestResultAction {
+                label: "Change return type",
+                edits: vec![TestResultTextRange {
+                    text: "Self",
+                    start_pat: Some(start_pat),
+                    end_pat: Some(end_pat),
+                }],
+            }];
+            let quickfixes = result.as_ref().unwrap().quickfixes.as_ref().unwrap();
+            verify_actions(&code, quickfixes, &expected_quickfixes);
+        }
+    }
+
     #[test]
     // Ref: <https://github.com/paritytech/ink/blob/v4.1.0/crates/ink/ir/src/ir/item_impl/constructor.rs#L407-L422>.
     fn missing_return_type_fails() {
@@ -595,6 +820,71 @@ mod tests {
         }
     }
 
+    #[test]
+    fn non_reserved_selector_works() {
+        for selector in [
+            quote! { 1 },
+            quote! { 0x1 },
+            quote! { 4294967294 },
+            quote! { 0xFFFFFFFE },
+        ] {
+            let constructor = parse_first_constructor(quote_as_str! {
+                #[ink(constructor, selector = #selector)]
+                pub fn my_constructor() -> Self {}
+            });
+
+            let result = utils::ensure_no_reserved_selector(&constructor, CONSTRUCTOR_SCOPE_NAME);
+            assert!(result.is_none(), "selector: {selector}");
+        }
+    }
+
+    #[test]
+    fn reserved_selector_fails() {
+        for (selector, expected_value, start_pat, end_pat) in [
+            (quote! { 0 }, "1", "<-0)]", "selector = 0"),
+            (quote! { 0x0 }, "0x00000001", "<-0x0)]", "selector = 0x0"),
+            (
+                quote! { 4294967295 },
+                "4294967294",
+                "<-4294967295)]",
+                "selector = 4294967295",
+            ),
+            (
+                quote! { 0xFFFFFFFF },
+                "0xfffffffe",
+                "<-0xFFFFFFFF)]",
+                "selector = 0xFFFFFFFF",
+            ),
+        ] {
+            let code = quote_as_pretty_string! {
+                #[ink(constructor, selector = #selector)]
+                pub fn my_constructor() -> Self {}
+            };
+            let constructor = parse_first_constructor(&code);
+
+            let result = utils::ensure_no_reserved_selector(&constructor, CONSTRUCTOR_SCOPE_NAME);
+
+            // Verifies diagnostics.
+            assert!(result.is_some(), "constructor: {code}");
+            assert_eq!(
+                result.as_ref().unwrap().severity,
+                Severity::Error,
+                "constructor: {code}"
+            );
+            // Verifies quickfixes.
+            let expected_quickfixes = vec![TestResultAction {
+                label: "Replace with an unused selector",
+                edits: vec![TestResultTextRange {
+                    text: expected_value,
+                    start_pat: Some(start_pat),
+                    end_pat: Some(end_pat),
+                }],
+            }];
+            let quickfixes = result.as_ref().unwrap().quickfixes.as_ref().unwrap();
+            verify_actions(&code, quickfixes, &expected_quickfixes);
+        }
+    }
+
     #[test]
     fn no_ink_descendants_works() {
         for code in valid_constructors!() {
@@ -643,7 +933,7 @@ mod tests {
                     edits: vec![TestResultTextRange {
                         text: "",
                         start_pat: Some("<-#[ink(event)]"),
-                        end_pat: Some("#[ink(event)]"),
+                        end_pat: Some("#[ink(event)]\n    "),
                     }],
                 },
                 TestResultAction {
@@ -660,7 +950,7 @@ mod tests {
                 edits: vec![TestResultTextRange {
                     text: "",
                     start_pat: Some("<-#[ink(topic)]"),
-                    end_pat: Some("#[ink(topic)]"),
+                    end_pat: Some("#[ink(topic)]\n        "),
                 }],
             }],
         ];
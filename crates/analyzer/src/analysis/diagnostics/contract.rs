@@ -2,12 +2,13 @@
 
 use ink_analyzer_ir::ast::HasName;
 use ink_analyzer_ir::meta::MetaValue;
-use ink_analyzer_ir::syntax::{AstNode, SyntaxKind, SyntaxNode, SyntaxToken};
+use ink_analyzer_ir::syntax::{AstNode, SyntaxKind, SyntaxToken};
 use ink_analyzer_ir::{
-    ast, Contract, InkArg, InkArgKind, InkAttributeKind, InkEntity, InkMacroKind, IsInkCallable,
-    Selector, SelectorArg, Storage,
+    ast, CallableRef, Contract, InkArg, InkArgKind, InkAttributeKind, InkEntity, InkMacroKind,
+    IsInkCallable, Selector, SelectorArg, Storage,
 };
 use std::collections::HashSet;
+use std::iter;
 
 use super::{
     constructor, environment, event, ink_e2e_test, ink_impl, ink_test, message, storage, utils,
@@ -85,6 +86,10 @@ pub fn diagnostics(results: &mut Vec<Diagnostic>, contract: &Contract) {
     // see `ensure_impl_parent_for_callables` doc.
     ensure_impl_parent_for_callables(results, contract);
 
+    // Lints inconsistent namespace usage across inherent ink! impls,
+    // see `lint_namespace_consistency` doc.
+    lint_namespace_consistency(results, contract);
+
     // Runs ink! test diagnostics, see `ink_test::diagnostics` doc.
     for item in contract.tests() {
         ink_test::diagnostics(results, item);
@@ -231,20 +236,6 @@ fn ensure_contains_message(contract: &Contract) -> Option<Diagnostic> {
     )
 }
 
-/// Returns composed selectors for a list of ink! callable entities.
-fn get_composed_selectors<T>(items: &[T]) -> Vec<(Selector, SyntaxNode, Option<SelectorArg>)>
-where
-    T: IsInkCallable,
-{
-    items
-        .iter()
-        .filter_map(|item| {
-            item.composed_selector()
-                .map(|selector| (selector, item.syntax().clone(), item.selector_arg()))
-        })
-        .collect()
-}
-
 /// Ensures that no ink! message or constructor selectors are overlapping.
 ///
 /// Overlaps between ink! constructor and message selectors are allowed.
@@ -255,100 +246,135 @@ where
 ///
 /// Ref: <https://github.com/paritytech/ink/blob/v4.1.0/crates/ink/ir/src/ir/trait_def/item/mod.rs#L336-L337>.
 fn ensure_no_overlapping_selectors(results: &mut Vec<Diagnostic>, contract: &Contract) {
-    for (selectors, name, mut unavailable_ids) in [
-        (
-            get_composed_selectors(contract.constructors()),
-            "constructor",
-            contract
-                .constructors()
-                .iter()
-                .filter_map(|it| it.composed_selector().map(Selector::into_be_u32))
-                .collect::<HashSet<u32>>(),
-        ),
-        (
-            get_composed_selectors(contract.messages()),
-            "message",
-            contract
-                .messages()
-                .iter()
-                .filter_map(|it| it.composed_selector().map(Selector::into_be_u32))
-                .collect::<HashSet<u32>>(),
-        ),
-    ] {
-        let mut seen_selectors: HashSet<u32> = HashSet::new();
-        for (idx, (selector, node, selector_arg)) in selectors.iter().enumerate() {
-            let selector_value = selector.into_be_u32();
-
-            if seen_selectors.get(&selector_value).is_some() {
-                // Determines text range for the argument value.
-                let value_range_option = selector_arg
-                    .as_ref()
-                    .map(SelectorArg::arg)
-                    .and_then(InkArg::value)
-                    .map(MetaValue::text_range);
-                // Gets the `fn` item (if any).
-                let fn_item_option = || ast::Fn::cast(node.clone());
-                // Gets the `fn` item's name (if any).
-                let fn_name_option = || {
-                    fn_item_option()
-                        // Quickfix for using a unique `fn` name.
-                        .as_ref()
-                        .and_then(HasName::name)
-                };
-                // Determines text range for the `fn` item declaration (if any).
-                let fn_declaration_range = || {
-                    fn_item_option().and_then(|fn_item| {
-                        analysis_utils::ast_item_declaration_range(&ast::Item::Fn(fn_item))
+    // Returns the composed selector values (used as unavailable ids for quickfixes).
+    fn composed_selector_values<T: IsInkCallable>(items: &[T]) -> HashSet<u32> {
+        items
+            .iter()
+            .filter_map(|it| it.composed_selector().map(Selector::into_be_u32))
+            .collect()
+    }
+    let mut unavailable_constructor_ids = composed_selector_values(contract.constructors());
+    let mut unavailable_message_ids = composed_selector_values(contract.messages());
+    for conflict in contract.callable_selector_conflicts() {
+        let (node, selector_arg, name, unavailable_ids) = match &conflict.second {
+            CallableRef::Constructor(constructor) => (
+                constructor.syntax(),
+                constructor.selector_arg(),
+                "constructor",
+                &mut unavailable_constructor_ids,
+            ),
+            CallableRef::Message(message) => (
+                message.syntax(),
+                message.selector_arg(),
+                "message",
+                &mut unavailable_message_ids,
+            ),
+        };
+        // Determines text range for the argument value.
+        let value_range_option = selector_arg
+            .as_ref()
+            .map(SelectorArg::arg)
+            .and_then(InkArg::value)
+            .map(MetaValue::text_range);
+        // Gets the `fn` item (if any).
+        let fn_item_option = || ast::Fn::cast(node.clone());
+        // Gets the `fn` item's name (if any).
+        let fn_name_option = || {
+            fn_item_option()
+                // Quickfix for using a unique `fn` name.
+                .as_ref()
+                .and_then(HasName::name)
+        };
+        // Determines text range for the `fn` item declaration (if any).
+        let fn_declaration_range = || {
+            fn_item_option().and_then(|fn_item| {
+                analysis_utils::ast_item_declaration_range(&ast::Item::Fn(fn_item))
+            })
+        };
+        results.push(Diagnostic {
+            message: format!(
+                "Selector{} must be unique across all ink! {name}s in an ink! contract.",
+                match value_range_option {
+                    Some(_) => " values",
+                    None => "s",
+                }
+            ),
+            range: value_range_option
+                .or(fn_name_option().map(|name| name.syntax().text_range()))
+                .or(fn_declaration_range())
+                .unwrap_or(node.text_range()),
+            severity: Severity::Error,
+            quickfixes: value_range_option
+                // Quickfix for using a unique selector value.
+                .map(|range| {
+                    let suggested_id = analysis_utils::suggest_unique_id(
+                        Some(conflict.second_index as u32 + 1),
+                        unavailable_ids,
+                    );
+                    vec![Action {
+                        label: "Replace with a unique selector.".to_string(),
+                        kind: ActionKind::QuickFix,
+                        range,
+                        edits: vec![TextEdit::replace_with_snippet(
+                            format!("{suggested_id}"),
+                            range,
+                            Some(format!("${{1:{suggested_id}}}")),
+                        )],
+                    }]
+                })
+                .or(fn_name_option().map(|name| {
+                    let range = name.syntax().text_range();
+                    // Quickfix for using a unique `fn` name.
+                    iter::once(Action {
+                        label: "Replace with a unique name.".to_string(),
+                        kind: ActionKind::QuickFix,
+                        range,
+                        edits: vec![TextEdit::replace_with_snippet(
+                            format!("{name}2"),
+                            range,
+                            Some(format!("${{1:{name}2}}")),
+                        )],
                     })
-                };
-                results.push(Diagnostic {
-                    message: format!(
-                        "Selector{} must be unique across all ink! {name}s in an ink! contract.",
-                        match value_range_option {
-                            Some(_) => " values",
-                            None => "s",
-                        }
-                    ),
-                    range: value_range_option
-                        .or(fn_name_option().map(|name| name.syntax().text_range()))
-                        .or(fn_declaration_range())
-                        .unwrap_or(node.text_range()),
-                    severity: Severity::Error,
-                    quickfixes: value_range_option
-                        // Quickfix for using a unique selector value.
-                        .map(|range| {
-                            let suggested_id = analysis_utils::suggest_unique_id(
-                                Some(idx as u32 + 1),
-                                &mut unavailable_ids,
-                            );
-                            vec![Action {
-                                label: "Replace with a unique selector.".to_string(),
-                                kind: ActionKind::QuickFix,
-                                range,
-                                edits: vec![TextEdit::replace_with_snippet(
-                                    format!("{suggested_id}"),
+                    // Quickfix for adding an explicit unique selector.
+                    .chain(
+                        ink_analyzer_ir::ink_attrs(node)
+                            .find(|attr| {
+                                matches!(
+                                    attr.kind(),
+                                    InkAttributeKind::Arg(
+                                        InkArgKind::Constructor | InkArgKind::Message
+                                    )
+                                )
+                            })
+                            .and_then(|attr| {
+                                let (insert_offset, prefix, suffix) =
+                                    analysis_utils::ink_arg_insert_offset_and_affixes(
+                                        &attr,
+                                        Some(InkArgKind::Selector),
+                                    )?;
+                                let suggested_id = analysis_utils::suggest_unique_id(
+                                    Some(conflict.second_index as u32 + 1),
+                                    unavailable_ids,
+                                );
+                                let prefix = prefix.unwrap_or_default();
+                                let suffix = suffix.unwrap_or_default();
+                                Some(Action {
+                                    label: "Add a unique selector.".to_string(),
+                                    kind: ActionKind::QuickFix,
                                     range,
-                                    Some(format!("${{1:{suggested_id}}}")),
-                                )],
-                            }]
-                        })
-                        .or(fn_name_option().map(|name| {
-                            vec![Action {
-                                label: "Replace with a unique name.".to_string(),
-                                kind: ActionKind::QuickFix,
-                                range: name.syntax().text_range(),
-                                edits: vec![TextEdit::replace_with_snippet(
-                                    format!("{name}2"),
-                                    name.syntax().text_range(),
-                                    Some(format!("${{1:{name}2}}")),
-                                )],
-                            }]
-                        })),
-                });
-            }
-
-            seen_selectors.insert(selector_value);
-        }
+                                    edits: vec![TextEdit::insert_with_snippet(
+                                        format!("{prefix}selector = {suggested_id}{suffix}"),
+                                        insert_offset,
+                                        Some(format!(
+                                            "{prefix}selector = ${{1:{suggested_id}}}{suffix}"
+                                        )),
+                                    )],
+                                })
+                            }),
+                    )
+                    .collect()
+                })),
+        });
     }
 }
 
@@ -408,6 +434,37 @@ fn ensure_at_most_one_wildcard_selector(results: &mut Vec<Diagnostic>, contract:
     }
 }
 
+/// Lints inconsistent namespace usage across inherent (i.e. non-trait) ink! impls.
+///
+/// Selectors of ink! callables in namespaced `impl` blocks are composed differently from
+/// those in `impl` blocks without a namespace, so mixing both is likely unintended.
+///
+/// **NOTE**: Trait `impl` blocks are ignored because they can't be annotated with a namespace.
+fn lint_namespace_consistency(results: &mut Vec<Diagnostic>, contract: &Contract) {
+    let inherent_impls: Vec<_> = contract
+        .impls()
+        .iter()
+        .filter(|ink_impl| ink_impl.trait_type().is_none())
+        .collect();
+    let has_namespaced_impls = inherent_impls
+        .iter()
+        .any(|ink_impl| ink_impl.namespace_effective().is_some());
+    if has_namespaced_impls {
+        for ink_impl in inherent_impls {
+            if ink_impl.namespace_effective().is_none() {
+                results.push(Diagnostic {
+                    message:
+                        "Inconsistent namespace usage across impl blocks; consider using a uniform namespace."
+                            .to_string(),
+                    range: analysis_utils::ink_impl_declaration_range(ink_impl),
+                    severity: Severity::Warning,
+                    quickfixes: None,
+                });
+            }
+        }
+    }
+}
+
 /// Ensures that item is defined in the root of this specific ink! contract.
 fn ensure_parent_contract<T>(
     contract: &Contract,
@@ -539,7 +596,7 @@ mod tests {
     use ink_analyzer_ir::syntax::{TextRange, TextSize};
     use quote::{format_ident, quote};
     use test_utils::{
-        parse_offset_at, quote_as_pretty_string, quote_as_str, TestResultAction,
+        parse_offset_at, quote_as_pretty_string, quote_as_str, quote_as_string, TestResultAction,
         TestResultTextRange,
     };
 
@@ -1049,7 +1106,7 @@ mod tests {
                     edits: vec![TestResultTextRange {
                         text: "",
                         start_pat: Some("<-#[ink::contract]"),
-                        end_pat: Some("#[ink::contract]"),
+                        end_pat: Some("#[ink::contract]\n"),
                     }],
                 },
                 TestResultAction {
@@ -1153,6 +1210,59 @@ mod tests {
         }
     }
 
+    #[test]
+    fn multiple_storage_items_excess_targets_works() {
+        for idx in 2..=3 {
+            let storage_items = (1..=idx).map(|i| {
+                let name = format_ident!("MyContract{}", i);
+                quote! {
+                    #[ink(storage)]
+                    pub struct #name {
+                    }
+                }
+            });
+            let code = quote_as_pretty_string! {
+                #[ink::contract]
+                mod my_contract {
+                    #( #storage_items )*
+                }
+            };
+            let contract = parse_first_contract(&code);
+
+            let mut results = Vec::new();
+            ensure_storage_quantity(&mut results, &contract);
+
+            // Only the excess storage definitions (i.e. all except the first) are flagged,
+            // and the primary quickfix removes their `#[ink(storage)]` attribute.
+            assert_eq!(results.len(), idx - 1);
+            for (result, i) in results.iter().zip(2..=idx) {
+                let start = TextSize::from(
+                    parse_offset_at(
+                        &code,
+                        Some(&format!("<-#[ink(storage)]\n    pub struct MyContract{i}")),
+                    )
+                    .unwrap() as u32,
+                );
+                let end = TextSize::from(
+                    parse_offset_at(&code, Some(&format!("pub struct MyContract{i} {{}}"))).unwrap()
+                        as u32,
+                );
+                assert_eq!(result.range, TextRange::new(start, end), "code: {code}");
+
+                let fix = &result.quickfixes.as_ref().unwrap()[0];
+                assert!(fix.label.contains("#[ink(storage)]"), "code: {code}");
+                assert_eq!(
+                    fix.edits.iter().map(|edit| edit.range).collect::<Vec<_>>(),
+                    [TextRange::new(
+                        start,
+                        start + TextSize::of("#[ink(storage)]\n    ")
+                    )],
+                    "code: {code}"
+                );
+            }
+        }
+    }
+
     #[test]
     fn one_or_multiple_constructors_works() {
         for code in valid_contracts!() {
@@ -1359,7 +1469,7 @@ mod tests {
             if let Some(quickfixes) = &results[0].quickfixes {
                 for fix in quickfixes {
                     assert!(
-                        fix.label.contains("Replace")
+                        (fix.label.contains("Replace") || fix.label.contains("Add"))
                             && (fix.label.contains("unique selector")
                                 || fix.label.contains("unique name"))
                     );
@@ -1368,6 +1478,52 @@ mod tests {
         }
     }
 
+    #[test]
+    fn overlapping_composed_selectors_quickfix_works() {
+        let code = quote_as_pretty_string! {
+            #[ink::contract]
+            mod my_contract {
+                impl first::MyTrait for MyContract {
+                    #[ink(message)]
+                    fn my_message(&self) {}
+                }
+
+                impl second::MyTrait for MyContract {
+                    #[ink(message)]
+                    fn my_message(&self) {}
+                }
+            }
+        };
+        let contract = parse_first_contract(&code);
+
+        let mut results = Vec::new();
+        ensure_no_overlapping_selectors(&mut results, &contract);
+        // 1 error for the second `my_message` (i.e. composed selectors overlap).
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].severity, Severity::Error);
+        // Verifies quickfixes (i.e. both renaming the `fn` and adding an explicit selector are suggested).
+        let expected_quickfixes = vec![
+            TestResultAction {
+                label: "unique name",
+                edits: vec![TestResultTextRange {
+                    text: "my_message2",
+                    start_pat: Some("<-my_message(&self) {}\n    }\n}"),
+                    end_pat: Some("<-(&self) {}\n    }\n}"),
+                }],
+            },
+            TestResultAction {
+                label: "unique selector",
+                edits: vec![TestResultTextRange {
+                    text: ", selector = 2",
+                    start_pat: Some("impl second::MyTrait for MyContract {\n        #[ink(message"),
+                    end_pat: Some("impl second::MyTrait for MyContract {\n        #[ink(message"),
+                }],
+            },
+        ];
+        let quickfixes = results[0].quickfixes.as_ref().unwrap();
+        verify_actions(&code, quickfixes, &expected_quickfixes);
+    }
+
     #[test]
     // Ref: <https://github.com/paritytech/ink/blob/v4.1.0/crates/ink/ir/src/ir/item_mod.rs#L883-L902>.
     fn one_or_no_wildcard_selectors_works() {
@@ -1749,7 +1905,7 @@ mod tests {
                 edits: vec![TestResultTextRange {
                     text: "",
                     start_pat: Some("<-#[ink(topic)]"),
-                    end_pat: Some("#[ink(topic)]"),
+                    end_pat: Some("#[ink(topic)]\n        "),
                 }],
             }],
             vec![
@@ -1758,7 +1914,7 @@ mod tests {
                     edits: vec![TestResultTextRange {
                         text: "",
                         start_pat: Some("<-#[ink(extension = 1, handle_status = false)]"),
-                        end_pat: Some("#[ink(extension = 1, handle_status = false)]"),
+                        end_pat: Some("#[ink(extension = 1, handle_status = false)]\n    "),
                     }],
                 },
                 TestResultAction {
@@ -1787,7 +1943,102 @@ mod tests {
 
             let mut results = Vec::new();
             diagnostics(&mut results, &contract);
+            // Ignores namespace consistency lints and payable `&self` receiver lints
+            // (i.e. some valid contracts intentionally mix namespaced and non-namespaced impls,
+            // and have payable ink! messages with a `&self` receiver).
+            results.retain(|diagnostic| {
+                !diagnostic
+                    .message
+                    .starts_with("Inconsistent namespace usage across impl blocks")
+                    && !diagnostic
+                        .message
+                        .starts_with("A payable ink! message typically needs `&mut self`")
+            });
+            assert!(results.is_empty(), "contract: {code}");
+        }
+    }
+
+    #[test]
+    fn consistent_namespace_usage_works() {
+        for code in [
+            // All impls with a namespace.
+            quote_as_str! {
+                #[ink::contract]
+                mod my_contract {
+                    #[ink(namespace="namespace_a")]
+                    impl MyContract {
+                        #[ink(message)]
+                        pub fn my_message(&self) {}
+                    }
+
+                    #[ink(impl, namespace="namespace_b")]
+                    impl MyContract {
+                        #[ink(message)]
+                        pub fn my_message2(&self) {}
+                    }
+
+                    // Trait impls are ignored.
+                    impl MyTrait for MyContract {
+                        #[ink(message)]
+                        fn my_message3(&self) {}
+                    }
+                }
+            },
+            // All impls without a namespace.
+            quote_as_str! {
+                #[ink::contract]
+                mod my_contract {
+                    impl MyContract {
+                        #[ink(message)]
+                        pub fn my_message(&self) {}
+                    }
+
+                    #[ink(impl)]
+                    impl MyContract {
+                        #[ink(message)]
+                        pub fn my_message2(&self) {}
+                    }
+                }
+            },
+        ] {
+            let contract = parse_first_contract(code);
+
+            let mut results = Vec::new();
+            lint_namespace_consistency(&mut results, &contract);
             assert!(results.is_empty(), "contract: {code}");
         }
     }
+
+    #[test]
+    fn inconsistent_namespace_usage_fails() {
+        let code = quote_as_string! {
+            #[ink::contract]
+            mod my_contract {
+                #[ink(namespace="my_namespace")]
+                impl MyContract {
+                    #[ink(message)]
+                    pub fn my_message(&self) {}
+                }
+
+                impl MyContract {
+                    #[ink(message)]
+                    pub fn my_message2(&self) {}
+                }
+            }
+        };
+        let contract = parse_first_contract(&code);
+
+        let mut results = Vec::new();
+        lint_namespace_consistency(&mut results, &contract);
+
+        // Verifies diagnostics.
+        assert_eq!(results.len(), 1, "contract: {code}");
+        assert_eq!(results[0].severity, Severity::Warning);
+        // Verifies that the non-namespaced impl (i.e. the second impl) is flagged.
+        assert_eq!(&code[results[0].range], "impl MyContract {");
+        assert!(
+            usize::from(results[0].range.start())
+                > parse_offset_at(&code, Some("my_message")).unwrap()
+        );
+    }
 }
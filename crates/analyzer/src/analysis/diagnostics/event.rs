@@ -47,6 +47,9 @@ pub fn diagnostics(results: &mut Vec<Diagnostic>, event: &Event) {
 
     // Ensures that ink! event fields are not annotated with `cfg` attributes, see `ensure_no_cfg_event_fields` doc.
     ensure_no_cfg_event_fields(results, event);
+
+    // Lints ink! event fields with heap allocated types, see `ensure_event_fields_are_not_boxed` doc.
+    ensure_event_fields_are_not_boxed(results, event);
 }
 
 /// Ensures that ink! event `struct` has no generic parameters.
@@ -116,12 +119,45 @@ fn ensure_no_cfg_event_fields(results: &mut Vec<Diagnostic>, event: &Event) {
     }
 }
 
+/// Lints ink! event fields with (potentially large) heap allocated types
+/// (i.e. `Box<T>`, `Vec<T>`, `String` and `HashMap<K, V>`).
+///
+/// **NOTE**: Types are detected by the name of the last segment of the type's path.
+fn ensure_event_fields_are_not_boxed(results: &mut Vec<Diagnostic>, event: &Event) {
+    if let Some(ast::FieldList::RecordFieldList(field_list)) =
+        event.struct_item().and_then(ast::Struct::field_list)
+    {
+        for field in field_list.fields() {
+            if let Some(ty) = field.ty() {
+                let is_heap_type = ink_analyzer_ir::path_from_type(&ty)
+                    .as_ref()
+                    .and_then(ast::Path::segment)
+                    .and_then(|segment| segment.name_ref())
+                    .map_or(false, |name| {
+                        matches!(name.text().as_str(), "Box" | "Vec" | "String" | "HashMap")
+                    });
+                if is_heap_type {
+                    results.push(Diagnostic {
+                        message: "Large heap types in event fields may increase storage costs; \
+                        consider using fixed-size types."
+                            .to_string(),
+                        range: ty.syntax().text_range(),
+                        severity: Severity::Hint,
+                        quickfixes: None,
+                    });
+                }
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::test_utils::*;
     use crate::Severity;
     use ink_analyzer_ir::syntax::{TextRange, TextSize};
+    use ink_analyzer_ir::InkFile;
     use quote::quote;
     use test_utils::{
         parse_offset_at, quote_as_pretty_string, quote_as_str, TestResultAction,
@@ -431,7 +467,9 @@ mod tests {
             fix.edits[0].range,
             TextRange::new(
                 TextSize::from(parse_offset_at(&code, Some("<-#[ink(message)]")).unwrap() as u32),
-                TextSize::from(parse_offset_at(&code, Some("#[ink(message)]")).unwrap() as u32)
+                TextSize::from(
+                    parse_offset_at(&code, Some("#[ink(message)]\n    ")).unwrap() as u32
+                )
             )
         );
     }
@@ -480,6 +518,119 @@ mod tests {
         );
     }
 
+    #[test]
+    fn non_heap_type_fields_works() {
+        for code in valid_events!() {
+            let event = parse_first_event(quote_as_str! {
+                #code
+            });
+
+            let mut results = Vec::new();
+            ensure_event_fields_are_not_boxed(&mut results, &event);
+            assert!(results.is_empty(), "event: {code}");
+        }
+    }
+
+    #[test]
+    fn heap_type_fields_fails() {
+        for (ty, expected_ty_text) in [
+            (quote! { bool }, None),
+            (quote! { Vec<u8> }, Some("Vec<u8>")),
+            (quote! { String }, Some("String")),
+            (quote! { Box<u32> }, Some("Box<u32>")),
+            (
+                quote! { ink::prelude::vec::Vec<u8> },
+                Some("ink::prelude::vec::Vec<u8>"),
+            ),
+            (quote! { HashMap<u8, u8> }, Some("HashMap<u8, u8>")),
+        ] {
+            let code = quote_as_pretty_string! {
+                #[ink(event)]
+                pub struct MyEvent {
+                    value: #ty,
+                }
+            };
+            let event = parse_first_event(&code);
+
+            let mut results = Vec::new();
+            ensure_event_fields_are_not_boxed(&mut results, &event);
+
+            // Verifies diagnostics.
+            assert_eq!(
+                results
+                    .iter()
+                    .map(|diagnostic| (&code[diagnostic.range], diagnostic.severity))
+                    .collect::<Vec<(&str, Severity)>>(),
+                expected_ty_text
+                    .map(|text| (text, Severity::Hint))
+                    .into_iter()
+                    .collect::<Vec<(&str, Severity)>>(),
+                "event: {code}"
+            );
+        }
+    }
+
+    #[test]
+    fn anonymous_with_event_works() {
+        for code in valid_events!() {
+            let code = quote_as_pretty_string! {
+                #code
+            };
+            let event = parse_first_event(&code);
+            let attrs_range = TextRange::new(
+                event.syntax().text_range().start(),
+                TextSize::from(parse_offset_at(&code, Some("<-pub struct")).unwrap() as u32),
+            );
+
+            // Verifies that there are no ink! attribute diagnostics for the ink! event.
+            let results = crate::analysis::diagnostics::diagnostics(&InkFile::parse(&code));
+            assert!(
+                results
+                    .iter()
+                    .all(|item| !attrs_range.contains_range(item.range)),
+                "event: {code}"
+            );
+        }
+    }
+
+    #[test]
+    fn anonymous_without_event_fails() {
+        let code = quote_as_pretty_string! {
+            #[ink::contract]
+            mod my_contract {
+                #[ink(anonymous)]
+                pub struct MyEvent {
+                    #[ink(topic)]
+                    field_1: i32,
+                }
+            }
+        };
+        let results = crate::analysis::diagnostics::diagnostics(&InkFile::parse(&code));
+        let attr_range = TextRange::new(
+            TextSize::from(parse_offset_at(&code, Some("<-#[ink(anonymous)]")).unwrap() as u32),
+            TextSize::from(parse_offset_at(&code, Some("#[ink(anonymous)]")).unwrap() as u32),
+        );
+        let attr_results: Vec<&Diagnostic> = results
+            .iter()
+            .filter(|item| attr_range.contains_range(item.range))
+            .collect();
+
+        // Verifies diagnostics.
+        assert_eq!(attr_results.len(), 1);
+        assert_eq!(attr_results[0].severity, Severity::Error);
+        // Verifies quickfixes.
+        let expected_quickfixes = vec![TestResultAction {
+            label: "Add an ink! `event`",
+            edits: vec![TestResultTextRange {
+                text: "event, ",
+                start_pat: Some("<-anonymous)]"),
+                end_pat: Some("<-anonymous)]"),
+            }],
+        }];
+        let quickfixes = attr_results[0].quickfixes.as_ref().unwrap();
+        verify_actions(&code, quickfixes, &expected_quickfixes);
+    }
+
     #[test]
     // Ref: <https://github.com/paritytech/ink/blob/v4.1.0/crates/ink/ir/src/ir/item/event.rs#L249-L260>.
     fn compound_diagnostic_works() {
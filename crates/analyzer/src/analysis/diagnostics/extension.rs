@@ -1,11 +1,12 @@
 //! ink! extension diagnostics.
 
 use ink_analyzer_ir::syntax::{AstNode, SyntaxNode};
-use ink_analyzer_ir::{ast, Extension, InkEntity, IsInkFn};
+use ink_analyzer_ir::{ast, Extension, InkEntity, InkVersionHints, IsInkFn};
 use itertools::Itertools;
 
 use super::utils;
-use crate::Diagnostic;
+use crate::analysis::text_edit::TextEdit;
+use crate::{Action, ActionKind, Diagnostic, Severity};
 
 const EXTENSION_SCOPE_NAME: &str = "extension";
 
@@ -16,7 +17,9 @@ const EXTENSION_SCOPE_NAME: &str = "extension";
 /// Ref: <https://github.com/paritytech/ink/blob/v4.1.0/crates/ink/ir/src/ir/chain_extension.rs#L467-L500>.
 ///
 /// Ref: <https://github.com/paritytech/ink/blob/v4.3.0/crates/ink/macro/src/lib.rs#L859-L860>.
-pub fn diagnostics(results: &mut Vec<Diagnostic>, extension: &Extension) {
+///
+/// `version` is the hints about the ink! version used by the file (see `InkFile::used_ink_version_features` doc).
+pub fn diagnostics(results: &mut Vec<Diagnostic>, extension: &Extension, version: InkVersionHints) {
     // Runs generic diagnostics, see `utils::run_generic_diagnostics` doc.
     utils::run_generic_diagnostics(results, extension);
 
@@ -39,6 +42,12 @@ pub fn diagnostics(results: &mut Vec<Diagnostic>, extension: &Extension) {
         }
     }
 
+    // Ensures that ink! extension id is within the range supported by the (likely) ink! version,
+    // see `ensure_valid_id_range` doc.
+    if let Some(diagnostic) = ensure_valid_id_range(extension, version) {
+        results.push(diagnostic);
+    }
+
     // Ensures that ink! extension input and output types implement SCALE codec traits,
     // see `ensure_custom_types_impl_scale_codec_traits` doc.
     ensure_custom_types_impl_scale_codec_traits(results, extension);
@@ -47,6 +56,35 @@ pub fn diagnostics(results: &mut Vec<Diagnostic>, extension: &Extension) {
     utils::ensure_no_ink_descendants(results, extension, EXTENSION_SCOPE_NAME);
 }
 
+/// Ensures that the ink! extension id fits in a `u16` for ink! 5.x
+/// (i.e. chain extension ids are `u16` values in ink! 5.x, but `u32` values in ink! 4.x).
+///
+/// **NOTE**: The `u32` range is enforced by the generic ink! attribute argument diagnostics,
+/// see `utils::run_generic_diagnostics` doc.
+fn ensure_valid_id_range(extension: &Extension, version: InkVersionHints) -> Option<Diagnostic> {
+    let arg = extension.extension_arg()?;
+    let id = arg.value_as_u32()?;
+    if id <= u32::from(u16::MAX) || version.likely_major != Some(5) {
+        return None;
+    }
+
+    let range = arg.value()?.text_range();
+    Some(Diagnostic {
+        message: format!(
+            "ink! 5.x extension ids must fit in a `u16` (i.e. be less than or equal to {}).",
+            u16::MAX
+        ),
+        range: arg.text_range(),
+        severity: Severity::Error,
+        quickfixes: Some(vec![Action {
+            label: format!("Change extension id to `{}` (i.e. `u16::MAX`).", u16::MAX),
+            kind: ActionKind::QuickFix,
+            range,
+            edits: vec![TextEdit::replace(u16::MAX.to_string(), range)],
+        }]),
+    })
+}
+
 // Ensures that the ink! extension input and output types implement SCALE codec traits.
 //
 // Ref: <https://docs.substrate.io/reference/scale-codec/>.
@@ -169,6 +207,7 @@ mod tests {
     use super::*;
     use crate::test_utils::*;
     use crate::Severity;
+    use ink_analyzer_ir::InkFile;
     use quote::quote;
     use test_utils::{quote_as_pretty_string, quote_as_str, TestResultAction, TestResultTextRange};
 
@@ -597,6 +636,75 @@ mod tests {
         }
     }
 
+    #[test]
+    fn valid_id_range_works() {
+        for (id, is_v5) in [
+            // ink! 4.x extension ids are `u32` values.
+            ("1", false),
+            ("65535", false),
+            ("65536", false),
+            ("0xFFFFFFFF", false),
+            // ink! 5.x extension ids are `u16` values.
+            ("1", true),
+            ("65535", true),
+            ("0xFFFF", true),
+        ] {
+            let code = extension_id_code(id, is_v5);
+            let extension = parse_first_extension(&code);
+
+            let version = InkFile::parse(&code).used_ink_version_features();
+            let result = ensure_valid_id_range(&extension, version);
+            assert!(result.is_none(), "code: {code}");
+        }
+    }
+
+    #[test]
+    fn invalid_id_range_fails() {
+        for id in ["65536", "0x10000", "4294967295"] {
+            let code = extension_id_code(id, true);
+            let extension = parse_first_extension(&code);
+
+            let version = InkFile::parse(&code).used_ink_version_features();
+            let result = ensure_valid_id_range(&extension, version);
+
+            // Verifies diagnostics.
+            assert_eq!(
+                result.as_ref().map(|diagnostic| diagnostic.severity),
+                Some(Severity::Error),
+                "code: {code}"
+            );
+            // Verifies quickfixes.
+            let quickfixes = result.unwrap().quickfixes.unwrap();
+            assert_eq!(quickfixes.len(), 1);
+            assert_eq!(quickfixes[0].edits.len(), 1);
+            let edit = &quickfixes[0].edits[0];
+            assert_eq!(edit.text, "65535");
+            assert_eq!(&code[edit.range], id);
+        }
+    }
+
+    // Returns ink! chain extension code with an ink! extension with the given id
+    // (using an ink! 5.x feature if `is_v5` is true).
+    fn extension_id_code(id: &str, is_v5: bool) -> String {
+        format!(
+            r#"
+            {}
+            #[ink::chain_extension]
+            pub trait MyChainExtension {{
+                type ErrorCode = MyErrorCode;
+
+                #[ink(extension={id})]
+                fn my_extension();
+            }}
+            "#,
+            if is_v5 {
+                "#[ink::scale_derive(Encode, Decode, TypeInfo)]\npub enum MyErrorCode {}"
+            } else {
+                "pub enum MyErrorCode {}"
+            }
+        )
+    }
+
     #[test]
     fn no_ink_descendants_works() {
         for code in valid_extensions!() {
@@ -645,7 +753,7 @@ mod tests {
                     edits: vec![TestResultTextRange {
                         text: "",
                         start_pat: Some("<-#[ink(event)]"),
-                        end_pat: Some("#[ink(event)]"),
+                        end_pat: Some("#[ink(event)]\n    "),
                     }],
                 },
                 TestResultAction {
@@ -662,7 +770,7 @@ mod tests {
                 edits: vec![TestResultTextRange {
                     text: "",
                     start_pat: Some("<-#[ink(topic)]"),
-                    end_pat: Some("#[ink(topic)]"),
+                    end_pat: Some("#[ink(topic)]\n        "),
                 }],
             }],
         ];
@@ -680,7 +788,7 @@ mod tests {
             });
 
             let mut results = Vec::new();
-            diagnostics(&mut results, &extension);
+            diagnostics(&mut results, &extension, InkVersionHints::default());
             assert!(results.is_empty(), "extension: {code}");
         }
     }
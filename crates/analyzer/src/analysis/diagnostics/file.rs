@@ -5,7 +5,7 @@ use ink_analyzer_ir::{InkAttributeKind, InkFile};
 use super::{
     chain_extension, contract, ink_e2e_test, ink_test, storage_item, trait_definition, utils,
 };
-use crate::{Diagnostic, Severity};
+use crate::{Action, Diagnostic, Severity};
 
 /// Runs ink! file level diagnostics.
 pub fn diagnostics(results: &mut Vec<Diagnostic>, file: &InkFile) {
@@ -26,8 +26,12 @@ pub fn diagnostics(results: &mut Vec<Diagnostic>, file: &InkFile) {
     }
 
     // Runs ink! chain extension diagnostics, see `chain_extension::diagnostics` doc.
-    for item in file.chain_extensions() {
-        chain_extension::diagnostics(results, item);
+    if !file.chain_extensions().is_empty() {
+        // Computes ink! version hints once for all ink! chain extensions.
+        let version = file.used_ink_version_features();
+        for item in file.chain_extensions() {
+            chain_extension::diagnostics(results, item, version);
+        }
     }
 
     // Runs ink! storage item diagnostics, see `storage_item::diagnostics` doc.
@@ -48,6 +52,9 @@ pub fn diagnostics(results: &mut Vec<Diagnostic>, file: &InkFile) {
     // Ensures that only ink! attribute macro quasi-direct descendants (i.e ink! descendants without any ink! ancestors),
     // See `ensure_valid_quasi_direct_ink_descendants` doc.
     ensure_valid_quasi_direct_ink_descendants(results, file);
+
+    // Ensures that ink! attributes are applied to valid target items, see `ensure_no_orphaned_ink_attrs` doc.
+    ensure_no_orphaned_ink_attrs(results, file);
 }
 
 /// Ensures that there are not multiple ink! contract definitions.
@@ -73,10 +80,38 @@ fn ensure_valid_quasi_direct_ink_descendants(results: &mut Vec<Diagnostic>, file
     });
 }
 
+/// Ensures that ink! attributes are applied to items that are valid targets for their ink! attribute kind
+/// (see `InkFile::detect_orphaned_ink_attrs` doc).
+///
+/// **NOTE**: Most invalid targets are already reported by the diagnostics of the equivalent ink! entity
+/// (e.g. "ink! message must be an `fn` item"), so orphaned ink! attributes whose removal is already
+/// suggested by an error for the same ink! attribute are not reported again.
+fn ensure_no_orphaned_ink_attrs(results: &mut Vec<Diagnostic>, file: &InkFile) {
+    for attr in file.detect_orphaned_ink_attrs() {
+        let remove_attr = Action::remove_attribute(&attr);
+        let is_reported = results.iter().any(|diagnostic| {
+            diagnostic.severity == Severity::Error
+                && diagnostic
+                    .quickfixes
+                    .as_ref()
+                    .is_some_and(|quickfixes| quickfixes.contains(&remove_attr))
+        });
+        if !is_reported {
+            results.push(Diagnostic {
+                message: format!("`{}` can't be applied to this item.", attr.syntax()),
+                range: attr.syntax().text_range(),
+                severity: Severity::Error,
+                quickfixes: Some(vec![remove_attr]),
+            });
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::test_utils::verify_actions;
+    use ink_analyzer_ir::syntax::{TextRange, TextSize};
     use ink_analyzer_ir::InkFile;
     use quote::format_ident;
     use test_utils::{quote_as_pretty_string, quote_as_str, TestResultAction, TestResultTextRange};
@@ -223,7 +258,7 @@ mod tests {
                     edits: vec![TestResultTextRange {
                         text: "",
                         start_pat: Some("<-#[ink(storage)]"),
-                        end_pat: Some("#[ink(storage)]"),
+                        end_pat: Some("#[ink(storage)]\n"),
                     }],
                 },
                 TestResultAction {
@@ -241,7 +276,7 @@ mod tests {
                     edits: vec![TestResultTextRange {
                         text: "",
                         start_pat: Some("<-#[ink(event)]"),
-                        end_pat: Some("#[ink(event)]"),
+                        end_pat: Some("#[ink(event)]\n"),
                     }],
                 },
                 TestResultAction {
@@ -259,7 +294,7 @@ mod tests {
                     edits: vec![TestResultTextRange {
                         text: "",
                         start_pat: Some("<-#[ink(constructor)]"),
-                        end_pat: Some("#[ink(constructor)]"),
+                        end_pat: Some("#[ink(constructor)]\n    "),
                     }],
                 },
                 TestResultAction {
@@ -277,7 +312,7 @@ mod tests {
                     edits: vec![TestResultTextRange {
                         text: "",
                         start_pat: Some("<-#[ink(message)]"),
-                        end_pat: Some("#[ink(message)]"),
+                        end_pat: Some("#[ink(message)]\n    "),
                     }],
                 },
                 TestResultAction {
@@ -295,4 +330,112 @@ mod tests {
             verify_actions(&code, quickfixes, &expected_quickfixes[idx]);
         }
     }
+
+    #[test]
+    fn orphaned_ink_attrs_fails() {
+        for (code, expected_attr) in [
+            (
+                quote_as_pretty_string! {
+                    #[ink(message)]
+                    pub struct MyStruct {}
+                },
+                "#[ink(message)]",
+            ),
+            (
+                quote_as_pretty_string! {
+                    #[ink(storage)]
+                    pub fn my_fn() {}
+                },
+                "#[ink(storage)]",
+            ),
+            (
+                quote_as_pretty_string! {
+                    #[ink(topic)]
+                    pub fn my_fn() {}
+                },
+                "#[ink(topic)]",
+            ),
+            (
+                quote_as_pretty_string! {
+                    #[ink::contract]
+                    pub struct MyContract {}
+                },
+                "#[ink::contract]",
+            ),
+            (
+                quote_as_pretty_string! {
+                    #[ink::test]
+                    pub struct MyTest {}
+                },
+                "#[ink::test]",
+            ),
+        ] {
+            let file = InkFile::parse(&code);
+
+            let mut results = Vec::new();
+            ensure_no_orphaned_ink_attrs(&mut results, &file);
+
+            // Verifies diagnostics.
+            assert_eq!(results.len(), 1, "code: {code}");
+            assert_eq!(results[0].severity, Severity::Error, "code: {code}");
+            assert_eq!(&code[results[0].range], expected_attr, "code: {code}");
+            // Verifies quickfixes.
+            verify_actions(
+                &code,
+                results[0].quickfixes.as_ref().unwrap(),
+                &[TestResultAction {
+                    label: "Remove",
+                    edits: vec![TestResultTextRange {
+                        text: "",
+                        start_pat: Some("<-#["),
+                        end_pat: Some("<-pub"),
+                    }],
+                }],
+            );
+        }
+    }
+
+    #[test]
+    fn reported_orphaned_ink_attrs_are_ignored() {
+        let code = quote_as_pretty_string! {
+            #[ink::contract]
+            mod my_contract {
+                #[ink(storage)]
+                pub struct MyContract {}
+
+                impl MyContract {
+                    #[ink(constructor)]
+                    pub fn new() -> Self {}
+
+                    #[ink(message)]
+                    pub fn my_message(&self) {}
+                }
+
+                #[ink(message)]
+                pub struct MyStruct {}
+            }
+        };
+        let file = InkFile::parse(&code);
+        assert_eq!(file.detect_orphaned_ink_attrs().len(), 1);
+
+        // The orphaned ink! message attribute is already reported by ink! message diagnostics.
+        let mut results = Vec::new();
+        diagnostics(&mut results, &file);
+        assert!(!results.is_empty());
+        assert!(results
+            .iter()
+            .all(|diagnostic| !diagnostic.message.contains("can't be applied to this item")));
+
+        // Errors for other ink! attributes don't hide the orphaned ink! attribute,
+        // even if their range contains it.
+        let mut results = vec![Diagnostic {
+            message: "Unrelated error.".to_string(),
+            range: TextRange::up_to(TextSize::of(&code)),
+            severity: Severity::Error,
+            quickfixes: None,
+        }];
+        ensure_no_orphaned_ink_attrs(&mut results, &file);
+        assert_eq!(results.len(), 2);
+        assert_eq!(&code[results[1].range], "#[ink(message)]", "code: {code}");
+    }
 }
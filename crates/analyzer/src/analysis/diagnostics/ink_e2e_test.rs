@@ -1,9 +1,13 @@
 //! ink! e2e test diagnostics.
 
-use ink_analyzer_ir::InkE2ETest;
+use ink_analyzer_ir::ast::{self, HasName};
+use ink_analyzer_ir::syntax::AstNode;
+use ink_analyzer_ir::{InkE2ETest, IsInkFn};
 
 use super::{environment, utils};
-use crate::Diagnostic;
+use crate::analysis::text_edit::TextEdit;
+use crate::analysis::utils as analysis_utils;
+use crate::{Action, ActionKind, Diagnostic, Severity};
 
 const E2E_TEST_SCOPE_NAME: &str = "e2e test";
 
@@ -22,6 +26,18 @@ pub fn diagnostics(results: &mut Vec<Diagnostic>, ink_e2e_test: &InkE2ETest) {
         results.push(diagnostic);
     }
 
+    if let Some(fn_item) = ink_e2e_test.fn_item() {
+        // Ensures that ink! e2e test is an `async fn`, see `ensure_async` doc.
+        if let Some(diagnostic) = ensure_async(fn_item) {
+            results.push(diagnostic);
+        }
+
+        // Ensures that ink! e2e test returns either `()` or a `Result`, see `ensure_valid_return_type` doc.
+        if let Some(diagnostic) = ensure_valid_return_type(fn_item) {
+            results.push(diagnostic);
+        }
+    }
+
     // Ensures that ink! e2e test has no ink! descendants, see `utils::ensure_no_ink_descendants` doc.
     utils::ensure_no_ink_descendants(results, ink_e2e_test, E2E_TEST_SCOPE_NAME);
 
@@ -29,6 +45,65 @@ pub fn diagnostics(results: &mut Vec<Diagnostic>, ink_e2e_test: &InkE2ETest) {
     environment::diagnostics(results, ink_e2e_test);
 }
 
+/// Ensures that ink! e2e test `fn` is `async`.
+fn ensure_async(fn_item: &ast::Fn) -> Option<Diagnostic> {
+    if fn_item.async_token().is_some() {
+        return None;
+    }
+    let fn_token = fn_item.fn_token()?;
+    // `async` must come before `unsafe` (if any).
+    let insert_offset = fn_item
+        .unsafe_token()
+        .unwrap_or(fn_token.clone())
+        .text_range()
+        .start();
+    Some(Diagnostic {
+        message: "ink! e2e test functions must be `async fn`.".to_string(),
+        range: fn_item
+            .name()
+            .map(|name| name.syntax().text_range())
+            .unwrap_or(fn_token.text_range()),
+        severity: Severity::Error,
+        quickfixes: Some(vec![Action {
+            label: "Add `async` keyword.".to_string(),
+            kind: ActionKind::QuickFix,
+            range: fn_token.text_range(),
+            edits: vec![TextEdit::insert("async ".to_string(), insert_offset)],
+        }]),
+    })
+}
+
+/// Ensures that ink! e2e test `fn` either has no return type (i.e. returns `()`)
+/// or returns a `Result` (e.g. `Result<(), Box<dyn std::error::Error>>` or an alias like `E2EResult<()>`).
+///
+/// **NOTE**: Type aliases can't be resolved, so any path type whose name ends with `Result` is accepted.
+fn ensure_valid_return_type(fn_item: &ast::Fn) -> Option<Diagnostic> {
+    let ret_type = fn_item.ret_type()?;
+    let return_type = ret_type.ty()?;
+    let is_valid = match &return_type {
+        ast::Type::TupleType(tuple_type) => tuple_type.fields().next().is_none(),
+        ast::Type::PathType(path_type) => path_type
+            .path()
+            .and_then(|path| path.segment())
+            .and_then(|segment| segment.name_ref())
+            .is_some_and(|name| name.to_string().ends_with("Result")),
+        _ => false,
+    };
+    // Edit range for quickfix.
+    let range = analysis_utils::node_and_trivia_range(ret_type.syntax());
+    (!is_valid).then_some(Diagnostic {
+        message: "ink! e2e test functions must either return `()` or a `Result`.".to_string(),
+        range: return_type.syntax().text_range(),
+        severity: Severity::Error,
+        quickfixes: Some(vec![Action {
+            label: "Remove return type.".to_string(),
+            kind: ActionKind::QuickFix,
+            range,
+            edits: vec![TextEdit::delete(range)],
+        }]),
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -103,7 +178,9 @@ mod tests {
                     TextSize::from(
                         parse_offset_at(&code, Some("<-#[ink_e2e::test]")).unwrap() as u32
                     ),
-                    TextSize::from(parse_offset_at(&code, Some("#[ink_e2e::test]")).unwrap() as u32)
+                    TextSize::from(
+                        parse_offset_at(&code, Some("#[ink_e2e::test]\n")).unwrap() as u32
+                    )
                 )
             );
         }
@@ -160,7 +237,7 @@ mod tests {
                     edits: vec![TestResultTextRange {
                         text: "",
                         start_pat: Some("<-#[ink(event)]"),
-                        end_pat: Some("#[ink(event)]"),
+                        end_pat: Some("#[ink(event)]\n    "),
                     }],
                 },
                 TestResultAction {
@@ -177,7 +254,144 @@ mod tests {
                 edits: vec![TestResultTextRange {
                     text: "",
                     start_pat: Some("<-#[ink(topic)]"),
-                    end_pat: Some("#[ink(topic)]"),
+                    end_pat: Some("#[ink(topic)]\n        "),
+                }],
+            }],
+        ];
+        for (idx, item) in results.iter().enumerate() {
+            let quickfixes = item.quickfixes.as_ref().unwrap();
+            verify_actions(&code, quickfixes, &expected_quickfixes[idx]);
+        }
+    }
+
+    #[test]
+    fn async_fn_and_valid_return_type_works() {
+        for code in [
+            quote! {
+                async fn it_works(mut client: ::ink_e2e::Client<C,E>) {
+                }
+            },
+            quote! {
+                async fn it_works(mut client: ::ink_e2e::Client<C,E>) -> () {
+                }
+            },
+            quote! {
+                async fn it_works(mut client: ::ink_e2e::Client<C,E>) -> Result<(), Box<dyn std::error::Error>> {
+                }
+            },
+            quote! {
+                async fn it_works(mut client: ::ink_e2e::Client<C,E>) -> E2EResult<()> {
+                }
+            },
+        ] {
+            let code = quote_as_pretty_string! {
+                #[ink_e2e::test]
+                #code
+            };
+            let ink_e2e_test = parse_first_ink_e2e_test(&code);
+            let fn_item = ink_e2e_test.fn_item().unwrap();
+
+            assert!(ensure_async(fn_item).is_none(), "code: {code}");
+            assert!(ensure_valid_return_type(fn_item).is_none(), "code: {code}");
+        }
+    }
+
+    #[test]
+    fn non_async_fn_fails() {
+        for (code, expected_quickfixes) in [
+            (
+                quote! {
+                    fn it_works(mut client: ::ink_e2e::Client<C,E>) {
+                    }
+                },
+                vec![TestResultAction {
+                    label: "Add `async`",
+                    edits: vec![TestResultTextRange {
+                        text: "async ",
+                        start_pat: Some("<-fn"),
+                        end_pat: Some("<-fn"),
+                    }],
+                }],
+            ),
+            (
+                quote! {
+                    pub unsafe fn it_works(mut client: ::ink_e2e::Client<C,E>) {
+                    }
+                },
+                vec![TestResultAction {
+                    label: "Add `async`",
+                    edits: vec![TestResultTextRange {
+                        text: "async ",
+                        start_pat: Some("<-unsafe"),
+                        end_pat: Some("<-unsafe"),
+                    }],
+                }],
+            ),
+        ] {
+            let code = quote_as_pretty_string! {
+                #[ink_e2e::test]
+                #code
+            };
+            let ink_e2e_test = parse_first_ink_e2e_test(&code);
+
+            let result = ensure_async(ink_e2e_test.fn_item().unwrap());
+
+            // Verifies diagnostics.
+            assert_eq!(
+                result.as_ref().map(|diagnostic| diagnostic.severity),
+                Some(Severity::Error),
+                "code: {code}"
+            );
+            // Verifies quickfixes.
+            verify_actions(
+                &code,
+                result.as_ref().unwrap().quickfixes.as_ref().unwrap(),
+                &expected_quickfixes,
+            );
+        }
+    }
+
+    #[test]
+    fn non_async_fn_and_invalid_return_type_fails() {
+        let code = quote_as_pretty_string! {
+            #[ink_e2e::test]
+            fn it_works(mut client: ::ink_e2e::Client<C,E>) -> bool {
+            }
+        };
+        let ink_e2e_test = parse_first_ink_e2e_test(&code);
+
+        let mut results = Vec::new();
+        diagnostics(&mut results, &ink_e2e_test);
+        // 2 diagnostics for missing `async` keyword and invalid return type.
+        assert_eq!(results.len(), 2);
+        // All diagnostics should be errors.
+        assert!(results
+            .iter()
+            .all(|diagnostic| diagnostic.severity == Severity::Error));
+        // Verifies diagnostic ranges.
+        assert_eq!(
+            results[1].range,
+            TextRange::new(
+                TextSize::from(parse_offset_at(&code, Some("<-bool")).unwrap() as u32),
+                TextSize::from(parse_offset_at(&code, Some("bool")).unwrap() as u32)
+            )
+        );
+        // Verifies quickfixes.
+        let expected_quickfixes = vec![
+            vec![TestResultAction {
+                label: "Add `async`",
+                edits: vec![TestResultTextRange {
+                    text: "async ",
+                    start_pat: Some("<-fn"),
+                    end_pat: Some("<-fn"),
+                }],
+            }],
+            vec![TestResultAction {
+                label: "Remove return type",
+                edits: vec![TestResultTextRange {
+                    text: "",
+                    start_pat: Some("<--> bool"),
+                    end_pat: Some("bool "),
                 }],
             }],
         ];
@@ -6,9 +6,8 @@ use ink_analyzer_ir::{
     ast, HasInkImplParent, InkArg, InkArgKind, InkArgValueKind, InkAttributeKind, InkEntity,
     InkImpl, IsInkFn, IsInkTrait, Message,
 };
-use itertools::Itertools;
+use itertools::{Either, Itertools};
 use std::collections::{HashMap, HashSet};
-use std::iter;
 
 use super::{constructor, message, utils};
 use crate::analysis::actions::entity as entity_actions;
@@ -177,96 +176,84 @@ pub fn ensure_impl_invariants(results: &mut Vec<Diagnostic>, ink_impl: &InkImpl)
             });
         }
 
-        let constructor_fns: Vec<&ast::Fn> = ink_impl
-            .constructors()
-            .iter()
-            .filter_map(IsInkFn::fn_item)
-            .collect();
-        let message_fns: Vec<&ast::Fn> = ink_impl
-            .messages()
-            .iter()
-            .filter_map(IsInkFn::fn_item)
-            .collect();
-        for (fns, name) in [(constructor_fns, "constructor"), (message_fns, "message")] {
-            for fn_item in fns {
-                if impl_item.trait_().is_some() {
-                    // Callables must have inherent visibility for trait implementation blocks.
-                    if let Some(visibility) = fn_item.visibility() {
-                        // Edit range for quickfix.
-                        let range = analysis_utils::node_and_trivia_range(visibility.syntax());
-                        results.push(Diagnostic {
-                            message: format!("ink! {name}s in trait ink! impl blocks must have inherited visibility."),
-                            range: visibility.syntax().text_range(),
-                            severity: Severity::Error,
-                            quickfixes: Some(vec![Action {
-                                label: format!("Remove visibility `{}`.", visibility.syntax()),
-                                kind: ActionKind::QuickFix,
-                                range,
-                                edits: vec![TextEdit::delete(range)],
-                            }]),
-                        });
-                    }
-                } else {
-                    // Callables must have `pub` visibility for inherent implementation blocks.
-                    let (has_pub_visibility, visibility) = match fn_item.visibility() {
-                        // Check `pub` visibility.
-                        Some(visibility) => {
-                            (visibility.syntax().to_string() == "pub", Some(visibility))
-                        }
-                        // Inherited visibility.
-                        None => (false, None),
-                    };
-
-                    if !has_pub_visibility {
-                        // Gets the declaration range for the `fn` item.
-                        let fn_declaration_range = analysis_utils::ast_item_declaration_range(
-                            &ast::Item::Fn(fn_item.clone()),
-                        )
-                        .unwrap_or(fn_item.syntax().text_range());
-                        results.push(Diagnostic {
-                            message: format!(
-                                "ink! {name}s in inherent ink! impl blocks must have `pub` visibility."
-                            ),
-                            range: visibility
-                                .as_ref()
-                                .map_or(fn_declaration_range, |it| it.syntax().text_range()),
-                            severity: Severity::Error,
-                            quickfixes: visibility
-                                .as_ref()
-                                .map(|vis| vis.syntax().text_range())
-                                .or(fn_item
-                                    .default_token()
-                                    .or(fn_item.const_token())
-                                    .or(fn_item.async_token())
-                                    .or(fn_item.unsafe_token())
-                                    .or(fn_item.abi().and_then(|abi| abi.syntax().first_token()))
-                                    .or(fn_item.fn_token())
-                                    .map(|it| {
-                                        TextRange::new(
-                                            it.text_range().start(),
-                                            it.text_range().start(),
-                                        )
-                                    }))
-                                .map(|range| {
-                                    vec![Action {
-                                        label: "Change visibility to `pub`.".to_string(),
-                                        kind: ActionKind::QuickFix,
-                                        range: visibility
-                                            .as_ref()
-                                            .map_or(fn_declaration_range, |it| {
-                                                it.syntax().text_range()
-                                            }),
-                                        edits: vec![TextEdit::replace(
-                                            format!(
-                                                "pub{}",
-                                                if visibility.is_none() { " " } else { "" }
-                                            ),
-                                            range,
-                                        )],
-                                    }]
-                                }),
-                        });
+        let callable_fns = ink_impl
+            .all_callables()
+            .filter_map(|callable| match callable {
+                Either::Left(constructor) => constructor.fn_item().zip(Some("constructor")),
+                Either::Right(message) => message.fn_item().zip(Some("message")),
+            });
+        for (fn_item, name) in callable_fns {
+            if impl_item.trait_().is_some() {
+                // Callables must have inherent visibility for trait implementation blocks.
+                if let Some(visibility) = fn_item.visibility() {
+                    // Edit range for quickfix.
+                    let range = analysis_utils::node_and_trivia_range(visibility.syntax());
+                    results.push(Diagnostic {
+                        message: format!("ink! {name}s in trait ink! impl blocks must have inherited visibility."),
+                        range: visibility.syntax().text_range(),
+                        severity: Severity::Error,
+                        quickfixes: Some(vec![Action {
+                            label: format!("Remove visibility `{}`.", visibility.syntax()),
+                            kind: ActionKind::QuickFix,
+                            range,
+                            edits: vec![TextEdit::delete(range)],
+                        }]),
+                    });
+                }
+            } else {
+                // Callables must have `pub` visibility for inherent implementation blocks.
+                let (has_pub_visibility, visibility) = match fn_item.visibility() {
+                    // Check `pub` visibility.
+                    Some(visibility) => {
+                        (visibility.syntax().to_string() == "pub", Some(visibility))
                     }
+                    // Inherited visibility.
+                    None => (false, None),
+                };
+
+                if !has_pub_visibility {
+                    // Gets the declaration range for the `fn` item.
+                    let fn_declaration_range =
+                        analysis_utils::ast_item_declaration_range(&ast::Item::Fn(fn_item.clone()))
+                            .unwrap_or(fn_item.syntax().text_range());
+                    results.push(Diagnostic {
+                        message: format!(
+                            "ink! {name}s in inherent ink! impl blocks must have `pub` visibility."
+                        ),
+                        range: visibility
+                            .as_ref()
+                            .map_or(fn_declaration_range, |it| it.syntax().text_range()),
+                        severity: Severity::Error,
+                        quickfixes: visibility
+                            .as_ref()
+                            .map(|vis| vis.syntax().text_range())
+                            .or(fn_item
+                                .default_token()
+                                .or(fn_item.const_token())
+                                .or(fn_item.async_token())
+                                .or(fn_item.unsafe_token())
+                                .or(fn_item.abi().and_then(|abi| abi.syntax().first_token()))
+                                .or(fn_item.fn_token())
+                                .map(|it| {
+                                    TextRange::new(it.text_range().start(), it.text_range().start())
+                                }))
+                            .map(|range| {
+                                vec![Action {
+                                    label: "Change visibility to `pub`.".to_string(),
+                                    kind: ActionKind::QuickFix,
+                                    range: visibility.as_ref().map_or(fn_declaration_range, |it| {
+                                        it.syntax().text_range()
+                                    }),
+                                    edits: vec![TextEdit::replace(
+                                        format!(
+                                            "pub{}",
+                                            if visibility.is_none() { " " } else { "" }
+                                        ),
+                                        range,
+                                    )],
+                                }]
+                            }),
+                    });
                 }
             }
         }
@@ -391,7 +378,11 @@ fn ensure_trait_definition_impl_invariants(results: &mut Vec<Diagnostic>, ink_im
                     .collect()
             })
             .unwrap_or(HashSet::new());
-        let mut seen_messages: HashSet<String> = HashSet::new();
+        // Maps message name to parameter list mismatch description.
+        let param_list_mismatches: HashMap<String, String> = ink_impl
+            .verify_message_signatures_match_trait()
+            .into_iter()
+            .collect();
 
         if let Some(assoc_item_list) = impl_item.assoc_item_list() {
             for item in assoc_item_list.assoc_items() {
@@ -414,7 +405,8 @@ fn ensure_trait_definition_impl_invariants(results: &mut Vec<Diagnostic>, ink_im
                                 }
                                 // Verifies that method signatures and ink! attribute arguments match the declaration.
                                 Some(message_declaration) => {
-                                    seen_messages.insert(fn_name_text);
+                                    let param_list_mismatch =
+                                        param_list_mismatches.get(&fn_name_text);
 
                                     // Verifies that `fn` item has attributes that match the equivalent ink! trait definition method.
                                     ensure_trait_definition_impl_message_args(
@@ -425,30 +417,15 @@ fn ensure_trait_definition_impl_invariants(results: &mut Vec<Diagnostic>, ink_im
 
                                     if let Some(fn_declaration) = message_declaration.fn_item() {
                                         // Verifies that param list matches the declaration.
-                                        let diagnostic_range = fn_item
-                                            .param_list()
-                                            .map_or(fn_name.syntax().text_range(), |it| {
-                                                it.syntax().text_range()
-                                            });
-                                        let replace_range = fn_item.param_list().map_or(
-                                            TextRange::new(
-                                                fn_name.syntax().text_range().end(),
-                                                fn_name.syntax().text_range().end(),
-                                            ),
-                                            |it| it.syntax().text_range(),
-                                        );
-                                        verify_signature_part_match(
-                                            results,
-                                            fn_declaration
-                                                .param_list()
-                                                .as_ref()
-                                                .map(|it| it.syntax()),
-                                            fn_item.param_list().as_ref().map(|it| it.syntax()),
-                                            diagnostic_range,
-                                            replace_range,
-                                            "parameter list",
-                                            Some("parameters"),
-                                        );
+                                        if let Some(description) = param_list_mismatch {
+                                            ensure_param_list_matches_declaration(
+                                                results,
+                                                fn_item,
+                                                &fn_name,
+                                                fn_declaration,
+                                                description,
+                                            );
+                                        }
 
                                         // Verifies that return type matches the declaration.
                                         let fallback_insert_offset = fn_item
@@ -503,25 +480,14 @@ fn ensure_trait_definition_impl_invariants(results: &mut Vec<Diagnostic>, ink_im
             }
         }
 
-        // Computes diagnostic for missing messages (if any).
-        let mut missing_messages = message_index.values().filter_map(|message| {
-            // Filters missing messages (with valid names).
-            message.fn_item().and_then(|fn_item| {
-                fn_item
-                    .name()
-                    .is_some_and(|name| {
-                        let name_text = name.to_string();
-                        !name_text.is_empty() && !seen_messages.contains(&name_text)
-                    })
-                    .then_some(fn_item)
-            })
-        });
-        if let Some(fn_item) = missing_messages.next() {
-            let (insert_offset, indent_option, prefix_option, suffix_option) =
+        // Computes diagnostics for unimplemented messages (if any).
+        let unimplemented_messages = trait_definition.unimplemented_messages(ink_impl);
+        if !unimplemented_messages.is_empty() {
+            let (insert_offset, indent, prefix_option, suffix_option) =
                 impl_item.assoc_item_list().map_or(
                     (
                         impl_item.syntax().text_range().end(),
-                        Some(analysis_utils::item_children_indenting(impl_item.syntax())),
+                        analysis_utils::item_children_indenting(impl_item.syntax()),
                         Some(" {"),
                         Some(format!(
                             "{}}}",
@@ -533,90 +499,120 @@ fn ensure_trait_definition_impl_invariants(results: &mut Vec<Diagnostic>, ink_im
                     |assoc_item_list| {
                         (
                             analysis_utils::assoc_item_insert_offset_end(&assoc_item_list),
-                            Some(analysis_utils::item_children_indenting(impl_item.syntax())),
+                            analysis_utils::item_children_indenting(impl_item.syntax()),
                             None,
                             None,
                         )
                     },
                 );
             let range = analysis_utils::ink_impl_declaration_range(ink_impl);
-            let mut edit_option = None;
-            let mut snippet_option = None;
-            let mut snippet_idx = 1;
-            for fn_item in iter::once(fn_item).chain(missing_messages) {
-                // Only create quickfixes if the method name is declared properly.
-                if let Some(fn_name) = fn_item.name().as_ref().map(ToString::to_string) {
-                    let params = fn_item.param_list().map(|param_list| {
-                        let self_param = param_list.self_param().as_ref().map(ToString::to_string);
-                        let other_params = param_list.params().join(", ");
-                        format!(
-                            "{}{}{}",
-                            self_param.as_deref().unwrap_or_default(),
-                            if self_param.is_some() && !other_params.is_empty() {
-                                ", "
-                            } else {
-                                ""
-                            },
-                            other_params
-                        )
-                    });
-                    let ret_type = fn_item
-                        .ret_type()
-                        .as_ref()
-                        .and_then(ast::RetType::ty)
-                        .as_ref()
-                        .map(ToString::to_string);
-                    let fn_sig = format!(
-                        "fn {fn_name}({}){}{}",
-                        params.unwrap_or_default(),
-                        if ret_type.is_some() { " -> " } else { "" },
-                        ret_type.unwrap_or_default()
-                    );
-                    let item_spacing = if snippet_idx > 1 { "\n\n" } else { "" };
-
-                    edit_option = Some(format!(
-                        "{}{item_spacing}{fn_sig} {{\n    todo!()\n}}",
-                        edit_option.unwrap_or_default()
-                    ));
-                    snippet_option = Some(format!(
-                        "{}{item_spacing}{fn_sig} {{\n    ${{{snippet_idx}:todo!()}}\n}}",
-                        snippet_option.unwrap_or_default()
-                    ));
-                    snippet_idx += 1;
-                }
-            }
-            let format_edit = |edit: String| {
+            let format_edit = |edit: &str| {
                 format!(
                     "{}{}{}",
                     prefix_option.unwrap_or_default(),
-                    indent_option.as_ref().map_or(edit.clone(), |indent| {
-                        analysis_utils::apply_indenting(&edit, indent)
-                    }),
+                    analysis_utils::apply_indenting(edit, &indent),
                     suffix_option.as_deref().unwrap_or_default()
                 )
             };
-            results.push(Diagnostic {
-                message: "Missing message(s) for ink! trait definition implementation.".to_string(),
-                range,
-                severity: Severity::Error,
-                quickfixes: edit_option.map(|edit| {
-                    vec![Action {
-                        label: "Add missing message(s) to ink! trait definition implementation."
-                            .to_string(),
+            for fn_item in unimplemented_messages
+                .iter()
+                .filter_map(|message| message.fn_item())
+            {
+                let Some(fn_name) = fn_item.name().as_ref().map(ToString::to_string) else {
+                    continue;
+                };
+                let params = fn_item.param_list().map(|param_list| {
+                    let self_param = param_list.self_param().as_ref().map(ToString::to_string);
+                    let other_params = param_list.params().join(", ");
+                    format!(
+                        "{}{}{}",
+                        self_param.as_deref().unwrap_or_default(),
+                        if self_param.is_some() && !other_params.is_empty() {
+                            ", "
+                        } else {
+                            ""
+                        },
+                        other_params
+                    )
+                });
+                let ret_type = fn_item
+                    .ret_type()
+                    .as_ref()
+                    .and_then(ast::RetType::ty)
+                    .as_ref()
+                    .map(ToString::to_string);
+                let fn_sig = format!(
+                    "fn {fn_name}({}){}{}",
+                    params.unwrap_or_default(),
+                    if ret_type.is_some() { " -> " } else { "" },
+                    ret_type.unwrap_or_default()
+                );
+                let edit = format!("{fn_sig} {{\n    todo!()\n}}");
+                let snippet = format!("{fn_sig} {{\n    ${{1:todo!()}}\n}}");
+                results.push(Diagnostic {
+                    message: format!(
+                        "Missing `{fn_name}` message for ink! trait definition implementation."
+                    ),
+                    range,
+                    severity: Severity::Error,
+                    quickfixes: Some(vec![Action {
+                        label: format!(
+                            "Add missing `{fn_name}` message to ink! trait definition implementation."
+                        ),
                         kind: ActionKind::QuickFix,
                         range,
                         edits: vec![TextEdit::insert_with_snippet(
-                            format_edit(edit),
+                            format_edit(&edit),
                             insert_offset,
-                            snippet_option.map(format_edit),
+                            Some(format_edit(&snippet)),
                         )],
-                    }]
-                }),
-            })
+                    }]),
+                });
+            }
         }
     }
 }
 
+/// Ensures that the parameter list of a trait definition implementation method matches
+/// the declaration in the ink! trait definition.
+///
+/// (i.e. reports the mismatches found by `InkImpl::verify_message_signatures_match_trait`).
+fn ensure_param_list_matches_declaration(
+    results: &mut Vec<Diagnostic>,
+    fn_item: &ast::Fn,
+    fn_name: &ast::Name,
+    fn_declaration: &ast::Fn,
+    description: &str,
+) {
+    let name_range = fn_name.syntax().text_range();
+    let range = fn_item
+        .param_list()
+        .map_or(name_range, |it| it.syntax().text_range());
+    let replace_range = fn_item
+        .param_list()
+        .map_or(TextRange::new(name_range.end(), name_range.end()), |it| {
+            it.syntax().text_range()
+        });
+    results.push(Diagnostic {
+        message: format!(
+            "The parameter list for this method doesn't match \
+            the ink! trait definition declaration for the method ({description})."
+        ),
+        range,
+        severity: Severity::Error,
+        quickfixes: fn_declaration.param_list().map(|declared| {
+            vec![Action {
+                label: "Change parameter list to match the \
+                ink! trait definition declaration for the method."
+                    .to_string(),
+                kind: ActionKind::QuickFix,
+                range,
+                edits: vec![TextEdit::replace(declared.to_string(), replace_range)],
+            }]
+        }),
+    });
+}
+
 /// Verifies that two signature parts "match" or creates an appropriate diagnostic and quickfix.
 fn verify_signature_part_match(
     results: &mut Vec<Diagnostic>,
@@ -701,7 +697,7 @@ fn ensure_trait_definition_impl_message_args(
     // Verifies that `fn` item has attributes that match the equivalent ink! trait definition method.
     let ink_arg_index: HashMap<InkArgKind, InkArg> =
         ink_analyzer_ir::ink_args(message_declaration.syntax())
-            .filter_map(|arg| (*arg.kind() != InkArgKind::Unknown).then_some((*arg.kind(), arg)))
+            .filter_map(|arg| (!arg.is_unknown()).then_some((*arg.kind(), arg)))
             .collect();
     let mut seen_arg_kinds: HashSet<InkArgKind> = HashSet::new();
     for attr in ink_analyzer_ir::ink_attrs(fn_item.syntax()) {
@@ -1163,7 +1159,7 @@ mod tests {
                 fix.edits[0].range,
                 TextRange::new(
                     TextSize::from(parse_offset_at(&code, Some("<-#[ink(impl)]")).unwrap() as u32),
-                    TextSize::from(parse_offset_at(&code, Some("#[ink(impl)]")).unwrap() as u32)
+                    TextSize::from(parse_offset_at(&code, Some("#[ink(impl)]\n")).unwrap() as u32)
                 )
             );
         }
@@ -1530,7 +1526,7 @@ mod tests {
                     impl MyTrait for MyContract {}
                 },
                 vec![TestResultAction {
-                    label: "missing message",
+                    label: "Add missing",
                     edits: vec![TestResultTextRange {
                         text: "fn my_message(&self) {",
                         start_pat: Some("impl MyTrait for MyContract {"),
@@ -1549,7 +1545,7 @@ mod tests {
                     impl MyTrait for MyContract {}
                 },
                 vec![TestResultAction {
-                    label: "missing message",
+                    label: "Add missing",
                     edits: vec![TestResultTextRange {
                         text: "fn my_message(&self, value: u8) -> u8 {",
                         start_pat: Some("impl MyTrait for MyContract {"),
@@ -1574,7 +1570,7 @@ mod tests {
                     }
                 },
                 vec![TestResultAction {
-                    label: "missing message",
+                    label: "Add missing",
                     edits: vec![TestResultTextRange {
                         text: "fn my_message_2(&self) {",
                         start_pat: Some("fn my_message(&self) {}"),
@@ -1764,6 +1760,113 @@ mod tests {
         }
     }
 
+    #[test]
+    fn unimplemented_trait_definition_messages_fails() {
+        for (impl_code, expected_missing) in [
+            // 1 out of 3 messages implemented.
+            (
+                quote! {
+                    impl MyTrait for MyContract {
+                        #[ink(message)]
+                        fn my_message_2(&self) {}
+                    }
+                },
+                vec!["my_message", "my_message_3"],
+            ),
+            // 2 out of 3 messages implemented.
+            (
+                quote! {
+                    impl MyTrait for MyContract {
+                        #[ink(message)]
+                        fn my_message(&self) {}
+
+                        #[ink(message)]
+                        fn my_message_3(&mut self, value: u8) -> u8 {}
+                    }
+                },
+                vec!["my_message_2"],
+            ),
+        ] {
+            let code = quote_as_pretty_string! {
+                #[ink::trait_definition]
+                pub trait MyTrait {
+                    #[ink(message)]
+                    fn my_message(&self);
+
+                    #[ink(message)]
+                    fn my_message_2(&self);
+
+                    #[ink(message)]
+                    fn my_message_3(&mut self, value: u8) -> u8;
+                }
+
+                #impl_code
+            };
+            let ink_impl = parse_first_ink_impl(&code);
+
+            let mut results = Vec::new();
+            ensure_trait_definition_impl_invariants(&mut results, &ink_impl);
+
+            // Verifies diagnostics (i.e. 1 error per unimplemented message, in declaration order).
+            assert_eq!(results.len(), expected_missing.len(), "impl: {code}");
+            for (diagnostic, fn_name) in results.iter().zip(expected_missing) {
+                assert_eq!(diagnostic.severity, Severity::Error, "impl: {code}");
+                assert!(
+                    diagnostic.message.contains(&format!("`{fn_name}`")),
+                    "impl: {code}"
+                );
+                // Verifies quickfixes.
+                let quickfixes = diagnostic.quickfixes.as_ref().unwrap();
+                assert_eq!(quickfixes.len(), 1, "impl: {code}");
+                assert!(
+                    quickfixes[0].edits[0]
+                        .text
+                        .contains(&format!("fn {fn_name}(")),
+                    "impl: {code}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn mismatched_trait_definition_message_param_type_fails() {
+        let code = test_utils::read_source_code("trait_definitions/erc20_trait").replace(
+            "fn transfer(&mut self, to: AccountId, value: Balance) -> Result<()> {",
+            "fn transfer(&mut self, to: AccountId, value: u8) -> Result<()> {",
+        );
+        let ink_impl = InkFile::parse(&code).contracts()[0]
+            .impls()
+            .iter()
+            .find(|ink_impl| ink_impl.trait_type().is_some())
+            .unwrap()
+            .clone();
+
+        let mut results = Vec::new();
+        ensure_trait_definition_impl_invariants(&mut results, &ink_impl);
+
+        // Verifies diagnostics.
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].severity, Severity::Error);
+        assert_eq!(
+            &code[results[0].range],
+            "(&mut self, to: AccountId, value: u8)"
+        );
+        assert!(results[0].message.contains("value: u8"));
+        // Verifies quickfixes.
+        verify_actions(
+            &code,
+            results[0].quickfixes.as_ref().unwrap(),
+            &[TestResultAction {
+                label: "parameter list",
+                edits: vec![TestResultTextRange {
+                    text: "(&mut self, to: AccountId, value: Balance)",
+                    start_pat: Some("<-(&mut self, to: AccountId, value: u8)"),
+                    end_pat: Some("(&mut self, to: AccountId, value: u8)"),
+                }],
+            }],
+        );
+    }
+
     #[test]
     fn valid_quasi_direct_descendant_works() {
         for code in valid_ink_impls!() {
@@ -1821,7 +1924,7 @@ mod tests {
                     edits: vec![TestResultTextRange {
                         text: "",
                         start_pat: Some("<-#[ink(storage)]"),
-                        end_pat: Some("#[ink(storage)]"),
+                        end_pat: Some("#[ink(storage)]\n    "),
                     }],
                 },
                 TestResultAction {
@@ -1839,7 +1942,7 @@ mod tests {
                     edits: vec![TestResultTextRange {
                         text: "",
                         start_pat: Some("<-#[ink(event)]"),
-                        end_pat: Some("#[ink(event)]"),
+                        end_pat: Some("#[ink(event)]\n    "),
                     }],
                 },
                 TestResultAction {
@@ -1857,7 +1960,7 @@ mod tests {
                     edits: vec![TestResultTextRange {
                         text: "",
                         start_pat: Some("<-#[ink::trait_definition]"),
-                        end_pat: Some("#[ink::trait_definition]"),
+                        end_pat: Some("#[ink::trait_definition]\n    "),
                     }],
                 },
                 TestResultAction {
@@ -1875,7 +1978,7 @@ mod tests {
                     edits: vec![TestResultTextRange {
                         text: "",
                         start_pat: Some("<-#[ink::chain_extension]"),
-                        end_pat: Some("#[ink::chain_extension]"),
+                        end_pat: Some("#[ink::chain_extension]\n    "),
                     }],
                 },
                 TestResultAction {
@@ -1893,7 +1996,7 @@ mod tests {
                     edits: vec![TestResultTextRange {
                         text: "",
                         start_pat: Some("<-#[ink::storage_item]"),
-                        end_pat: Some("#[ink::storage_item]"),
+                        end_pat: Some("#[ink::storage_item]\n    "),
                     }],
                 },
                 TestResultAction {
@@ -1924,6 +2027,13 @@ mod tests {
 
             let mut results = Vec::new();
             diagnostics(&mut results, &ink_impl, false);
+            // Ignores warnings about payable ink! messages with a `&self` receiver
+            // (i.e. valid but "unusual" ink! messages).
+            results.retain(|diagnostic| {
+                !diagnostic
+                    .message
+                    .starts_with("A payable ink! message typically needs `&mut self`")
+            });
             assert!(results.is_empty(), "impl: {code}");
         }
     }
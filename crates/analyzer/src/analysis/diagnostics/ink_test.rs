@@ -1,9 +1,12 @@
 //! ink! test diagnostics.
 
-use ink_analyzer_ir::InkTest;
+use ink_analyzer_ir::ast::{AstNode, HasAttrs};
+use ink_analyzer_ir::{ast, InkEntity, InkTest, IsInkFn};
 
 use super::utils;
-use crate::Diagnostic;
+use crate::analysis::text_edit::TextEdit;
+use crate::analysis::utils as analysis_utils;
+use crate::{Action, ActionKind, Diagnostic, Severity};
 
 const TEST_SCOPE_NAME: &str = "test";
 
@@ -24,10 +27,62 @@ pub fn diagnostics(results: &mut Vec<Diagnostic>, ink_test: &InkTest) {
         results.push(diagnostic);
     }
 
+    // Ensures that ink! test is defined inside a `#[cfg(test)]` module, see `ensure_cfg_test_parent` doc.
+    if let Some(diagnostic) = ensure_cfg_test_parent(ink_test) {
+        results.push(diagnostic);
+    }
+
     // Ensures that ink! test has no ink! descendants, see `utils::ensure_no_ink_descendants` doc.
     utils::ensure_no_ink_descendants(results, ink_test, TEST_SCOPE_NAME);
 }
 
+/// Ensures that ink! test `fn` is defined inside a `#[cfg(test)]` module.
+///
+/// An ink! test outside a test-only module compiles but is never run,
+/// so this is a warning rather than an error.
+fn ensure_cfg_test_parent(ink_test: &InkTest) -> Option<Diagnostic> {
+    let fn_item = ink_test.fn_item()?;
+    let has_cfg_test_parent = fn_item
+        .syntax()
+        .ancestors()
+        .filter_map(ast::Module::cast)
+        .any(|module| {
+            module
+                .attrs()
+                .any(|attr| analysis_utils::is_cfg_test_attr(&attr))
+        });
+    if has_cfg_test_parent {
+        return None;
+    }
+
+    // Wraps the ink! test `fn` (including its attributes and rustdoc) in a new `#[cfg(test)]` module.
+    let range = ink_test.syntax().text_range();
+    let indent = analysis_utils::item_indenting(ink_test.syntax()).unwrap_or_default();
+    let fn_text = analysis_utils::reduce_indenting(&ink_test.syntax().to_string(), &indent);
+    let module_text = analysis_utils::apply_indenting(
+        &format!(
+            "#[cfg(test)]\nmod tests {{\n    use super::*;\n\n{}\n}}",
+            analysis_utils::apply_indenting(&fn_text, "    ")
+        ),
+        &indent,
+    );
+    Some(Diagnostic {
+        message: "ink! tests should be inside a `#[cfg(test)]` module.".to_string(),
+        range: analysis_utils::ast_item_declaration_range(&ast::Item::Fn(fn_item.clone()))
+            .unwrap_or(fn_item.syntax().text_range()),
+        severity: Severity::Warning,
+        quickfixes: Some(vec![Action {
+            label: "Wrap ink! test in a `#[cfg(test)]` module.".to_string(),
+            kind: ActionKind::QuickFix,
+            range,
+            edits: vec![TextEdit::replace(
+                module_text.trim_start().to_string(),
+                range,
+            )],
+        }]),
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -98,12 +153,112 @@ mod tests {
                 fix.edits[0].range,
                 TextRange::new(
                     TextSize::from(parse_offset_at(&code, Some("<-#[ink::test]")).unwrap() as u32),
-                    TextSize::from(parse_offset_at(&code, Some("#[ink::test]")).unwrap() as u32)
+                    TextSize::from(parse_offset_at(&code, Some("#[ink::test]\n")).unwrap() as u32)
                 )
             );
         }
     }
 
+    #[test]
+    fn cfg_test_parent_works() {
+        for code in [
+            quote_as_str! {
+                #[cfg(test)]
+                mod tests {
+                    #[ink::test]
+                    fn it_works() {
+                    }
+                }
+            },
+            quote_as_str! {
+                #[cfg(all(test, feature = "std"))]
+                mod tests {
+                    #[ink::test]
+                    fn it_works() {
+                    }
+                }
+            },
+            quote_as_str! {
+                #[cfg(test)]
+                mod tests {
+                    mod nested {
+                        #[ink::test]
+                        fn it_works() {
+                        }
+                    }
+                }
+            },
+        ] {
+            let ink_test = parse_first_ink_test(code);
+
+            let result = ensure_cfg_test_parent(&ink_test);
+            assert!(result.is_none(), "ink test: {code}");
+        }
+    }
+
+    #[test]
+    fn no_cfg_test_parent_fails() {
+        for (code, expected_text, start_pat, end_pat) in [
+            (
+                r#"
+#[ink::test]
+fn it_works() {
+}"#,
+                "#[cfg(test)]\nmod tests {\n    use super::*;\n\n    #[ink::test]\n    fn it_works() {\n    }\n}",
+                "<-#[ink::test]",
+                "}",
+            ),
+            (
+                r#"
+mod my_mod {
+    /// Tests something.
+    #[ink::test]
+    fn it_works() {
+        assert!(true);
+    }
+}"#,
+                "#[cfg(test)]\n    mod tests {\n        use super::*;\n\n        /// Tests something.\n        #[ink::test]\n        fn it_works() {\n            assert!(true);\n        }\n    }",
+                "<-/// Tests something.",
+                "assert!(true);\n    }",
+            ),
+            (
+                r#"
+#[cfg(feature = "std")]
+mod my_mod {
+    #[ink::test]
+    fn it_works() {
+    }
+}"#,
+                "#[cfg(test)]\n    mod tests {\n        use super::*;\n\n        #[ink::test]\n        fn it_works() {\n        }\n    }",
+                "<-#[ink::test]",
+                "fn it_works() {\n    }",
+            ),
+        ] {
+            let ink_test = parse_first_ink_test(code);
+
+            let result = ensure_cfg_test_parent(&ink_test);
+
+            // Verifies diagnostics.
+            assert!(result.is_some(), "ink test: {code}");
+            assert_eq!(
+                result.as_ref().unwrap().severity,
+                Severity::Warning,
+                "ink test: {code}"
+            );
+            // Verifies quickfixes.
+            let expected_quickfixes = vec![TestResultAction {
+                label: "Wrap ink! test",
+                edits: vec![TestResultTextRange {
+                    text: expected_text,
+                    start_pat: Some(start_pat),
+                    end_pat: Some(end_pat),
+                }],
+            }];
+            let quickfixes = result.as_ref().unwrap().quickfixes.as_ref().unwrap();
+            verify_actions(code, quickfixes, &expected_quickfixes);
+        }
+    }
+
     #[test]
     fn no_ink_descendants_works() {
         let ink_test = parse_first_ink_test(quote_as_str! {
@@ -152,7 +307,7 @@ mod tests {
                     edits: vec![TestResultTextRange {
                         text: "",
                         start_pat: Some("<-#[ink(event)]"),
-                        end_pat: Some("#[ink(event)]"),
+                        end_pat: Some("#[ink(event)]\n    "),
                     }],
                 },
                 TestResultAction {
@@ -169,7 +324,7 @@ mod tests {
                 edits: vec![TestResultTextRange {
                     text: "",
                     start_pat: Some("<-#[ink(topic)]"),
-                    end_pat: Some("#[ink(topic)]"),
+                    end_pat: Some("#[ink(topic)]\n        "),
                 }],
             }],
         ];
@@ -184,18 +339,24 @@ mod tests {
     fn compound_diagnostic_works() {
         for code in [
             quote_as_str! {
-                // Conventional unit test that works with assertions.
-                #[ink::test]
-                fn test1() {
-                   // test code comes here as usual
+                #[cfg(test)]
+                mod tests {
+                    // Conventional unit test that works with assertions.
+                    #[ink::test]
+                    fn test1() {
+                       // test code comes here as usual
+                    }
                 }
             },
             quote_as_str! {
-                // Conventional unit test that returns some Result.
-                // The test code can make use of operator-`?`.
-                #[ink::test]
-                fn test2() -> Result<(), ink_env::Error> {
-                    // test code that returns a Rust Result type
+                #[cfg(test)]
+                mod tests {
+                    // Conventional unit test that returns some Result.
+                    // The test code can make use of operator-`?`.
+                    #[ink::test]
+                    fn test2() -> Result<(), ink_env::Error> {
+                        // test code that returns a Rust Result type
+                    }
                 }
             },
         ] {
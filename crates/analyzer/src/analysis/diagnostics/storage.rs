@@ -1,9 +1,11 @@
 //! ink! storage diagnostics.
 
-use ink_analyzer_ir::Storage;
+use ink_analyzer_ir::ast::Type;
+use ink_analyzer_ir::syntax::{AstNode, SyntaxKind};
+use ink_analyzer_ir::{ast, IsInkStruct, Storage};
 
 use super::utils;
-use crate::Diagnostic;
+use crate::{Diagnostic, Severity};
 
 const STORAGE_SCOPE_NAME: &str = "storage";
 
@@ -33,6 +35,72 @@ pub fn diagnostics(results: &mut Vec<Diagnostic>, storage: &Storage) {
 
     // Ensures that ink! storage has no ink! descendants, see `utils::ensure_no_ink_descendants` doc.
     utils::ensure_no_ink_descendants(results, storage, STORAGE_SCOPE_NAME);
+
+    // Ensures that ink! storage field types can implement `StorageLayout` (best effort),
+    // see `ensure_storage_layout_compatible_field_types` doc.
+    ensure_storage_layout_compatible_field_types(results, storage);
+}
+
+/// Ensures that ink! storage fields don't use types that never implement `StorageLayout`
+/// (i.e. closure, function pointer and raw pointer types).
+///
+/// **NOTE**: This is a best effort check based on the syntax of the field types
+/// (i.e. type aliases aren't resolved), so all other types are assumed to be compatible.
+fn ensure_storage_layout_compatible_field_types(results: &mut Vec<Diagnostic>, storage: &Storage) {
+    let field_types: Vec<Type> = match storage.struct_item().and_then(ast::Struct::field_list) {
+        Some(ast::FieldList::RecordFieldList(field_list)) => {
+            field_list.fields().filter_map(|field| field.ty()).collect()
+        }
+        Some(ast::FieldList::TupleFieldList(field_list)) => {
+            field_list.fields().filter_map(|field| field.ty()).collect()
+        }
+        None => Vec::new(),
+    };
+    for field_type in field_types {
+        // Finds the first (i.e. outermost) incompatible type (if any) in the field type.
+        let incompatible_type = field_type
+            .syntax()
+            .descendants()
+            .filter_map(Type::cast)
+            .find_map(|ty| incompatible_storage_layout_type_desc(&ty).map(|desc| (ty, desc)));
+        if let Some((ty, desc)) = incompatible_type {
+            results.push(Diagnostic {
+                message: format!(
+                    "{desc} (e.g. `{ty}`) never implement `StorageLayout`, \
+                    so they can't be used in ink! storage fields."
+                ),
+                range: ty.syntax().text_range(),
+                severity: Severity::Warning,
+                quickfixes: None,
+            });
+        }
+    }
+}
+
+/// Returns a description of the type if it never implements `StorageLayout`.
+fn incompatible_storage_layout_type_desc(ty: &Type) -> Option<&'static str> {
+    match ty.syntax().kind() {
+        SyntaxKind::PTR_TYPE => Some("Raw pointer types"),
+        SyntaxKind::FN_PTR_TYPE => Some("Function pointer types"),
+        // Closure types are unnameable, so they can only be referenced via
+        // `Fn`, `FnMut` and `FnOnce` trait bounds (e.g. `impl Fn(u8) -> u8`).
+        SyntaxKind::IMPL_TRAIT_TYPE | SyntaxKind::DYN_TRAIT_TYPE => {
+            let bounds = match ty {
+                Type::ImplTraitType(it) => it.type_bound_list(),
+                Type::DynTraitType(it) => it.type_bound_list(),
+                _ => None,
+            }?;
+            bounds
+                .bounds()
+                .filter_map(|bound| match bound.ty()? {
+                    Type::PathType(path_type) => path_type.path()?.segment()?.name_ref(),
+                    _ => None,
+                })
+                .any(|name| matches!(name.text().as_str(), "Fn" | "FnMut" | "FnOnce"))
+                .then_some("Closure types")
+        }
+        _ => None,
+    }
 }
 
 #[cfg(test)]
@@ -284,7 +352,7 @@ mod tests {
             edits: vec![TestResultTextRange {
                 text: "",
                 start_pat: Some("<-#[ink(topic)]"),
-                end_pat: Some("#[ink(topic)]"),
+                end_pat: Some("#[ink(topic)]\n    "),
             }],
         }]];
         for (idx, item) in results.iter().enumerate() {
@@ -293,6 +361,62 @@ mod tests {
         }
     }
 
+    #[test]
+    fn storage_layout_compatible_field_types_works() {
+        for code in valid_storage!().into_iter().chain([
+            quote! {
+                #[ink(storage)]
+                pub struct MyContract {
+                    value: u32,
+                }
+            },
+            quote! {
+                #[ink(storage)]
+                pub struct MyContract(u32, Option<bool>);
+            },
+        ]) {
+            let storage = parse_first_storage_definition(quote_as_str! {
+                #code
+            });
+
+            let mut results = Vec::new();
+            ensure_storage_layout_compatible_field_types(&mut results, &storage);
+            assert!(results.is_empty(), "storage: {code}");
+        }
+    }
+
+    #[test]
+    fn storage_layout_incompatible_field_types_fails() {
+        for (field_type, expected_range_text) in [
+            // Closure types.
+            ("impl Fn(u8) -> u8", "impl Fn(u8) -> u8"),
+            ("Box<dyn FnMut()>", "dyn FnMut()"),
+            // Function pointer types.
+            ("fn(u8) -> u8", "fn(u8) -> u8"),
+            ("Option<fn()>", "fn()"),
+            // Raw pointer types.
+            ("*const u8", "*const u8"),
+            ("*mut u8", "*mut u8"),
+            ("Vec<*mut u8>", "*mut u8"),
+        ] {
+            let code = format!(
+                "#[ink(storage)]\npub struct MyContract {{\n    value: u32,\n    other: {field_type},\n}}"
+            );
+            let storage = parse_first_storage_definition(&code);
+
+            let mut results = Vec::new();
+            ensure_storage_layout_compatible_field_types(&mut results, &storage);
+
+            // Verifies diagnostics.
+            assert_eq!(results.len(), 1, "storage: {code}");
+            assert_eq!(results[0].severity, Severity::Warning, "storage: {code}");
+            assert_eq!(
+                &code[results[0].range], expected_range_text,
+                "storage: {code}"
+            );
+        }
+    }
+
     #[test]
     // Ref: <https://github.com/paritytech/ink/blob/v4.1.0/crates/ink/ir/src/ir/item/storage.rs#L130-L140>.
     fn compound_diagnostic_works() {
@@ -132,7 +132,7 @@ mod tests {
                         parse_offset_at(&code, Some("<-#[ink::storage_item]")).unwrap() as u32
                     ),
                     TextSize::from(
-                        parse_offset_at(&code, Some("#[ink::storage_item]")).unwrap() as u32
+                        parse_offset_at(&code, Some("#[ink::storage_item]\n")).unwrap() as u32
                     )
                 )
             );
@@ -184,7 +184,7 @@ mod tests {
                 edits: vec![TestResultTextRange {
                     text: "",
                     start_pat: Some("<-#[ink(event)]"),
-                    end_pat: Some("#[ink(event)]"),
+                    end_pat: Some("#[ink(event)]\n    "),
                 }],
             }],
             vec![TestResultAction {
@@ -192,7 +192,7 @@ mod tests {
                 edits: vec![TestResultTextRange {
                     text: "",
                     start_pat: Some("<-#[ink(topic)]"),
-                    end_pat: Some("#[ink(topic)]"),
+                    end_pat: Some("#[ink(topic)]\n    "),
                 }],
             }],
         ];
@@ -16,7 +16,7 @@ pub fn diagnostics(results: &mut Vec<Diagnostic>, topic: &Topic) {
     // Runs generic diagnostics, see `utils::run_generic_diagnostics` doc.
     utils::run_generic_diagnostics(results, topic);
 
-    // Ensures that ink! topic is a `struct` field, see `ensure_struct_field` doc.
+    // Ensures that ink! topic is a `struct` record field, see `ensure_struct_field` doc.
     if let Some(diagnostic) = ensure_struct_field(topic) {
         results.push(diagnostic);
     }
@@ -27,6 +27,9 @@ pub fn diagnostics(results: &mut Vec<Diagnostic>, topic: &Topic) {
 
 /// Ensures that ink! topic is a `struct` field.
 ///
+/// **NOTE**: Only record (i.e. named) fields are valid,
+/// so ink! topics on tuple `struct` fields, `fn`, `mod` and `struct` items are all invalid.
+///
 /// Ref: <https://github.com/paritytech/ink/blob/v4.1.0/crates/ink/ir/src/ir/item/event.rs#L106-L140>.
 fn ensure_struct_field(topic: &Topic) -> Option<Diagnostic> {
     let ink_attr = topic.ink_attr()?;
@@ -68,7 +71,7 @@ mod tests {
 
     #[test]
     fn non_struct_field_fails() {
-        for item in vec![
+        for item in [
             quote! { mod my_topic; },
             quote! {
                 pub struct MyTopic {
@@ -79,6 +82,9 @@ mod tests {
                 This,
                 That,
             } },
+            quote! {
+                fn my_topic() {}
+            },
         ] {
             let code = quote_as_pretty_string! {
                 #[ink(topic)]
@@ -99,12 +105,80 @@ mod tests {
                 fix.edits[0].range,
                 TextRange::new(
                     TextSize::from(parse_offset_at(&code, Some("<-#[ink(topic)]")).unwrap() as u32),
-                    TextSize::from(parse_offset_at(&code, Some("#[ink(topic)]")).unwrap() as u32)
+                    TextSize::from(parse_offset_at(&code, Some("#[ink(topic)]\n")).unwrap() as u32)
+                )
+            );
+        }
+    }
+
+    #[test]
+    fn assoc_item_fails() {
+        for item in [
+            // Method.
+            quote! {
+                #[ink(topic)]
+                pub fn my_topic(&self) -> bool {}
+            },
+            // Associated function.
+            quote! {
+                #[ink(topic)]
+                fn my_topic() {}
+            },
+            // Associated const.
+            quote! {
+                #[ink(topic)]
+                const MY_TOPIC: bool = true;
+            },
+        ] {
+            let code = quote_as_pretty_string! {
+                impl MyEvent {
+                    #item
+                }
+            };
+            let topic = parse_first_topic_field(&code);
+
+            let result = ensure_struct_field(&topic);
+
+            // Verifies diagnostics.
+            assert!(result.is_some(), "code: {code}");
+            assert_eq!(result.as_ref().unwrap().severity, Severity::Error);
+            // Verifies quickfixes.
+            let fix = &result.as_ref().unwrap().quickfixes.as_ref().unwrap()[0];
+            assert!(fix.label.contains("Remove `#[ink(topic)]`"));
+            assert!(fix.edits[0].text.is_empty());
+            assert_eq!(
+                fix.edits[0].range,
+                TextRange::new(
+                    TextSize::from(parse_offset_at(&code, Some("<-#[ink(topic)]")).unwrap() as u32),
+                    TextSize::from(
+                        parse_offset_at(&code, Some("#[ink(topic)]\n    ")).unwrap() as u32
+                    )
                 )
             );
         }
     }
 
+    #[test]
+    fn tuple_struct_field_fails() {
+        let code = quote_as_pretty_string! {
+            pub struct MyEvent(
+                #[ink(topic)]
+                bool,
+            );
+        };
+        let topic = parse_first_topic_field(&code);
+
+        let result = ensure_struct_field(&topic);
+
+        // Verifies diagnostics.
+        assert!(result.is_some());
+        assert_eq!(result.as_ref().unwrap().severity, Severity::Error);
+        // Verifies quickfixes.
+        let fix = &result.as_ref().unwrap().quickfixes.as_ref().unwrap()[0];
+        assert!(fix.label.contains("Remove `#[ink(topic)]`"));
+        assert!(fix.edits[0].text.is_empty());
+    }
+
     #[test]
     fn compound_diagnostic_works() {
         let topic = parse_first_topic_field(quote_as_str! {
@@ -51,6 +51,9 @@ pub fn diagnostics(results: &mut Vec<Diagnostic>, trait_definition: &TraitDefini
         results.push(diagnostic);
     }
 
+    // Ensures that no ink! constructors, see `ensure_no_constructors` doc.
+    ensure_no_constructors(results, trait_definition);
+
     // Ensures that only valid quasi-direct ink! attribute descendants (i.e ink! descendants without any ink! ancestors),
     // see `ensure_valid_quasi_direct_ink_descendants` doc.
     ensure_valid_quasi_direct_ink_descendants(results, trait_definition);
@@ -193,6 +196,38 @@ fn ensure_contains_message(trait_definition: &TraitDefinition) -> Option<Diagnos
     )
 }
 
+/// Ensures that no ink! constructors (i.e. trait methods annotated with `#[ink(constructor)]`).
+///
+/// Ref: <https://github.com/paritytech/ink/blob/v4.1.0/crates/ink/ir/src/ir/trait_def/tests.rs#L254-L270>.
+fn ensure_no_constructors(results: &mut Vec<Diagnostic>, trait_definition: &TraitDefinition) {
+    let Some(assoc_item_list) = trait_definition
+        .trait_item()
+        .and_then(ast::Trait::assoc_item_list)
+    else {
+        return;
+    };
+    for fn_item in assoc_item_list.assoc_items().filter_map(|item| match item {
+        ast::AssocItem::Fn(fn_item) => Some(fn_item),
+        _ => None,
+    }) {
+        for attr in ink_analyzer_ir::ink_attrs(fn_item.syntax())
+            .filter(|attr| *attr.kind() == InkAttributeKind::Arg(InkArgKind::Constructor))
+        {
+            results.push(Diagnostic {
+                message: "ink! constructors are not allowed in ink! trait definitions, \
+                only ink! messages are supported."
+                    .to_string(),
+                range: attr.syntax().text_range(),
+                severity: Severity::Error,
+                quickfixes: Some(vec![
+                    Action::remove_attribute(&attr),
+                    Action::remove_item(fn_item.syntax()),
+                ]),
+            });
+        }
+    }
+}
+
 /// Ensures that only valid quasi-direct ink! attribute descendants (i.e ink! descendants without any ink! ancestors).
 ///
 /// Ref: <https://github.com/paritytech/ink/blob/v4.1.0/crates/ink/ir/src/ir/trait_def/item/trait_item.rs#L85-L99>.
@@ -205,10 +240,12 @@ fn ensure_valid_quasi_direct_ink_descendants(
     trait_definition: &TraitDefinition,
 ) {
     utils::ensure_valid_quasi_direct_ink_descendants(results, trait_definition, |attr| {
+        // NOTE: ink! constructors are handled by `ensure_no_constructors`.
         matches!(
             attr.kind(),
             InkAttributeKind::Arg(
                 InkArgKind::Message
+                    | InkArgKind::Constructor
                     | InkArgKind::Payable
                     | InkArgKind::Default
                     | InkArgKind::Selector
@@ -506,6 +543,13 @@ mod tests {
 
             let mut results = Vec::new();
             ensure_trait_item_invariants(&mut results, trait_definition.trait_item().unwrap());
+            // Ignores warnings about payable ink! messages with a `&self` receiver
+            // (i.e. valid but "unusual" ink! messages).
+            results.retain(|diagnostic| {
+                !diagnostic
+                    .message
+                    .starts_with("A payable ink! message typically needs `&mut self`")
+            });
             assert!(results.is_empty(), "trait definition: {code}");
         }
     }
@@ -829,16 +873,16 @@ mod tests {
                         label: "Add",
                         edits: vec![TestResultTextRange {
                             text: "&self",
-                            start_pat: Some("no_self_ref_receiver("),
-                            end_pat: Some("no_self_ref_receiver("),
+                            start_pat: Some("<-self);"),
+                            end_pat: Some("(self"),
                         }],
                     },
                     TestResultAction {
                         label: "Add",
                         edits: vec![TestResultTextRange {
                             text: "&mut self",
-                            start_pat: Some("no_self_ref_receiver("),
-                            end_pat: Some("no_self_ref_receiver("),
+                            start_pat: Some("<-self);"),
+                            end_pat: Some("(self"),
                         }],
                     },
                 ],
@@ -999,61 +1043,134 @@ mod tests {
 
         let mut results = Vec::new();
         ensure_valid_quasi_direct_ink_descendants(&mut results, &trait_definition);
-        // 1 diagnostic each for `constructor` and `event`.
-        assert_eq!(results.len(), 2);
+        // 1 diagnostic for `event`
+        // (`constructor` is handled by `ensure_no_constructors`, see `constructor_fails` test).
+        assert_eq!(results.len(), 1);
         // All diagnostics should be errors.
         assert_eq!(
             results
                 .iter()
                 .filter(|item| item.severity == Severity::Error)
                 .count(),
-            2
+            1
         );
         // Verifies quickfixes.
-        let expected_quickfixes = vec![
-            vec![
-                TestResultAction {
-                    label: "Remove `#[ink(constructor)]`",
-                    edits: vec![TestResultTextRange {
-                        text: "",
-                        start_pat: Some("<-#[ink(constructor)]"),
-                        end_pat: Some("#[ink(constructor)]"),
-                    }],
-                },
-                TestResultAction {
-                    label: "Remove item",
-                    edits: vec![TestResultTextRange {
-                        text: "",
-                        start_pat: Some("<-#[ink(constructor)]"),
-                        end_pat: Some("fn my_constructor() -> Self;"),
-                    }],
-                },
-            ],
-            vec![
-                TestResultAction {
-                    label: "Remove `#[ink(event)]`",
-                    edits: vec![TestResultTextRange {
-                        text: "",
-                        start_pat: Some("<-#[ink(event)]"),
-                        end_pat: Some("#[ink(event)]"),
-                    }],
-                },
-                TestResultAction {
-                    label: "Remove item",
-                    edits: vec![TestResultTextRange {
-                        text: "",
-                        start_pat: Some("<-#[ink(event)]"),
-                        end_pat: Some("fn unsupported_method(&self);"),
-                    }],
-                },
-            ],
-        ];
+        let expected_quickfixes = vec![vec![
+            TestResultAction {
+                label: "Remove `#[ink(event)]`",
+                edits: vec![TestResultTextRange {
+                    text: "",
+                    start_pat: Some("<-#[ink(event)]"),
+                    end_pat: Some("#[ink(event)]\n    "),
+                }],
+            },
+            TestResultAction {
+                label: "Remove item",
+                edits: vec![TestResultTextRange {
+                    text: "",
+                    start_pat: Some("<-#[ink(event)]"),
+                    end_pat: Some("fn unsupported_method(&self);"),
+                }],
+            },
+        ]];
         for (idx, item) in results.iter().enumerate() {
             let quickfixes = item.quickfixes.as_ref().unwrap();
             verify_actions(&code, quickfixes, &expected_quickfixes[idx]);
         }
     }
 
+    #[test]
+    fn no_constructors_works() {
+        for code in [
+            // Only ink! messages.
+            quote! {
+                #[ink(message)]
+                fn my_message(&self);
+
+                #[ink(message, payable)]
+                fn my_message_mut(&mut self);
+            },
+            // Non-annotated method.
+            quote! {
+                #[ink(message)]
+                fn my_message(&self);
+
+                fn non_flagged(&self);
+            },
+        ] {
+            let code = quote_as_pretty_string! {
+                #[ink::trait_definition]
+                pub trait MyTrait {
+                    #code
+                }
+            };
+            let trait_definition = parse_first_trait_definition(&code);
+
+            let mut results = Vec::new();
+            ensure_no_constructors(&mut results, &trait_definition);
+            assert!(results.is_empty(), "trait: {code}");
+        }
+    }
+
+    #[test]
+    fn constructor_fails() {
+        for code in [
+            quote! {
+                #[ink(constructor)]
+                fn my_constructor() -> Self;
+            },
+            quote! {
+                #[ink(constructor, payable)]
+                fn my_constructor() -> Self;
+            },
+        ] {
+            let code = quote_as_pretty_string! {
+                #[ink::trait_definition]
+                pub trait MyTrait {
+                    #[ink(message)]
+                    fn my_message(&self);
+
+                    #code
+                }
+            };
+            let trait_definition = parse_first_trait_definition(&code);
+
+            let mut results = Vec::new();
+            ensure_no_constructors(&mut results, &trait_definition);
+
+            // Verifies diagnostics.
+            assert_eq!(results.len(), 1, "trait: {code}");
+            assert_eq!(results[0].severity, Severity::Error, "trait: {code}");
+            assert!(
+                code[results[0].range].starts_with("#[ink(constructor"),
+                "trait: {code}"
+            );
+            // Verifies quickfixes.
+            verify_actions(
+                &code,
+                results[0].quickfixes.as_ref().unwrap(),
+                &[
+                    TestResultAction {
+                        label: "Remove `#[ink(constructor",
+                        edits: vec![TestResultTextRange {
+                            text: "",
+                            start_pat: Some("<-#[ink(constructor"),
+                            end_pat: Some("<-fn my_constructor"),
+                        }],
+                    },
+                    TestResultAction {
+                        label: "Remove item",
+                        edits: vec![TestResultTextRange {
+                            text: "",
+                            start_pat: Some("<-#[ink(constructor"),
+                            end_pat: Some("fn my_constructor() -> Self;"),
+                        }],
+                    },
+                ],
+            );
+        }
+    }
+
     #[test]
     fn compound_diagnostic_works() {
         for code in valid_traits!() {
@@ -1063,6 +1180,13 @@ mod tests {
 
             let mut results = Vec::new();
             diagnostics(&mut results, &trait_definition);
+            // Ignores warnings about payable ink! messages with a `&self` receiver
+            // (i.e. valid but "unusual" ink! messages).
+            results.retain(|diagnostic| {
+                !diagnostic
+                    .message
+                    .starts_with("A payable ink! message typically needs `&mut self`")
+            });
             assert!(results.is_empty(), "trait definition: {code}");
         }
     }
@@ -9,7 +9,8 @@ use ink_analyzer_ir::syntax::{
 };
 use ink_analyzer_ir::{
     ast, Contract, HasInkImplParent, InkArg, InkArgKind, InkArgValueKind, InkArgValueStringKind,
-    InkAttribute, InkAttributeKind, InkEntity, InkMacroKind, IsInkFn, IsInkStruct, IsInkTrait,
+    InkAttribute, InkAttributeKind, InkEntity, InkMacroKind, IsInkCallable, IsInkFn, IsInkStruct,
+    IsInkTrait, Selector,
 };
 use itertools::Itertools;
 use std::collections::HashSet;
@@ -144,211 +145,202 @@ fn ensure_no_unknown_ink_attributes(results: &mut Vec<Diagnostic>, attrs: &[InkA
 fn ensure_valid_attribute_arguments(results: &mut Vec<Diagnostic>, attr: &InkAttribute) {
     for arg in attr.args() {
         let arg_name_text = arg.meta().name().to_string();
-        match arg.kind() {
-            // Handle unknown argument.
-            InkArgKind::Unknown => {
-                // Edit range for quickfix.
-                let range = utils::ink_arg_and_delimiter_removal_range(arg, Some(attr));
-                results.push(Diagnostic {
-                    message: if arg_name_text.is_empty() {
-                        "Missing ink! attribute argument.".to_string()
-                    } else {
-                        format!("Unknown ink! attribute argument: '{arg_name_text}'.")
-                    },
-                    range: arg.text_range(),
-                    severity: if arg_name_text.is_empty() {
-                        // error for missing.
-                        Severity::Error
-                    } else {
-                        // warning because it's possible ink! analyzer is just outdated.
-                        Severity::Warning
+        // Handle unknown argument.
+        if arg.is_unknown() {
+            // Edit range for quickfix.
+            let range = utils::ink_arg_and_delimiter_removal_range(arg, Some(attr));
+            results.push(Diagnostic {
+                message: if arg_name_text.is_empty() {
+                    "Missing ink! attribute argument.".to_string()
+                } else {
+                    format!("Unknown ink! attribute argument: '{arg_name_text}'.")
+                },
+                range: arg.text_range(),
+                severity: if arg_name_text.is_empty() {
+                    // error for missing.
+                    Severity::Error
+                } else {
+                    // warning because it's possible ink! analyzer is just outdated.
+                    Severity::Warning
+                },
+                quickfixes: Some(vec![Action {
+                    label: format!("Remove unknown ink! attribute argument: '{arg_name_text}'."),
+                    kind: ActionKind::QuickFix,
+                    range,
+                    edits: vec![TextEdit::delete(range)],
+                }]),
+            });
+            continue;
+        }
+
+        let arg_value_type = InkArgValueKind::from(*arg.kind());
+        match arg_value_type {
+            // Arguments that must have no value.
+            InkArgValueKind::None => {
+                if arg.meta().eq().is_some() || arg.meta().value().is_some() {
+                    results.push(Diagnostic {
+                        message: format!("`{arg_name_text}` argument shouldn't have a value."),
+                        range: arg.text_range(),
+                        severity: Severity::Error,
+                        quickfixes: Some(vec![Action {
+                            label: format!("Remove `{arg_name_text}` argument value"),
+                            kind: ActionKind::QuickFix,
+                            range: arg.text_range(),
+                            edits: vec![TextEdit::replace(arg_name_text, arg.text_range())],
+                        }]),
+                    });
+                }
+            }
+            // Arguments that should have an integer (`u32` to be specific) value.
+            InkArgValueKind::U32 | InkArgValueKind::U32OrWildcard => {
+                let can_be_wildcard = arg_value_type == InkArgValueKind::U32OrWildcard;
+                if !ensure_valid_attribute_arg_value(
+                    arg,
+                    |meta_value| {
+                        // Ensures that the meta value is either a decimal or hex encoded `u32`.
+                        // Ref: <https://github.com/paritytech/ink/blob/v4.1.0/crates/ink/ir/src/ir/attrs.rs#L903-L910>.
+                        // Ref: <https://github.com/paritytech/ink/blob/v4.1.0/crates/ink/ir/src/ir/attrs.rs#L938-L943>.
+                        meta_value.as_u32().is_some()
+                                // A wildcard/underscore (`_`) is also valid value for selectors.
+                                // Ref: <https://github.com/paritytech/ink/blob/v4.1.0/crates/ink/ir/src/ir/attrs.rs#L884-L900>.
+                                || (can_be_wildcard
+                                && meta_value.is_wildcard())
                     },
-                    quickfixes: Some(vec![Action {
-                        label: format!(
-                            "Remove unknown ink! attribute argument: '{arg_name_text}'."
+                    |_| false,
+                    false,
+                ) {
+                    results.push(Diagnostic {
+                        message: format!(
+                            "`{arg_name_text}` argument should have an `integer` (`u32`) {} value.",
+                            if can_be_wildcard {
+                                "or wildcard/underscore (`_`)"
+                            } else {
+                                ""
+                            }
                         ),
-                        kind: ActionKind::QuickFix,
-                        range,
-                        edits: vec![TextEdit::delete(range)],
-                    }]),
-                });
+                        range: arg.text_range(),
+                        severity: Severity::Error,
+                        quickfixes: Some(vec![Action {
+                            label: format!("Add `{arg_name_text}` argument value"),
+                            kind: ActionKind::QuickFix,
+                            range: arg.text_range(),
+                            edits: vec![TextEdit::replace_with_snippet(
+                                format!("{arg_name_text} = 1"),
+                                arg.text_range(),
+                                Some(format!("{arg_name_text} = ${{1:1}}")),
+                            )],
+                        }]),
+                    });
+                }
             }
-            arg_kind => {
-                let arg_value_type = InkArgValueKind::from(*arg_kind);
-                match arg_value_type {
-                    // Arguments that must have no value.
-                    InkArgValueKind::None => {
-                        if arg.meta().eq().is_some() || arg.meta().value().is_some() {
-                            results.push(Diagnostic {
-                                message: format!(
-                                    "`{arg_name_text}` argument shouldn't have a value."
-                                ),
-                                range: arg.text_range(),
-                                severity: Severity::Error,
-                                quickfixes: Some(vec![Action {
-                                    label: format!("Remove `{arg_name_text}` argument value"),
-                                    kind: ActionKind::QuickFix,
-                                    range: arg.text_range(),
-                                    edits: vec![TextEdit::replace(arg_name_text, arg.text_range())],
-                                }]),
-                            });
-                        }
-                    }
-                    // Arguments that should have an integer (`u32` to be specific) value.
-                    InkArgValueKind::U32 | InkArgValueKind::U32OrWildcard => {
-                        let can_be_wildcard = arg_value_type == InkArgValueKind::U32OrWildcard;
-                        if !ensure_valid_attribute_arg_value(
-                            arg,
-                            |meta_value| {
-                                // Ensures that the meta value is either a decimal or hex encoded `u32`.
-                                // Ref: <https://github.com/paritytech/ink/blob/v4.1.0/crates/ink/ir/src/ir/attrs.rs#L903-L910>.
-                                // Ref: <https://github.com/paritytech/ink/blob/v4.1.0/crates/ink/ir/src/ir/attrs.rs#L938-L943>.
-                                meta_value.as_u32().is_some()
-                                        // A wildcard/underscore (`_`) is also valid value for selectors.
-                                        // Ref: <https://github.com/paritytech/ink/blob/v4.1.0/crates/ink/ir/src/ir/attrs.rs#L884-L900>.
-                                        || (can_be_wildcard
-                                        && meta_value.is_wildcard())
-                            },
-                            |_| false,
-                            false,
-                        ) {
-                            results.push(Diagnostic {
-                                message: format!(
-                                    "`{arg_name_text}` argument should have an `integer` (`u32`) {} value.",
-                                    if can_be_wildcard {
-                                        "or wildcard/underscore (`_`)"
+            // Arguments that should have a string value.
+            InkArgValueKind::String(str_kind) => {
+                if !ensure_valid_attribute_arg_value(
+                    arg,
+                    |meta_value| {
+                        meta_value.as_string().is_some()
+                            // For namespace arguments, ensure the meta value is a valid Rust identifier.
+                            // Ref: <https://github.com/paritytech/ink/blob/v4.1.0/crates/ink/ir/src/ir/attrs.rs#L922-L926>.
+                            && (str_kind != InkArgValueStringKind::Identifier || meta_value.as_string().and_then(|value| parse_ident(value.as_str())).is_some())
+                    },
+                    |_| false,
+                    false,
+                ) {
+                    results.push(Diagnostic {
+                        message: format!(
+                            "`{arg_name_text}` argument should have a {} `string` (`&str`) value.",
+                            if *arg.kind() == InkArgKind::KeepAttr {
+                                "comma separated"
+                            } else {
+                                ""
+                            }
+                        ),
+                        range: arg.text_range(),
+                        severity: Severity::Error,
+                        quickfixes: Some(vec![Action {
+                            label: format!("Add `{arg_name_text}` argument value"),
+                            kind: ActionKind::QuickFix,
+                            range: arg.text_range(),
+                            edits: vec![TextEdit::replace_with_snippet(
+                                format!(
+                                    r#"{arg_name_text} = "{}""#,
+                                    if str_kind == InkArgValueStringKind::Identifier {
+                                        "my_namespace"
                                     } else {
                                         ""
                                     }
                                 ),
-                                range: arg.text_range(),
-                                severity: Severity::Error,
-                                quickfixes: Some(vec![Action {
-                                    label: format!("Add `{arg_name_text}` argument value"),
-                                    kind: ActionKind::QuickFix,
-                                    range: arg.text_range(),
-                                    edits: vec![TextEdit::replace_with_snippet(
-                                        format!("{arg_name_text} = 1"),
-                                        arg.text_range(),
-                                        Some(format!("{arg_name_text} = ${{1:1}}")),
-                                    )],
-                                }]),
-                            });
-                        }
-                    }
-                    // Arguments that should have a string value.
-                    InkArgValueKind::String(str_kind) => {
-                        if !ensure_valid_attribute_arg_value(
-                            arg,
-                            |meta_value| {
-                                meta_value.as_string().is_some()
-                                    // For namespace arguments, ensure the meta value is a valid Rust identifier.
-                                    // Ref: <https://github.com/paritytech/ink/blob/v4.1.0/crates/ink/ir/src/ir/attrs.rs#L922-L926>.
-                                    && (str_kind != InkArgValueStringKind::Identifier || meta_value.as_string().and_then(|value| parse_ident(value.as_str())).is_some())
-                            },
-                            |_| false,
-                            false,
-                        ) {
-                            results.push(Diagnostic {
-                                message: format!(
-                                    "`{arg_name_text}` argument should have a {} `string` (`&str`) value.",
-                                    if *arg.kind() == InkArgKind::KeepAttr {
-                                        "comma separated"
+                                arg.text_range(),
+                                Some(format!(
+                                    r#"{arg_name_text} = "{}""#,
+                                    if str_kind == InkArgValueStringKind::Identifier {
+                                        "${1:my_namespace}"
                                     } else {
-                                        ""
+                                        "$1"
                                     }
-                                ),
-                                range: arg.text_range(),
-                                severity: Severity::Error,
-                                quickfixes: Some(vec![Action {
-                                    label: format!("Add `{arg_name_text}` argument value"),
-                                    kind: ActionKind::QuickFix,
-                                    range: arg.text_range(),
-                                    edits: vec![TextEdit::replace_with_snippet(
-                                        format!(
-                                            r#"{arg_name_text} = "{}""#,
-                                            if str_kind == InkArgValueStringKind::Identifier {
-                                                "my_namespace"
-                                            } else {
-                                                ""
-                                            }
-                                        ),
-                                        arg.text_range(),
-                                        Some(format!(
-                                            r#"{arg_name_text} = "{}""#,
-                                            if str_kind == InkArgValueStringKind::Identifier {
-                                                "${1:my_namespace}"
-                                            } else {
-                                                "$1"
-                                            }
-                                        )),
-                                    )],
-                                }]),
-                            });
-                        }
-                    }
-                    // Arguments that should have a boolean value.
-                    InkArgValueKind::Bool => {
-                        if !ensure_valid_attribute_arg_value(
-                            arg,
-                            |meta_value| meta_value.as_boolean().is_some(),
-                            |_| false,
-                            false,
-                        ) {
-                            results.push(Diagnostic {
-                                message: format!(
-                                    "`{arg_name_text}` argument should have a `boolean` (`bool`) value."
-                                ),
-                                range: arg.text_range(),
-                                severity: Severity::Error,
-                                quickfixes: Some(vec![Action {
-                                    label: format!("Add `{arg_name_text}` argument value"),
-                                    kind: ActionKind::QuickFix,
-                                    range: arg.text_range(),
-                                    edits: vec![TextEdit::replace_with_snippet(
-                                        format!("{arg_name_text} = true"),
-                                        arg.text_range(),
-                                        Some(format!("{arg_name_text} = ${{1:true}}")),
-                                    )],
-                                }]),
-                            });
-                        }
-                    }
-                    // Arguments that should have a path value.
-                    InkArgValueKind::Path(_) => {
-                        if !ensure_valid_attribute_arg_value(
-                            arg,
-                            |meta_value| {
-                                matches!(
-                                    meta_value.kind(),
-                                    SyntaxKind::PATH | SyntaxKind::PATH_EXPR
-                                )
-                            },
-                            |_| false,
-                            false,
-                        ) {
-                            results.push(Diagnostic {
-                                message: format!(
-                                    "`{arg_name_text}` argument should have a `path` (e.g `my::env::Types`) value."
-                                ),
-                                range: arg.text_range(),
-                                severity: Severity::Error,
-                                quickfixes: Some(vec![Action {
-                                    label: format!("Add `{arg_name_text}` argument value"),
-                                    kind: ActionKind::QuickFix,
-                                    range: arg.text_range(),
-                                    edits: vec![TextEdit::replace_with_snippet(
-                                        format!("{arg_name_text} = crate::"),
-                                        arg.text_range(),
-                                        Some(format!("{arg_name_text} = ${{1:crate::}}")),
-                                    )],
-                                }]),
-                            });
-                        }
-                    }
+                                )),
+                            )],
+                        }]),
+                    });
                 }
             }
-        };
+            // Arguments that should have a boolean value.
+            InkArgValueKind::Bool => {
+                if !ensure_valid_attribute_arg_value(
+                    arg,
+                    |meta_value| meta_value.as_boolean().is_some(),
+                    |_| false,
+                    false,
+                ) {
+                    results.push(Diagnostic {
+                        message: format!(
+                            "`{arg_name_text}` argument should have a `boolean` (`bool`) value."
+                        ),
+                        range: arg.text_range(),
+                        severity: Severity::Error,
+                        quickfixes: Some(vec![Action {
+                            label: format!("Add `{arg_name_text}` argument value"),
+                            kind: ActionKind::QuickFix,
+                            range: arg.text_range(),
+                            edits: vec![TextEdit::replace_with_snippet(
+                                format!("{arg_name_text} = true"),
+                                arg.text_range(),
+                                Some(format!("{arg_name_text} = ${{1:true}}")),
+                            )],
+                        }]),
+                    });
+                }
+            }
+            // Arguments that should have a path value.
+            InkArgValueKind::Path(_) => {
+                if !ensure_valid_attribute_arg_value(
+                    arg,
+                    |meta_value| {
+                        matches!(meta_value.kind(), SyntaxKind::PATH | SyntaxKind::PATH_EXPR)
+                    },
+                    |_| false,
+                    false,
+                ) {
+                    results.push(Diagnostic {
+                        message: format!(
+                            "`{arg_name_text}` argument should have a `path` (e.g `my::env::Types`) value."
+                        ),
+                        range: arg.text_range(),
+                        severity: Severity::Error,
+                        quickfixes: Some(vec![Action {
+                            label: format!("Add `{arg_name_text}` argument value"),
+                            kind: ActionKind::QuickFix,
+                            range: arg.text_range(),
+                            edits: vec![TextEdit::replace_with_snippet(
+                                format!("{arg_name_text} = crate::"),
+                                arg.text_range(),
+                                Some(format!("{arg_name_text} = ${{1:crate::}}")),
+                            )],
+                        }]),
+                    });
+                }
+            }
+        }
     }
 }
 
@@ -412,7 +404,7 @@ fn ensure_no_duplicate_attributes_and_arguments(
         for arg in attr.args() {
             let arg_kind = arg.kind();
             // Unknown ink! attribute arguments are ignored.
-            if *arg_kind != InkArgKind::Unknown && seen_args.get(arg_kind).is_some() {
+            if !arg.is_unknown() && seen_args.get(arg_kind).is_some() {
                 // Edit range for quickfix.
                 let range = utils::ink_arg_and_delimiter_removal_range(arg, Some(attr));
                 results.push(Diagnostic {
@@ -606,7 +598,7 @@ fn ensure_no_conflicting_attributes_and_arguments(
                         }
                         InkAttributeKind::Macro(macro_kind) => (
                             format!("#[{}]", macro_kind.path_as_str()),
-                            format!("ink! `{macro_kind}`"),
+                            format!("`{attr_kind}`"),
                             None,
                         ),
                     };
@@ -670,7 +662,7 @@ fn ensure_no_conflicting_attributes_and_arguments(
                                     // Adds the suggested ink! attribute macro to the existing ink! attribute arguments.
                                     InkAttributeKind::Arg(_) => Some(Action {
                                         label: format!(
-                                            "Add an ink! {macro_kind} macro to the `{}` attribute.",
+                                            "Add an `{macro_kind}` macro to the `{}` attribute.",
                                             primary_ink_attr_candidate.syntax()
                                         ),
                                         kind: ActionKind::QuickFix,
@@ -702,16 +694,7 @@ fn ensure_no_conflicting_attributes_and_arguments(
                     "An {} attribute should be the first ink! attribute for this item.",
                     primary_attribute_kind_suggestions
                         .iter()
-                        .map(|attr_kind| {
-                            match attr_kind {
-                                InkAttributeKind::Arg(arg_kind) => {
-                                    format!("`ink! {arg_kind}`")
-                                }
-                                InkAttributeKind::Macro(macro_kind) => {
-                                    format!("`ink! {macro_kind}`")
-                                }
-                            }
-                        })
+                        .map(|attr_kind| format!("`{attr_kind}`"))
                         .collect::<Vec<String>>()
                         .join(" or "), // It's never more than 2 suggestions at the moment.
                 ),
@@ -1001,8 +984,19 @@ where
 /// Ensures that an `fn` item has no self receiver (i.e no `&self`, `&mut self`, self or mut self).
 pub fn ensure_no_self_receiver(fn_item: &ast::Fn, ink_scope_name: &str) -> Option<Diagnostic> {
     fn_item.param_list()?.self_param().map(|self_param| {
-        // Edit range for quickfix.
-        let range = utils::node_and_delimiter_range(self_param.syntax(), SyntaxKind::COMMA);
+        // Edit range for quickfix (includes whitespace after the next delimiter, if any).
+        let mut range = utils::node_and_delimiter_range(self_param.syntax(), SyntaxKind::COMMA);
+        if range.end() > self_param.syntax().text_range().end() {
+            if let Some(token) = self_param
+                .syntax()
+                .last_token()
+                .and_then(|token| token.next_token())
+                .and_then(|token| token.next_token())
+                .filter(|token| token.kind() == SyntaxKind::WHITESPACE)
+            {
+                range = TextRange::new(range.start(), token.text_range().end());
+            }
+        }
         Diagnostic {
             message: format!("ink! {ink_scope_name} must not have a self receiver (i.e no `&self`, `&mut self`, self or mut self)."),
             range: self_param.syntax().text_range(),
@@ -1426,8 +1420,7 @@ where
         range: item.syntax().text_range(),
         severity: Severity::Error,
         // Moves the item to the root of the closest ink! contract's `mod` item.
-        quickfixes: ink_analyzer_ir::ink_ancestors::<Contract>(item.syntax())
-            .next()
+        quickfixes: ink_analyzer_ir::ink_ancestor_of_kind::<Contract>(item.syntax())
             .as_ref()
             .and_then(Contract::module)
             .and_then(|mod_item| Some(mod_item).zip(mod_item.item_list()))
@@ -1473,9 +1466,8 @@ where
                 Some(utils::item_children_indenting(impl_item.syntax()).as_str()),
             )]
         })
-        .or(ink_analyzer_ir::ink_ancestors::<Contract>(item.syntax())
-            .next()
-            .and_then(|contract| {
+        .or(
+            ink_analyzer_ir::ink_ancestor_of_kind::<Contract>(item.syntax()).and_then(|contract| {
                 // Moves the item to the first non-trait `impl` block or creates a new `impl` block if necessary for the parent ink! contract (if any).
                 utils::callable_insert_offset_indent_and_affixes(&contract).map(
                     |(insert_offset, indent, prefix, suffix)| {
@@ -1489,7 +1481,63 @@ where
                         )]
                     },
                 )
-            })),
+            }),
+        ),
+    })
+}
+
+/// Ensures that an explicit ink! callable selector value is not one of the reserved values
+/// (i.e `0` or `u32::MAX` - `0xFFFFFFFF`).
+pub fn ensure_no_reserved_selector<T>(callable: &T, ink_scope_name: &str) -> Option<Diagnostic>
+where
+    T: IsInkCallable + HasInkImplParent,
+{
+    let selector_arg = callable.selector_arg()?;
+    let selector_value = selector_arg.as_u32()?;
+    if selector_value != 0 && selector_value != u32::MAX {
+        return None;
+    }
+
+    let value = selector_arg.arg().value()?;
+    let range = value.text_range();
+    // Collects the selectors of all sibling ink! callables of the same kind (if any).
+    let mut unavailable_ids: HashSet<u32> =
+        ink_analyzer_ir::ink_ancestor_of_kind::<Contract>(callable.syntax())
+            .map(|contract| {
+                ink_analyzer_ir::ink_callable_closest_descendants::<T>(contract.syntax())
+                    .filter_map(|it| it.composed_selector().map(Selector::into_be_u32))
+                    .collect()
+            })
+            .unwrap_or_default();
+    unavailable_ids.extend([0, u32::MAX]);
+    // Suggests the closest unused selector (i.e counting up from `0` or down from `u32::MAX`).
+    let suggested_id = if selector_value == 0 {
+        (1..u32::MAX).find(|id| !unavailable_ids.contains(id))
+    } else {
+        (1..u32::MAX).rev().find(|id| !unavailable_ids.contains(id))
+    }?;
+    let suggested_value = if value.to_string().starts_with("0x") {
+        ink_analyzer_ir::format_selector(suggested_id)
+    } else {
+        format!("{suggested_id}")
+    };
+
+    Some(Diagnostic {
+        message: format!(
+            "ink! {ink_scope_name} selector value `{value}` is reserved and can't be used."
+        ),
+        range,
+        severity: Severity::Error,
+        quickfixes: Some(vec![Action {
+            label: format!("Replace with an unused selector (`{suggested_value}`)."),
+            kind: ActionKind::QuickFix,
+            range,
+            edits: vec![TextEdit::replace_with_snippet(
+                suggested_value.clone(),
+                range,
+                Some(format!("${{1:{suggested_value}}}")),
+            )],
+        }]),
     })
 }
 
@@ -2163,7 +2211,7 @@ mod tests {
                     end_pat,
                 }],
             }];
-            verify_actions(&code, quickfixes, &expected_quickfixes);
+            verify_actions(code, quickfixes, &expected_quickfixes);
         }
     }
 
@@ -2412,6 +2460,39 @@ mod tests {
                     }],
                 }],
             ),
+            (
+                r#"#[ink(namespace="my::namespace")]"#,
+                vec![TestResultAction {
+                    label: "argument value",
+                    edits: vec![TestResultTextRange {
+                        text: r#"namespace = "my_namespace""#,
+                        start_pat: Some(r#"<-namespace="my::namespace""#),
+                        end_pat: Some(r#"namespace="my::namespace""#),
+                    }],
+                }],
+            ),
+            (
+                r#"#[ink(namespace="my namespace")]"#,
+                vec![TestResultAction {
+                    label: "argument value",
+                    edits: vec![TestResultTextRange {
+                        text: r#"namespace = "my_namespace""#,
+                        start_pat: Some(r#"<-namespace="my namespace""#),
+                        end_pat: Some(r#"namespace="my namespace""#),
+                    }],
+                }],
+            ),
+            (
+                r#"#[ink(namespace="")]"#,
+                vec![TestResultAction {
+                    label: "argument value",
+                    edits: vec![TestResultTextRange {
+                        text: r#"namespace = "my_namespace""#,
+                        start_pat: Some(r#"<-namespace="""#),
+                        end_pat: Some(r#"namespace="""#),
+                    }],
+                }],
+            ),
             // Arguments that should have a boolean value.
             (
                 "#[ink(handle_status=1)]",
@@ -2593,7 +2674,7 @@ mod tests {
                     edits: vec![TestResultTextRange {
                         text: "",
                         start_pat: Some(r#"<-#[ink::contract(keep_attr="foo,bar")]"#),
-                        end_pat: Some(r#"#[ink::contract(keep_attr="foo,bar")]"#),
+                        end_pat: Some("#[ink::contract(keep_attr=\"foo,bar\")]\n                "),
                     }],
                 }],
             ),
@@ -2607,7 +2688,7 @@ mod tests {
                     edits: vec![TestResultTextRange {
                         text: "",
                         start_pat: Some(r#"<-#[ink::contract(keep_attr="foo,bar")]"#),
-                        end_pat: Some(r#"#[ink::contract(keep_attr="foo,bar")]"#),
+                        end_pat: Some("#[ink::contract(keep_attr=\"foo,bar\")]\n                "),
                     }],
                 }],
             ),
@@ -2810,7 +2891,7 @@ mod tests {
                     edits: vec![TestResultTextRange {
                         text: "",
                         start_pat: Some(r#"<-#[ink::trait_definition(namespace="my_namespace")]"#),
-                        end_pat: Some(r#"#[ink::trait_definition(namespace="my_namespace")]"#),
+                        end_pat: Some("#[ink::trait_definition(namespace=\"my_namespace\")]\n                "),
                     }],
                 }],
             ),
@@ -2824,7 +2905,7 @@ mod tests {
                     edits: vec![TestResultTextRange {
                         text: "",
                         start_pat: Some("<-#[ink_e2e::test(environment=my::env::Types)]"),
-                        end_pat: Some("#[ink_e2e::test(environment=my::env::Types)]"),
+                        end_pat: Some("#[ink_e2e::test(environment=my::env::Types)]\n                "),
                     }],
                 }],
             ),
@@ -2838,7 +2919,7 @@ mod tests {
                     edits: vec![TestResultTextRange {
                         text: "",
                         start_pat: Some("<-#[ink(message)]"),
-                        end_pat: Some("#[ink(message)]"),
+                        end_pat: Some("#[ink(message)]\n                "),
                     }],
                 }],
             ),
@@ -2852,7 +2933,7 @@ mod tests {
                     edits: vec![TestResultTextRange {
                         text: "",
                         start_pat: Some("<-#[ink(event)]"),
-                        end_pat: Some("#[ink(event)]"),
+                        end_pat: Some("#[ink(event)]\n                "),
                     }],
                 }],
             ),
@@ -3000,7 +3081,7 @@ mod tests {
                     edits: vec![TestResultTextRange {
                         text: "",
                         start_pat: Some("<-#[ink(env=my::env::Types)]"),
-                        end_pat: Some("#[ink(env=my::env::Types)]"),
+                        end_pat: Some("#[ink(env=my::env::Types)]\n                "),
                     }],
                 }],
             ),
@@ -3014,7 +3095,7 @@ mod tests {
                     edits: vec![TestResultTextRange {
                         text: "",
                         start_pat: Some(r#"<-#[ink(keep_attr="foo,bar")]"#),
-                        end_pat: Some(r#"#[ink(keep_attr="foo,bar")]"#),
+                        end_pat: Some("#[ink(keep_attr=\"foo,bar\")]\n                "),
                     }],
                 }],
             ),
@@ -3028,7 +3109,7 @@ mod tests {
                     edits: vec![TestResultTextRange {
                         text: "",
                         start_pat: Some("<-#[ink(derive=false)]"),
-                        end_pat: Some("#[ink(derive=false)]"),
+                        end_pat: Some("#[ink(derive=false)]\n                "),
                     }],
                 }],
             ),
@@ -3042,7 +3123,7 @@ mod tests {
                     edits: vec![TestResultTextRange {
                         text: "",
                         start_pat: Some("<-#[ink(environment=my::env::Types)]"),
-                        end_pat: Some("#[ink(environment=my::env::Types)]"),
+                        end_pat: Some("#[ink(environment=my::env::Types)]\n                "),
                     }],
                 }],
             ),
@@ -3167,7 +3248,7 @@ mod tests {
                     edits: vec![TestResultTextRange {
                         text: "",
                         start_pat: Some(r#"<-#[ink(namespace="my_namespace")]"#),
-                        end_pat: Some(r#"#[ink(namespace="my_namespace")]"#),
+                        end_pat: Some("#[ink(namespace=\"my_namespace\")]\n                "),
                     }],
                 }],
             ),
@@ -0,0 +1,554 @@
+//! Cross-file (i.e. workspace) diagnostics.
+
+use ink_analyzer_ir::ast::HasName;
+use ink_analyzer_ir::syntax::{AstNode, TextRange};
+use ink_analyzer_ir::{
+    ast, InkFile, IsInkCallable, IsInkFn, IsInkTrait, Selector, TraitDefinition,
+};
+use itertools::Itertools;
+use std::collections::{HashMap, HashSet};
+
+use crate::analysis::utils as analysis_utils;
+use crate::{Diagnostic, Severity};
+
+/// An ink! message that implements a method of an ink! trait definition.
+struct TraitMessageImpl<'a> {
+    /// Identifier of the file that contains the implementation.
+    file_id: &'a str,
+    /// Name of the ink! trait definition.
+    trait_name: String,
+    /// Name of the ink! message.
+    message_name: String,
+    /// Name of the parent ink! contract.
+    contract_name: String,
+    /// The composed selector of the ink! message.
+    selector: Selector,
+    /// Text range of the ink! message name.
+    range: TextRange,
+}
+
+/// Runs cross-file diagnostics for the given `(file_id, code)` pairs.
+///
+/// Returns diagnostics paired with the identifier of the file they apply to.
+pub fn diagnostics(sources: &[(&str, &str)]) -> Vec<(String, Diagnostic)> {
+    let files: Vec<(String, InkFile)> = sources
+        .iter()
+        .map(|(file_id, code)| (file_id.to_string(), InkFile::parse(code)))
+        .collect();
+    files_diagnostics(&files)
+}
+
+/// Runs cross-file diagnostics for the given `(file_id, file)` pairs (i.e. already parsed files).
+///
+/// Returns diagnostics paired with the identifier of the file they apply to.
+pub fn files_diagnostics(files: &[(String, InkFile)]) -> Vec<(String, Diagnostic)> {
+    let mut results = Vec::new();
+
+    // Ensures that ink! trait definition implementations have matching selectors across contracts,
+    // see `ensure_trait_definition_impl_selectors_match` doc.
+    ensure_trait_definition_impl_selectors_match(&mut results, files);
+
+    // Ensures that implementations of ink! trait definitions from other files match the declarations,
+    // see `ensure_external_trait_definition_impls_match` doc.
+    ensure_external_trait_definition_impls_match(&mut results, files);
+
+    results
+}
+
+/// Ensures that all ink! contracts (across all files) that implement the same ink! trait definition
+/// (resolved by name) compose the same selector for each method of the ink! trait definition.
+///
+/// Cross-contract calls via an ink! trait definition (e.g. using `contract_ref!`)
+/// will fail for implementers whose selectors don't agree.
+fn ensure_trait_definition_impl_selectors_match(
+    results: &mut Vec<(String, Diagnostic)>,
+    files: &[(String, InkFile)],
+) {
+    // Collects the names of all ink! trait definitions in the workspace.
+    let trait_names: HashSet<String> = files
+        .iter()
+        .flat_map(|(_, file)| {
+            file.trait_definitions()
+                .iter()
+                .filter_map(|trait_definition| {
+                    trait_definition
+                        .trait_item()
+                        .and_then(HasName::name)
+                        .as_ref()
+                        .map(ToString::to_string)
+                })
+        })
+        .collect();
+
+    // Collects ink! messages that implement ink! trait definition methods.
+    let mut message_impls = Vec::new();
+    for (file_id, file) in files {
+        for contract in file.contracts() {
+            let contract_name = contract
+                .module()
+                .and_then(HasName::name)
+                .map_or(String::new(), |name| name.to_string());
+            for ink_impl in contract.impls() {
+                let trait_name_option = ink_impl
+                    .trait_type()
+                    .as_ref()
+                    .and_then(trait_name)
+                    .filter(|name| trait_names.contains(name));
+                if let Some(trait_name) = trait_name_option {
+                    for message in ink_impl.messages() {
+                        if let Some((name, selector)) = message
+                            .fn_item()
+                            .and_then(HasName::name)
+                            .zip(message.composed_selector())
+                        {
+                            message_impls.push(TraitMessageImpl {
+                                file_id: file_id.as_str(),
+                                trait_name: trait_name.clone(),
+                                message_name: name.to_string(),
+                                contract_name: contract_name.clone(),
+                                selector,
+                                range: name.syntax().text_range(),
+                            });
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    for message_impl in &message_impls {
+        // Finds other implementations of the same ink! trait definition method with different selectors.
+        let mismatches = message_impls
+            .iter()
+            .filter(|other| {
+                other.trait_name == message_impl.trait_name
+                    && other.message_name == message_impl.message_name
+                    && other.selector != message_impl.selector
+            })
+            .map(|other| format!("`{}` (in `{}`)", other.contract_name, other.file_id))
+            .unique()
+            .join(", ");
+        if !mismatches.is_empty() {
+            results.push((
+                message_impl.file_id.to_string(),
+                Diagnostic {
+                    message: format!(
+                        "The selector of ink! message `{}` (`{}`) for ink! trait definition `{}` \
+                        doesn't match the selector(s) composed by other implementations in: {mismatches}.",
+                        message_impl.message_name,
+                        ink_analyzer_ir::format_selector(message_impl.selector.into_be_u32()),
+                        message_impl.trait_name,
+                    ),
+                    range: message_impl.range,
+                    severity: Severity::Error,
+                    quickfixes: None,
+                },
+            ));
+        }
+    }
+}
+
+/// Ensures that ink! `impl` blocks that implement an ink! trait definition declared in another file
+/// only implement (and implement all) the declared ink! messages with the declared parameter lists.
+///
+/// This catches ink! trait definitions that changed without updating their implementations in other files
+/// (per-file diagnostics only cover ink! trait definitions declared in the same file as the implementation).
+///
+/// **NOTE**: ink! trait definitions are resolved by name, so names that are either declared in more than one file
+/// or declared in the same file as the implementation are ignored.
+fn ensure_external_trait_definition_impls_match(
+    results: &mut Vec<(String, Diagnostic)>,
+    files: &[(String, InkFile)],
+) {
+    // Maps ink! trait definition names to their declarations (and declaring files).
+    let mut declarations: HashMap<String, Vec<(&str, &TraitDefinition)>> = HashMap::new();
+    for (file_id, file) in files {
+        for trait_definition in file.trait_definitions() {
+            if let Some(name) = trait_definition.trait_item().and_then(HasName::name) {
+                declarations
+                    .entry(name.to_string())
+                    .or_default()
+                    .push((file_id.as_str(), trait_definition));
+            }
+        }
+    }
+
+    for (file_id, file) in files {
+        for ink_impl in file
+            .contracts()
+            .iter()
+            .flat_map(|contract| contract.impls())
+        {
+            let Some((trait_type, trait_name)) = ink_impl
+                .trait_type()
+                .and_then(|trait_type| trait_name(&trait_type).map(|name| (trait_type, name)))
+            else {
+                continue;
+            };
+            let Some((declaration_file_id, trait_definition)) = declarations
+                .get(&trait_name)
+                .and_then(|it| match it.as_slice() {
+                    [(declaration_file_id, trait_definition)] if declaration_file_id != file_id => {
+                        Some((*declaration_file_id, *trait_definition))
+                    }
+                    _ => None,
+                })
+            else {
+                continue;
+            };
+
+            // Maps ink! message names to parameter lists (if any) for the declaration.
+            let declared_messages: Vec<(String, Option<ast::ParamList>)> = trait_definition
+                .messages()
+                .iter()
+                .filter_map(|message| {
+                    let fn_item = message.fn_item()?;
+                    Some((fn_item.name()?.to_string(), fn_item.param_list()))
+                })
+                .collect();
+            let implemented_fns: Vec<(ast::Name, ast::Fn)> = ink_impl
+                .impl_item()
+                .and_then(|impl_item| impl_item.assoc_item_list())
+                .into_iter()
+                .flat_map(|item_list| item_list.assoc_items())
+                .filter_map(|item| match item {
+                    ast::AssocItem::Fn(fn_item) => Some((fn_item.name()?, fn_item)),
+                    _ => None,
+                })
+                .collect();
+
+            for (name, fn_item) in &implemented_fns {
+                let name_text = name.to_string();
+                let diagnostic = match declared_messages
+                    .iter()
+                    .find(|(declared_name, _)| *declared_name == name_text)
+                {
+                    // Handles methods that aren't declared by the ink! trait definition.
+                    None => Diagnostic {
+                        message: format!(
+                            "A `{name_text}` method isn't declared in ink! trait definition `{trait_name}` \
+                            (in `{declaration_file_id}`)."
+                        ),
+                        range: name.syntax().text_range(),
+                        severity: Severity::Error,
+                        quickfixes: None,
+                    },
+                    // Handles parameter lists that don't match the declaration.
+                    Some((_, declared_params)) => {
+                        let is_match = match (declared_params, fn_item.param_list()) {
+                            (Some(declared), Some(implemented)) => {
+                                analysis_utils::is_trivia_insensitive_eq(
+                                    declared.syntax(),
+                                    implemented.syntax(),
+                                )
+                            }
+                            (None, None) => true,
+                            _ => false,
+                        };
+                        if is_match {
+                            continue;
+                        }
+                        Diagnostic {
+                            message: format!(
+                                "The parameter list of `{name_text}` doesn't match its declaration \
+                                in ink! trait definition `{trait_name}` (in `{declaration_file_id}`)."
+                            ),
+                            range: fn_item
+                                .param_list()
+                                .map_or(name.syntax().text_range(), |param_list| {
+                                    param_list.syntax().text_range()
+                                }),
+                            severity: Severity::Error,
+                            quickfixes: None,
+                        }
+                    }
+                };
+                results.push((file_id.clone(), diagnostic));
+            }
+
+            // Handles ink! messages that are declared but not implemented.
+            let unimplemented = declared_messages
+                .iter()
+                .filter(|(declared_name, _)| {
+                    !implemented_fns
+                        .iter()
+                        .any(|(name, _)| name.to_string() == *declared_name)
+                })
+                .map(|(declared_name, _)| format!("`{declared_name}`"))
+                .join(", ");
+            if !unimplemented.is_empty() {
+                results.push((
+                    file_id.clone(),
+                    Diagnostic {
+                        message: format!(
+                            "Missing implementation(s) for ink! message(s) {unimplemented} \
+                            of ink! trait definition `{trait_name}` (in `{declaration_file_id}`)."
+                        ),
+                        range: trait_type.syntax().text_range(),
+                        severity: Severity::Error,
+                        quickfixes: None,
+                    },
+                ));
+            }
+        }
+    }
+}
+
+/// Returns the name of the trait (i.e. the last segment of the trait path) for an `impl` trait type.
+fn trait_name(trait_type: &ast::Type) -> Option<String> {
+    match trait_type {
+        ast::Type::PathType(path_type) => path_type
+            .path()?
+            .segment()?
+            .name_ref()
+            .as_ref()
+            .map(ToString::to_string),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_utils::quote_as_string;
+
+    // Trait definition file.
+    fn trait_definition_file() -> String {
+        quote_as_string! {
+            #[ink::trait_definition]
+            pub trait MyTrait {
+                #[ink(message)]
+                fn my_message(&self);
+            }
+        }
+    }
+
+    // Contract file that implements `MyTrait`, `impl_attrs` are added to the `impl` item.
+    fn contract_file(name: &str, trait_path: &str, impl_attrs: &str) -> String {
+        format!(
+            r#"
+            #[ink::contract]
+            mod {name} {{
+                #[ink(storage)]
+                pub struct Contract {{}}
+
+                {impl_attrs}
+                impl {trait_path} for Contract {{
+                    #[ink(message)]
+                    fn my_message(&self) {{}}
+                }}
+            }}
+            "#
+        )
+    }
+
+    #[test]
+    fn matching_selectors_works() {
+        let trait_file = trait_definition_file();
+        for (contract_a, contract_b) in [
+            // Same trait path.
+            (
+                contract_file("contract_a", "MyTrait", ""),
+                contract_file("contract_b", "MyTrait", ""),
+            ),
+            // Different short paths with the same last segment.
+            (
+                contract_file("contract_a", "MyTrait", ""),
+                contract_file("contract_b", "super::MyTrait", ""),
+            ),
+            (
+                contract_file("contract_a", "crate::MyTrait", ""),
+                contract_file("contract_b", "MyTrait", ""),
+            ),
+            // Same namespace.
+            (
+                contract_file(
+                    "contract_a",
+                    "MyTrait",
+                    r#"#[ink(namespace="my_namespace")]"#,
+                ),
+                contract_file(
+                    "contract_b",
+                    "MyTrait",
+                    r#"#[ink(namespace="my_namespace")]"#,
+                ),
+            ),
+        ] {
+            let results = diagnostics(&[
+                ("traits.rs", &trait_file),
+                ("contract_a.rs", &contract_a),
+                ("contract_b.rs", &contract_b),
+            ]);
+            assert!(results.is_empty(), "a: {contract_a}\nb: {contract_b}");
+        }
+    }
+
+    #[test]
+    fn mismatched_selectors_fails() {
+        let trait_file = trait_definition_file();
+        for (contract_a, contract_b) in [
+            // Different namespaces.
+            (
+                contract_file("contract_a", "MyTrait", ""),
+                contract_file(
+                    "contract_b",
+                    "MyTrait",
+                    r#"#[ink(namespace="my_namespace")]"#,
+                ),
+            ),
+            (
+                contract_file(
+                    "contract_a",
+                    "MyTrait",
+                    r#"#[ink(namespace="namespace_a")]"#,
+                ),
+                contract_file(
+                    "contract_b",
+                    "MyTrait",
+                    r#"#[ink(namespace="namespace_b")]"#,
+                ),
+            ),
+            // Full path vs short path.
+            (
+                contract_file("contract_a", "MyTrait", ""),
+                contract_file("contract_b", "crate::traits::MyTrait", ""),
+            ),
+        ] {
+            let results = diagnostics(&[
+                ("traits.rs", &trait_file),
+                ("contract_a.rs", &contract_a),
+                ("contract_b.rs", &contract_b),
+            ]);
+            // Verifies that both implementations are flagged (in order).
+            assert_eq!(
+                results
+                    .iter()
+                    .map(|(file_id, diagnostic)| (file_id.as_str(), diagnostic.severity))
+                    .collect::<Vec<(&str, Severity)>>(),
+                vec![
+                    ("contract_a.rs", Severity::Error),
+                    ("contract_b.rs", Severity::Error)
+                ],
+                "a: {contract_a}\nb: {contract_b}"
+            );
+            // Verifies diagnostic ranges.
+            for ((_, diagnostic), code) in results.iter().zip([&contract_a, &contract_b]) {
+                assert_eq!(&code[diagnostic.range], "my_message");
+            }
+        }
+    }
+
+    #[test]
+    fn non_trait_definition_impls_are_ignored() {
+        // `OtherTrait` isn't an ink! trait definition in the workspace.
+        let results = diagnostics(&[
+            ("traits.rs", &trait_definition_file()),
+            (
+                "contract_a.rs",
+                &contract_file("contract_a", "OtherTrait", ""),
+            ),
+            (
+                "contract_b.rs",
+                &contract_file(
+                    "contract_b",
+                    "OtherTrait",
+                    r#"#[ink(namespace="my_namespace")]"#,
+                ),
+            ),
+        ]);
+        assert!(results.is_empty());
+    }
+
+    #[test]
+    fn external_trait_definition_impls_match_works() {
+        for (trait_file, contract_file, expected_results) in [
+            // (trait_file, contract_file, [(file_id, text)]) where:
+            // trait_file = source code for `traits.rs`,
+            // contract_file = source code for `contract.rs`,
+            // file_id = identifier of the file the diagnostic applies to,
+            // text = the text covered by the diagnostic range.
+
+            // Matching implementation.
+            (
+                trait_definition_file(),
+                contract_file("contract_a", "MyTrait", ""),
+                vec![],
+            ),
+            // Trivia differences are ignored.
+            (
+                trait_definition_file().replace("(& self)", "( &self /* comment */ )"),
+                contract_file("contract_a", "MyTrait", ""),
+                vec![],
+            ),
+            // Changed parameter list.
+            (
+                trait_definition_file().replace("(& self)", "(&self, a: u8)"),
+                contract_file("contract_a", "MyTrait", ""),
+                vec![("contract.rs", "(&self)")],
+            ),
+            // Renamed ink! message.
+            (
+                trait_definition_file().replace("my_message", "my_message2"),
+                contract_file("contract_a", "MyTrait", ""),
+                vec![("contract.rs", "my_message"), ("contract.rs", "MyTrait")],
+            ),
+            // ink! trait definition declared in the same file is ignored
+            // (i.e. it's covered by per-file diagnostics).
+            (
+                String::new(),
+                format!(
+                    "{}{}",
+                    trait_definition_file().replace("(& self)", "(&self, a: u8)"),
+                    contract_file("contract_a", "MyTrait", "")
+                ),
+                vec![],
+            ),
+            // Unknown trait is ignored.
+            (
+                trait_definition_file().replace("(& self)", "(&self, a: u8)"),
+                contract_file("contract_a", "OtherTrait", ""),
+                vec![],
+            ),
+        ] {
+            let files = vec![
+                ("traits.rs".to_string(), InkFile::parse(&trait_file)),
+                ("contract.rs".to_string(), InkFile::parse(&contract_file)),
+            ];
+
+            let mut results = Vec::new();
+            ensure_external_trait_definition_impls_match(&mut results, &files);
+            assert_eq!(
+                results
+                    .iter()
+                    .map(|(file_id, diagnostic)| (
+                        file_id.as_str(),
+                        &contract_file[diagnostic.range]
+                    ))
+                    .collect::<Vec<_>>(),
+                expected_results,
+                "traits: {trait_file}\ncontract: {contract_file}"
+            );
+            // All diagnostics should be errors.
+            assert!(results
+                .iter()
+                .all(|(_, diagnostic)| diagnostic.severity == Severity::Error));
+        }
+    }
+
+    #[test]
+    fn ambiguous_external_trait_definitions_are_ignored() {
+        let trait_file = trait_definition_file().replace("(& self)", "(&self, a: u8)");
+        let files = vec![
+            ("traits_a.rs".to_string(), InkFile::parse(&trait_file)),
+            ("traits_b.rs".to_string(), InkFile::parse(&trait_file)),
+            (
+                "contract.rs".to_string(),
+                InkFile::parse(&contract_file("contract_a", "MyTrait", "")),
+            ),
+        ];
+
+        let mut results = Vec::new();
+        ensure_external_trait_definition_impls_match(&mut results, &files);
+        assert!(results.is_empty());
+    }
+}
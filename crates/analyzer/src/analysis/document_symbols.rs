@@ -0,0 +1,355 @@
+//! ink! document symbols.
+
+use ink_analyzer_ir::ast::{HasName, Type};
+use ink_analyzer_ir::syntax::{AstNode, TextRange};
+use ink_analyzer_ir::{
+    ast, ChainExtension, Contract, InkEntity, InkFile, InkImpl, IsInkFn, IsInkStruct, IsInkTrait,
+    StorageItem, TraitDefinition,
+};
+
+/// An ink! document symbol (i.e. an outline entry for an ink! entity).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DocumentSymbol {
+    /// Name of the symbol.
+    pub name: String,
+    /// Kind of the symbol.
+    pub kind: SymbolKind,
+    /// Range of the entire ink! entity (including attributes and rustdoc).
+    pub range: TextRange,
+    /// Range of the name of the ink! entity (or its declaration if it has no name).
+    pub selection_range: TextRange,
+    /// Nested symbols (e.g. ink! constructors and ink! messages of an ink! impl).
+    pub children: Vec<DocumentSymbol>,
+}
+
+/// The kind of an ink! document symbol.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SymbolKind {
+    /// An ink! contract.
+    Contract,
+    /// An ink! storage definition.
+    Storage,
+    /// An ink! event.
+    Event,
+    /// An ink! impl block.
+    Impl,
+    /// An ink! constructor.
+    Constructor,
+    /// An ink! message.
+    Message,
+    /// An ink! trait definition.
+    TraitDefinition,
+    /// An ink! chain extension.
+    ChainExtension,
+    /// An ink! extension.
+    Extension,
+    /// An ink! storage item.
+    StorageItem,
+    /// An ink! test.
+    InkTest,
+    /// An ink! e2e test.
+    InkE2ETest,
+}
+
+/// Computes the ink! document symbol tree for the file.
+///
+/// Root symbols are ink! contracts, ink! trait definitions, ink! chain extensions and ink! storage items,
+/// ordered by their position in the file.
+pub fn document_symbols(file: &InkFile) -> Vec<DocumentSymbol> {
+    let mut results: Vec<DocumentSymbol> = file
+        .contracts()
+        .iter()
+        .filter_map(contract_symbol)
+        .chain(
+            file.trait_definitions()
+                .iter()
+                .filter_map(trait_definition_symbol),
+        )
+        .chain(
+            file.chain_extensions()
+                .iter()
+                .filter_map(chain_extension_symbol),
+        )
+        .chain(file.storage_items().iter().filter_map(storage_item_symbol))
+        .collect();
+    results.sort_by_key(|symbol| symbol.range.start());
+    results
+}
+
+/// Computes the document symbol for an ink! contract.
+fn contract_symbol(contract: &Contract) -> Option<DocumentSymbol> {
+    let mut children: Vec<DocumentSymbol> = contract
+        .storage()
+        .and_then(|storage| named_symbol(storage, storage.struct_item(), SymbolKind::Storage))
+        .into_iter()
+        .chain(
+            contract
+                .events()
+                .iter()
+                .filter_map(|event| named_symbol(event, event.struct_item(), SymbolKind::Event)),
+        )
+        .chain(contract.impls().iter().filter_map(impl_symbol))
+        .chain(
+            contract
+                .tests()
+                .iter()
+                .filter_map(|test| named_symbol(test, test.fn_item(), SymbolKind::InkTest)),
+        )
+        .chain(
+            contract
+                .e2e_tests()
+                .iter()
+                .filter_map(|test| named_symbol(test, test.fn_item(), SymbolKind::InkE2ETest)),
+        )
+        .collect();
+    children.sort_by_key(|symbol| symbol.range.start());
+
+    named_symbol(contract, contract.module(), SymbolKind::Contract)
+        .map(|symbol| DocumentSymbol { children, ..symbol })
+}
+
+/// Computes the document symbol for an ink! impl.
+///
+/// The name is composed from the `impl` declaration (e.g. `impl MyTrait for MyContract`).
+fn impl_symbol(ink_impl: &InkImpl) -> Option<DocumentSymbol> {
+    let impl_item = ink_impl.impl_item()?;
+    let self_ty = impl_item.self_ty().as_ref().map(Type::to_string);
+    let name = match impl_item.trait_() {
+        Some(trait_ty) => format!("impl {trait_ty} for {}", self_ty.unwrap_or_default()),
+        None => format!("impl {}", self_ty.unwrap_or_default()),
+    };
+    let mut children: Vec<DocumentSymbol> =
+        ink_impl
+            .constructors()
+            .iter()
+            .filter_map(|constructor| {
+                named_symbol(constructor, constructor.fn_item(), SymbolKind::Constructor)
+            })
+            .chain(ink_impl.messages().iter().filter_map(|message| {
+                named_symbol(message, message.fn_item(), SymbolKind::Message)
+            }))
+            .collect();
+    children.sort_by_key(|symbol| symbol.range.start());
+
+    Some(DocumentSymbol {
+        name,
+        kind: SymbolKind::Impl,
+        range: ink_impl.syntax().text_range(),
+        selection_range: impl_item
+            .self_ty()
+            .map_or(impl_item.syntax().text_range(), |ty| {
+                ty.syntax().text_range()
+            }),
+        children,
+    })
+}
+
+/// Computes the document symbol for an ink! trait definition.
+fn trait_definition_symbol(trait_definition: &TraitDefinition) -> Option<DocumentSymbol> {
+    let children = trait_definition
+        .messages()
+        .iter()
+        .filter_map(|message| named_symbol(message, message.fn_item(), SymbolKind::Message))
+        .collect();
+    named_symbol(
+        trait_definition,
+        trait_definition.trait_item(),
+        SymbolKind::TraitDefinition,
+    )
+    .map(|symbol| DocumentSymbol { children, ..symbol })
+}
+
+/// Computes the document symbol for an ink! chain extension.
+fn chain_extension_symbol(chain_extension: &ChainExtension) -> Option<DocumentSymbol> {
+    let children = chain_extension
+        .extensions()
+        .iter()
+        .filter_map(|extension| named_symbol(extension, extension.fn_item(), SymbolKind::Extension))
+        .collect();
+    named_symbol(
+        chain_extension,
+        chain_extension.trait_item(),
+        SymbolKind::ChainExtension,
+    )
+    .map(|symbol| DocumentSymbol { children, ..symbol })
+}
+
+/// Computes the document symbol for an ink! storage item.
+fn storage_item_symbol(storage_item: &StorageItem) -> Option<DocumentSymbol> {
+    let name = match storage_item.adt()? {
+        ast::Adt::Struct(it) => it.name(),
+        ast::Adt::Enum(it) => it.name(),
+        ast::Adt::Union(it) => it.name(),
+    }?;
+    Some(DocumentSymbol {
+        name: name.to_string(),
+        kind: SymbolKind::StorageItem,
+        range: storage_item.syntax().text_range(),
+        selection_range: name.syntax().text_range(),
+        children: Vec::new(),
+    })
+}
+
+/// Computes a leaf document symbol for an ink! entity with a named AST item (if any).
+fn named_symbol<T, N>(entity: &T, item: Option<&N>, kind: SymbolKind) -> Option<DocumentSymbol>
+where
+    T: InkEntity,
+    N: HasName,
+{
+    let name = item?.name()?;
+    Some(DocumentSymbol {
+        name: name.to_string(),
+        kind,
+        range: entity.syntax().text_range(),
+        selection_range: name.syntax().text_range(),
+        children: Vec::new(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_utils::quote_as_pretty_string;
+
+    // Returns (depth, number of nodes) for a list of symbols.
+    fn tree_stats(symbols: &[DocumentSymbol]) -> (usize, usize) {
+        symbols.iter().fold((0, 0), |(depth, count), symbol| {
+            let (child_depth, child_count) = tree_stats(&symbol.children);
+            (depth.max(child_depth + 1), count + child_count + 1)
+        })
+    }
+
+    // Returns (name, kind) pairs for a list of symbols and their descendants (in pre-order).
+    fn flatten(symbols: &[DocumentSymbol]) -> Vec<(String, SymbolKind)> {
+        symbols
+            .iter()
+            .flat_map(|symbol| {
+                std::iter::once((symbol.name.clone(), symbol.kind)).chain(flatten(&symbol.children))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn erc20_works() {
+        let code = test_utils::read_source_code("contracts/erc20");
+        let results = document_symbols(&InkFile::parse(&code));
+
+        // contract -> impl -> callables.
+        assert_eq!(tree_stats(&results).0, 3);
+
+        let contract = &results[0];
+        assert_eq!(results.len(), 1);
+        assert_eq!(contract.kind, SymbolKind::Contract);
+        assert_eq!(contract.name, "erc20");
+        assert_eq!(
+            contract
+                .children
+                .iter()
+                .map(|symbol| symbol.kind)
+                .filter(|kind| !matches!(kind, SymbolKind::InkTest | SymbolKind::InkE2ETest))
+                .collect::<Vec<SymbolKind>>(),
+            [
+                SymbolKind::Storage,
+                SymbolKind::Event,
+                SymbolKind::Event,
+                SymbolKind::Impl,
+            ]
+        );
+
+        let impl_symbol = contract
+            .children
+            .iter()
+            .find(|symbol| symbol.kind == SymbolKind::Impl)
+            .unwrap();
+        assert_eq!(impl_symbol.name, "impl Erc20");
+        assert_eq!(
+            flatten(&impl_symbol.children),
+            [
+                ("new", SymbolKind::Constructor),
+                ("total_supply", SymbolKind::Message),
+                ("balance_of", SymbolKind::Message),
+                ("allowance", SymbolKind::Message),
+                ("transfer", SymbolKind::Message),
+                ("approve", SymbolKind::Message),
+                ("transfer_from", SymbolKind::Message),
+            ]
+            .map(|(name, kind)| (name.to_string(), kind))
+        );
+
+        // 7 ink! tests and 2 ink! e2e tests.
+        let n_tests = contract
+            .children
+            .iter()
+            .filter(|symbol| matches!(symbol.kind, SymbolKind::InkTest | SymbolKind::InkE2ETest))
+            .count();
+        assert_eq!(n_tests, 9);
+        // 1 contract + 1 storage + 2 events + 1 impl + 7 callables + 9 tests.
+        assert_eq!(tree_stats(&results).1, 21);
+
+        // Selection ranges are inside ranges.
+        fn verify_ranges(symbols: &[DocumentSymbol]) {
+            for symbol in symbols {
+                assert!(symbol.range.contains_range(symbol.selection_range));
+                verify_ranges(&symbol.children);
+            }
+        }
+        verify_ranges(&results);
+    }
+
+    #[test]
+    fn root_symbols_works() {
+        let code = quote_as_pretty_string! {
+            #[ink::trait_definition]
+            pub trait MyTrait {
+                #[ink(message)]
+                fn my_message(&self);
+            }
+
+            #[ink::chain_extension]
+            pub trait MyChainExtension {
+                type ErrorCode = ();
+
+                #[ink(extension=1)]
+                fn my_extension();
+            }
+
+            #[ink::storage_item]
+            struct MyStorageItem {}
+
+            #[ink::contract]
+            mod my_contract {
+                #[ink(storage)]
+                pub struct MyContract {}
+
+                impl crate::MyTrait for MyContract {
+                    #[ink(message)]
+                    fn my_message(&self) {}
+                }
+
+                #[cfg(all(test, feature = "e2e-tests"))]
+                mod e2e_tests {
+                    #[ink_e2e::test]
+                    async fn it_works(mut client: ::ink_e2e::Client<C, E>) -> E2EResult<()> {}
+                }
+            }
+        };
+        let results = document_symbols(&InkFile::parse(&code));
+
+        assert_eq!(
+            flatten(&results),
+            [
+                ("MyTrait", SymbolKind::TraitDefinition),
+                ("my_message", SymbolKind::Message),
+                ("MyChainExtension", SymbolKind::ChainExtension),
+                ("my_extension", SymbolKind::Extension),
+                ("MyStorageItem", SymbolKind::StorageItem),
+                ("my_contract", SymbolKind::Contract),
+                ("MyContract", SymbolKind::Storage),
+                ("impl crate::MyTrait for MyContract", SymbolKind::Impl),
+                ("my_message", SymbolKind::Message),
+                ("it_works", SymbolKind::InkE2ETest),
+            ]
+            .map(|(name, kind)| (name.to_string(), kind))
+        );
+    }
+}
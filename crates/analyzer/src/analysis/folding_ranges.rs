@@ -0,0 +1,204 @@
+//! ink! folding ranges.
+
+use ink_analyzer_ir::syntax::{SyntaxToken, TextSize};
+use ink_analyzer_ir::{ast, Contract, Event, InkEntity, InkFile, InkImpl, IsInkStruct, IsInkTrait};
+
+/// A collapsible ink! block (i.e. the body of an ink! entity).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FoldingRange {
+    /// Zero-based line of the opening curly brace (i.e. `{`) of the block.
+    pub start_line: u32,
+    /// Zero-based line of the closing curly brace (i.e. `}`) of the block.
+    pub end_line: u32,
+    /// Kind of the ink! entity.
+    pub kind: FoldingRangeKind,
+}
+
+/// The kind of ink! entity for a folding range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FoldingRangeKind {
+    /// An ink! contract `mod` body.
+    Contract,
+    /// An ink! event `struct` body.
+    Event,
+    /// An ink! impl block body.
+    Impl,
+    /// An ink! trait definition `trait` body.
+    TraitDefinition,
+    /// An ink! chain extension `trait` body.
+    ChainExtension,
+}
+
+/// Computes folding ranges for the bodies of ink! contracts, ink! events, ink! impls,
+/// ink! trait definitions and ink! chain extensions in the file.
+///
+/// Blocks whose curly braces are on the same line are ignored, and results are ordered by position.
+pub fn folding_ranges(file: &InkFile) -> Vec<FoldingRange> {
+    let line_starts = ink_analyzer_ir::line_starts(&file.syntax().to_string());
+    let line_of = |offset: TextSize| ink_analyzer_ir::offset_to_line_col(offset, &line_starts).line;
+    let folding_range = |braces: Option<(SyntaxToken, SyntaxToken)>, kind: FoldingRangeKind| {
+        let (l_curly, r_curly) = braces?;
+        let start_line = line_of(l_curly.text_range().start());
+        let end_line = line_of(r_curly.text_range().start());
+        (start_line < end_line).then_some(FoldingRange {
+            start_line,
+            end_line,
+            kind,
+        })
+    };
+
+    let contract_ranges = file.contracts().iter().filter_map(|contract| {
+        folding_range(contract_braces(contract), FoldingRangeKind::Contract)
+    });
+    let event_ranges = ink_analyzer_ir::ink_descendants::<Event>(file.syntax())
+        .filter_map(|event| folding_range(event_braces(&event), FoldingRangeKind::Event));
+    let impl_ranges = file
+        .syntax()
+        .descendants()
+        .filter_map(InkImpl::cast)
+        .filter_map(|ink_impl| folding_range(impl_braces(&ink_impl), FoldingRangeKind::Impl));
+    let trait_definition_ranges = file
+        .trait_definitions()
+        .iter()
+        .filter_map(|trait_definition| {
+            folding_range(
+                trait_braces(trait_definition.trait_item()),
+                FoldingRangeKind::TraitDefinition,
+            )
+        });
+    let chain_extension_ranges = file
+        .chain_extensions()
+        .iter()
+        .filter_map(|chain_extension| {
+            folding_range(
+                trait_braces(chain_extension.trait_item()),
+                FoldingRangeKind::ChainExtension,
+            )
+        });
+
+    let mut results: Vec<FoldingRange> = contract_ranges
+        .chain(event_ranges)
+        .chain(impl_ranges)
+        .chain(trait_definition_ranges)
+        .chain(chain_extension_ranges)
+        .collect();
+    results.sort_by_key(|range| (range.start_line, range.end_line));
+    results
+}
+
+/// Returns the curly braces of an ink! contract's `mod` item list (if any).
+fn contract_braces(contract: &Contract) -> Option<(SyntaxToken, SyntaxToken)> {
+    let item_list = contract.module()?.item_list()?;
+    item_list.l_curly_token().zip(item_list.r_curly_token())
+}
+
+/// Returns the curly braces of an ink! event's `struct` field list (if any).
+fn event_braces(event: &Event) -> Option<(SyntaxToken, SyntaxToken)> {
+    match event.struct_item()?.field_list()? {
+        ast::FieldList::RecordFieldList(field_list) => {
+            field_list.l_curly_token().zip(field_list.r_curly_token())
+        }
+        ast::FieldList::TupleFieldList(_) => None,
+    }
+}
+
+/// Returns the curly braces of an ink! impl's associated item list (if any).
+fn impl_braces(ink_impl: &InkImpl) -> Option<(SyntaxToken, SyntaxToken)> {
+    let assoc_item_list = ink_impl.impl_item()?.assoc_item_list()?;
+    assoc_item_list
+        .l_curly_token()
+        .zip(assoc_item_list.r_curly_token())
+}
+
+/// Returns the curly braces of a `trait` item's associated item list (if any).
+fn trait_braces(trait_item: Option<&ast::Trait>) -> Option<(SyntaxToken, SyntaxToken)> {
+    let assoc_item_list = trait_item?.assoc_item_list()?;
+    assoc_item_list
+        .l_curly_token()
+        .zip(assoc_item_list.r_curly_token())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Returns (start line, end line, kind) triples for a list of folding ranges.
+    fn triples(results: &[FoldingRange]) -> Vec<(u32, u32, FoldingRangeKind)> {
+        results
+            .iter()
+            .map(|range| (range.start_line, range.end_line, range.kind))
+            .collect()
+    }
+
+    #[test]
+    fn fixtures_works() {
+        for (location, expected_results) in [
+            (
+                "contracts/erc20",
+                vec![
+                    (4, 675, FoldingRangeKind::Contract),
+                    (22, 28, FoldingRangeKind::Event),
+                    (33, 39, FoldingRangeKind::Event),
+                    (54, 215, FoldingRangeKind::Impl),
+                ],
+            ),
+            (
+                "chain_extensions/psp22_extension",
+                vec![
+                    (12, 78, FoldingRangeKind::ChainExtension),
+                    (123, 265, FoldingRangeKind::Contract),
+                    (135, 264, FoldingRangeKind::Impl),
+                ],
+            ),
+        ] {
+            let code = test_utils::read_source_code(location);
+            let results = folding_ranges(&InkFile::parse(&code));
+
+            assert_eq!(triples(&results), expected_results, "fixture: {location}");
+
+            // Start and end lines contain the opening and closing curly braces respectively.
+            let lines: Vec<&str> = code.lines().collect();
+            for range in &results {
+                assert!(lines[range.start_line as usize].contains('{'));
+                assert!(lines[range.end_line as usize].contains('}'));
+            }
+        }
+    }
+
+    #[test]
+    fn single_line_blocks_are_ignored() {
+        let code = r#"
+            #[ink::trait_definition]
+            pub trait MyTrait {
+                #[ink(message)]
+                fn my_message(&self);
+            }
+
+            #[ink::contract]
+            mod my_contract {
+                #[ink(storage)]
+                pub struct MyContract {}
+
+                #[ink(event)]
+                pub struct MyEvent {}
+
+                impl MyContract {
+                    #[ink(constructor)]
+                    pub fn new() -> Self {}
+                }
+
+                impl MyContract { #[ink(message)] pub fn my_message(&self) {} }
+            }
+        "#;
+        let results = folding_ranges(&InkFile::parse(code));
+
+        assert_eq!(
+            triples(&results),
+            [
+                (2, 5, FoldingRangeKind::TraitDefinition),
+                (8, 21, FoldingRangeKind::Contract),
+                (15, 18, FoldingRangeKind::Impl),
+            ]
+        );
+    }
+}
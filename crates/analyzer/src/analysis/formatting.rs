@@ -0,0 +1,455 @@
+//! ink! attribute formatting.
+
+use ink_analyzer_ir::syntax::{
+    AstNode, NodeOrToken, SyntaxKind, SyntaxNode, SyntaxToken, TextRange, TextSize,
+};
+use ink_analyzer_ir::{ast, InkAttribute, InkEntity, InkFile};
+use itertools::Itertools;
+
+use super::text_edit::TextEdit;
+
+/// Computes formatting edits for the entire file.
+///
+/// In addition to the whitespace normalization of each ink! attribute (see [`range_format`]),
+/// ink! attributes on their own line are indented to match their target item,
+/// and top-level items with ink! attributes are separated from the preceding item by an empty line.
+///
+/// **NOTE**: Edits are minimal (i.e. only whitespace that needs changing is replaced)
+/// and sorted by their (non-overlapping) ranges.
+pub fn format(file: &InkFile) -> Vec<TextEdit> {
+    let mut results: Vec<TextEdit> = Vec::new();
+    for attr in file.tree().ink_attrs_in_scope() {
+        // Normalizes whitespace inside the ink! attribute.
+        results.extend(format_ink_attribute(&attr));
+
+        // Indents the ink! attribute to match its target item.
+        if let Some(edit) = indent_ink_attribute(&attr) {
+            results.push(edit);
+        }
+    }
+
+    // Separates top-level items with ink! attributes from the preceding item.
+    for item in file.syntax().children().filter(|node| {
+        ast::Item::can_cast(node.kind()) && ink_analyzer_ir::ink_attrs(node).next().is_some()
+    }) {
+        if let Some(edit) = separate_top_level_item(&item) {
+            results.push(edit);
+        }
+    }
+
+    results
+        .into_iter()
+        .sorted_by_key(|edit| (edit.range.start(), edit.range.end()))
+        .collect()
+}
+
+/// Computes formatting edits for the ink! attributes that intersect with the given text range.
+///
+/// **NOTE**: Only edits that are fully contained in the text range are returned.
+pub fn range_format(file: &InkFile, range: TextRange) -> Vec<TextEdit> {
+    file.tree()
+        .ink_attrs_in_scope()
+        .filter(|attr| attr.syntax().text_range().intersect(range).is_some())
+        .flat_map(|attr| format_ink_attribute(&attr))
+        .filter(|edit| range.contains_range(edit.range))
+        .collect()
+}
+
+/// Computes whitespace normalization edits for an ink! attribute
+/// (e.g. `#[ink( message,payable )]` -> `#[ink(message, payable)]`).
+///
+/// **NOTE**: Whitespace that contains new lines or comments is left as is,
+/// except for trailing whitespace at the end of lines which is removed.
+fn format_ink_attribute(attr: &InkAttribute) -> Vec<TextEdit> {
+    let mut results = Vec::new();
+    // Removes trailing whitespace at the end of lines.
+    for token in attr
+        .syntax()
+        .descendants_with_tokens()
+        .filter_map(|elem| elem.into_token())
+        .filter(|token| token.kind() == SyntaxKind::WHITESPACE && token.text().contains('\n'))
+    {
+        let text = token.text();
+        let expected = format!(
+            "{}{}",
+            "\n".repeat(text.matches('\n').count()),
+            text.rsplit('\n').next().unwrap_or_default()
+        );
+        if text != expected {
+            results.push(TextEdit::replace(expected, token.text_range()));
+        }
+    }
+
+    let mut prev_token_option: Option<SyntaxToken> = None;
+    let mut trivia: Vec<SyntaxToken> = Vec::new();
+    for token in attr
+        .syntax()
+        .descendants_with_tokens()
+        .filter_map(|elem| elem.into_token())
+    {
+        if token.kind().is_trivia() {
+            trivia.push(token);
+            continue;
+        }
+
+        if let Some(prev_token) = prev_token_option {
+            let is_plain_inline_whitespace = trivia
+                .iter()
+                .all(|it| it.kind() == SyntaxKind::WHITESPACE && !it.text().contains('\n'));
+            if let Some(expected) =
+                expected_whitespace(&prev_token, &token).filter(|_| is_plain_inline_whitespace)
+            {
+                let actual: String = trivia.iter().map(SyntaxToken::text).collect();
+                if actual != expected {
+                    results.push(TextEdit::replace(
+                        expected.to_string(),
+                        TextRange::new(prev_token.text_range().end(), token.text_range().start()),
+                    ));
+                }
+            }
+        }
+
+        prev_token_option = Some(token);
+        trivia.clear();
+    }
+    results
+}
+
+/// Computes an edit that indents an ink! attribute (on its own line) to match its target item
+/// (i.e. the indenting of the line that contains the item's declaration).
+fn indent_ink_attribute(attr: &InkAttribute) -> Option<TextEdit> {
+    let (actual_indent, indent_end) = line_indent_before(attr.syntax().first_token()?)?;
+    let (expected_indent, _) = attr
+        .syntax()
+        .parent()
+        .as_ref()
+        .and_then(declaration_first_token)
+        .and_then(line_indent_before)?;
+    (actual_indent != expected_indent).then(|| {
+        TextEdit::replace(
+            expected_indent.clone(),
+            TextRange::new(
+                indent_end - TextSize::of(actual_indent.as_str()),
+                indent_end,
+            ),
+        )
+    })
+}
+
+/// Computes an edit that separates a top-level item from the preceding item with an empty line
+/// (if there's a preceding item and the whitespace between them doesn't already include an empty line).
+///
+/// **NOTE**: Items that are separated from the preceding item by comments are left as is.
+fn separate_top_level_item(item: &SyntaxNode) -> Option<TextEdit> {
+    let first_token = item.first_token()?;
+    // Leading comments are part of the item's syntax tree.
+    if first_token.kind() == SyntaxKind::COMMENT {
+        return None;
+    }
+    let prev_token = first_token.prev_token()?;
+    match prev_token.kind() {
+        SyntaxKind::WHITESPACE => {
+            let text = prev_token.text();
+            let n_new_lines = text.matches('\n').count();
+            // Ignores items at the start of the file or preceded by comments.
+            if n_new_lines >= 2
+                || prev_token
+                    .prev_token()
+                    .map_or(true, |token| token.kind() == SyntaxKind::COMMENT)
+            {
+                return None;
+            }
+            // Only replaces the whitespace before the indenting (if any).
+            let indent = if n_new_lines == 0 {
+                ""
+            } else {
+                text.rsplit('\n').next().unwrap_or_default()
+            };
+            let start = prev_token.text_range().start();
+            Some(TextEdit::replace(
+                "\n\n".to_string(),
+                TextRange::new(start, start + TextSize::of(text) - TextSize::of(indent)),
+            ))
+        }
+        // Comments are left as is.
+        SyntaxKind::COMMENT => None,
+        _ => Some(TextEdit::insert(
+            "\n\n".to_string(),
+            item.text_range().start(),
+        )),
+    }
+}
+
+/// Returns the first non-trivia token of an item's declaration (i.e. excluding attributes and doc comments).
+fn declaration_first_token(item: &SyntaxNode) -> Option<SyntaxToken> {
+    item.children_with_tokens().find_map(|elem| match elem {
+        NodeOrToken::Node(node) if node.kind() != SyntaxKind::ATTR => node.first_token(),
+        NodeOrToken::Token(token) if !token.kind().is_trivia() => Some(token),
+        _ => None,
+    })
+}
+
+/// Returns the indenting of the line that starts with the given token (if any)
+/// and the end offset of the indenting.
+///
+/// Returns `None` if the token isn't the first non-whitespace token of its line.
+fn line_indent_before(token: SyntaxToken) -> Option<(String, TextSize)> {
+    let start = token.text_range().start();
+    match token.prev_token() {
+        Some(prev_token) if prev_token.kind() == SyntaxKind::WHITESPACE => {
+            match prev_token.text().rsplit_once('\n') {
+                Some((_, indent)) => Some((indent.to_string(), start)),
+                // Whitespace at the start of the file.
+                None => prev_token
+                    .prev_token()
+                    .is_none()
+                    .then(|| (prev_token.text().to_string(), start)),
+            }
+        }
+        Some(_) => None,
+        // Start of the file.
+        None => Some((String::new(), start)),
+    }
+}
+
+/// Returns the expected whitespace between 2 adjacent non-trivia tokens in an ink! attribute
+/// (if the whitespace between the tokens is significant for formatting).
+fn expected_whitespace(prev_token: &SyntaxToken, next_token: &SyntaxToken) -> Option<&'static str> {
+    match (prev_token.kind(), next_token.kind()) {
+        // No whitespace before delimiters and closing brackets.
+        (_, SyntaxKind::COMMA | SyntaxKind::R_PAREN | SyntaxKind::R_BRACK) => Some(""),
+        // Single space after commas.
+        (SyntaxKind::COMMA, _) => Some(" "),
+        // Single space around `=`.
+        (SyntaxKind::EQ, _) | (_, SyntaxKind::EQ) => Some(" "),
+        // No whitespace after opening brackets, around path separators and before argument lists.
+        (
+            SyntaxKind::POUND
+            | SyntaxKind::L_BRACK
+            | SyntaxKind::L_PAREN
+            | SyntaxKind::COLON
+            | SyntaxKind::COLON2,
+            _,
+        )
+        | (_, SyntaxKind::COLON | SyntaxKind::COLON2 | SyntaxKind::L_PAREN) => Some(""),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ink_analyzer_ir::syntax::TextSize;
+    use test_utils::parse_offset_at;
+
+    // Applies text edits to the source code.
+    fn apply_edits(code: &str, edits: &[TextEdit]) -> String {
+        let mut output = code.to_string();
+        // Applies edits in reverse order so that offsets of subsequent edits remain valid.
+        for edit in edits.iter().rev() {
+            output.replace_range(
+                usize::from(edit.range.start())..usize::from(edit.range.end()),
+                &edit.text,
+            );
+        }
+        output
+    }
+
+    #[test]
+    fn format_ink_attribute_works() {
+        for (code, expected_output) in [
+            // Already formatted.
+            ("#[ink::contract]", "#[ink::contract]"),
+            ("#[ink(message, payable)]", "#[ink(message, payable)]"),
+            (
+                r#"#[ink::contract(env = my::env::Types, keep_attr = "foo,bar")]"#,
+                r#"#[ink::contract(env = my::env::Types, keep_attr = "foo,bar")]"#,
+            ),
+            // Needs formatting.
+            ("# [ ink :: contract ]", "#[ink::contract]"),
+            ("#[ink( message,payable )]", "#[ink(message, payable)]"),
+            (
+                "#[ink (message , selector=0xA)]",
+                "#[ink(message, selector = 0xA)]",
+            ),
+            (
+                r#"#[ink::contract(env=my :: env::Types,keep_attr  =  "foo,bar")]"#,
+                r#"#[ink::contract(env = my::env::Types, keep_attr = "foo,bar")]"#,
+            ),
+            // Whitespace with new lines is left as is.
+            (
+                "#[ink(\n    message,\n    payable\n)]",
+                "#[ink(\n    message,\n    payable\n)]",
+            ),
+        ] {
+            let file = InkFile::parse(code);
+            let attr = file.tree().ink_attrs_in_scope().next().unwrap();
+
+            let edits = format_ink_attribute(&attr);
+            assert_eq!(apply_edits(code, &edits), expected_output, "code: {code}");
+        }
+    }
+
+    #[test]
+    fn range_format_works() {
+        let code = r#"
+#[ink::contract( env=my::env::Types )]
+mod my_contract {
+    #[ink( storage )]
+    pub struct MyContract {}
+
+    #[ink( event )]
+    pub struct MyEvent {
+        #[ink (topic)]
+        value: bool,
+    }
+
+    impl MyContract {
+        #[ink(constructor,payable , selector=1)]
+        pub fn new() -> Self {}
+
+        #[ink( constructor )]
+        pub fn default() -> Self {}
+
+        #[ink(message,selector=2 )]
+        pub fn my_message(&self) {}
+    }
+}
+"#;
+        let file = InkFile::parse(code);
+
+        for (range_pat, expected_attrs) in [
+            // Single constructor.
+            (
+                (
+                    Some("<-#[ink(constructor,payable"),
+                    Some("pub fn new() -> Self {}"),
+                ),
+                vec!["#[ink(constructor, payable, selector = 1)]"],
+            ),
+            // Partially covered ink! attribute.
+            (
+                (Some("<-#[ink( constructor )]"), Some("#[ink( c")),
+                vec!["#[ink(constructor )]"],
+            ),
+            // Multiple items.
+            (
+                (Some("<-#[ink( event )]"), Some("value: bool,")),
+                vec!["#[ink(event)]", "#[ink(topic)]"],
+            ),
+            // No ink! attributes in range.
+            ((Some("<-pub fn new()"), Some("pub fn new()")), vec![]),
+        ] {
+            let range = TextRange::new(
+                TextSize::from(parse_offset_at(code, range_pat.0).unwrap() as u32),
+                TextSize::from(parse_offset_at(code, range_pat.1).unwrap() as u32),
+            );
+            let edits = range_format(&file, range);
+
+            // Verifies that no edits are outside the range.
+            assert!(
+                edits.iter().all(|edit| range.contains_range(edit.range)),
+                "range: {range:?}"
+            );
+
+            // Verifies formatted ink! attributes.
+            let output = apply_edits(code, &edits);
+            for expected_attr in &expected_attrs {
+                assert!(output.contains(expected_attr), "output: {output}");
+            }
+            // Verifies that code outside the range is unchanged.
+            let suffix_len = code.len() - usize::from(range.end());
+            assert_eq!(
+                &output[..usize::from(range.start())],
+                &code[..usize::from(range.start())]
+            );
+            assert_eq!(
+                &output[output.len() - suffix_len..],
+                &code[usize::from(range.end())..]
+            );
+        }
+    }
+
+    #[test]
+    fn format_works() {
+        for (code, expected_output) in [
+            // Already formatted.
+            ("", ""),
+            (
+                "#[ink::contract]\nmod my_contract {\n    #[ink(storage)]\n    pub struct MyContract {}\n}",
+                "#[ink::contract]\nmod my_contract {\n    #[ink(storage)]\n    pub struct MyContract {}\n}",
+            ),
+            // ink! attribute whitespace.
+            (
+                "#[ink::contract( env=my::env::Types )]\nmod my_contract {\n    #[ink( storage )]\n    pub struct MyContract {}\n}",
+                "#[ink::contract(env = my::env::Types)]\nmod my_contract {\n    #[ink(storage)]\n    pub struct MyContract {}\n}",
+            ),
+            // Trailing whitespace in ink! attributes.
+            (
+                "#[ink(  \n    message,   \n    payable\n)]\npub fn my_message(&self) {}",
+                "#[ink(\n    message,\n    payable\n)]\npub fn my_message(&self) {}",
+            ),
+            // ink! attribute indenting.
+            (
+                "#[ink::contract]\nmod my_contract {\n#[ink(storage)]\n    pub struct MyContract {}\n\n    impl MyContract {\n            #[ink(constructor)]\n        pub fn new() -> Self {}\n\n        /// Docs.\n          #[ink(message)]\n        pub fn my_message(&self) {}\n    }\n}",
+                "#[ink::contract]\nmod my_contract {\n    #[ink(storage)]\n    pub struct MyContract {}\n\n    impl MyContract {\n        #[ink(constructor)]\n        pub fn new() -> Self {}\n\n        /// Docs.\n        #[ink(message)]\n        pub fn my_message(&self) {}\n    }\n}",
+            ),
+            (
+                "    #[ink::trait_definition]\npub trait MyTrait {}",
+                "#[ink::trait_definition]\npub trait MyTrait {}",
+            ),
+            // ink! attributes that aren't on their own line are left as is.
+            (
+                "#[ink::contract]\nmod my_contract {\n    #[ink(storage)] pub struct MyContract {}\n}",
+                "#[ink::contract]\nmod my_contract {\n    #[ink(storage)] pub struct MyContract {}\n}",
+            ),
+            // Empty lines between top-level ink! items.
+            (
+                "use ink::prelude::vec::Vec;\n#[ink::trait_definition]\npub trait MyTrait {}\n#[ink::contract]\nmod my_contract {}",
+                "use ink::prelude::vec::Vec;\n\n#[ink::trait_definition]\npub trait MyTrait {}\n\n#[ink::contract]\nmod my_contract {}",
+            ),
+            (
+                "pub trait MyTrait {} #[ink::contract]\nmod my_contract {}",
+                "pub trait MyTrait {}\n\n#[ink::contract]\nmod my_contract {}",
+            ),
+            (
+                "pub trait MyTrait {}#[ink::contract]\nmod my_contract {}",
+                "pub trait MyTrait {}\n\n#[ink::contract]\nmod my_contract {}",
+            ),
+            // Top-level items preceded by comments are left as is.
+            (
+                "pub trait MyTrait {}\n// A comment.\n#[ink::contract]\nmod my_contract {}",
+                "pub trait MyTrait {}\n// A comment.\n#[ink::contract]\nmod my_contract {}",
+            ),
+        ] {
+            let file = InkFile::parse(code);
+
+            let edits = format(&file);
+            let output = apply_edits(code, &edits);
+            assert_eq!(output, expected_output, "code: {code}");
+
+            // Formatting is idempotent.
+            assert!(format(&InkFile::parse(&output)).is_empty(), "code: {code}");
+        }
+    }
+
+    #[test]
+    fn format_is_idempotent_for_fixtures() {
+        for source in [
+            "contracts/erc20",
+            "contracts/flipper",
+            "contracts/mother",
+            "trait_definitions/erc20_trait",
+            "trait_definitions/flipper_trait",
+            "chain_extensions/psp22_extension",
+            "chain_extensions/rand_extension",
+            "storage_items/complex_packed_struct",
+        ] {
+            let code = test_utils::read_source_code(source);
+
+            let output = apply_edits(&code, &format(&InkFile::parse(&code)));
+            let edits = format(&InkFile::parse(&output));
+            assert!(edits.is_empty(), "source: {source}, edits: {edits:?}");
+        }
+    }
+}
@@ -3,8 +3,11 @@
 mod args;
 mod macros;
 
-use ink_analyzer_ir::syntax::{AstNode, AstToken, TextRange};
-use ink_analyzer_ir::{InkArgKind, InkAttributeKind, InkFile, InkMacroKind};
+use ink_analyzer_ir::ast::HasDocComments;
+use ink_analyzer_ir::syntax::{AstNode, AstToken, SyntaxKind, TextRange};
+use ink_analyzer_ir::{
+    ast, EnvArg, InkArg, InkArgKind, InkAttribute, InkAttributeKind, InkFile, InkMacroKind,
+};
 
 use crate::analysis::utils;
 
@@ -32,6 +35,11 @@ pub fn hover(file: &InkFile, range: TextRange) -> Option<Hover> {
         match ink_arg {
             // Returns hover content for the covered ink! attribute argument if it's valid.
             Some(ink_arg) => {
+                // Returns hover content for the covered ink! attribute argument value (if any).
+                if let Some(value_hover) = arg_value_hover(&ink_attr, ink_arg, range) {
+                    return Some(value_hover);
+                }
+
                 let attr_kind = InkAttributeKind::Arg(*ink_arg.kind());
                 let doc = content(&attr_kind);
                 (!doc.is_empty()).then_some(Hover {
@@ -65,6 +73,85 @@ pub fn hover(file: &InkFile, range: TextRange) -> Option<Hover> {
     })
 }
 
+/// Returns descriptive/informational text for the ink! attribute argument value at the given text range (if any).
+///
+/// Currently handles:
+/// - ink! environment argument values (e.g. `crate::MyEnvironment` in `#[ink::contract(env = crate::MyEnvironment)]`)
+///   that resolve to an item in the same file (i.e. shows the declaration and documentation of the item).
+/// - integer selectors (e.g. `0xDEADBEEF` in `#[ink(selector = 0xDEADBEEF)]`) (i.e. shows the decimal and hex values).
+/// - namespaces (e.g. `"my_namespace"` in `#[ink(namespace = "my_namespace")]`).
+fn arg_value_hover(ink_attr: &InkAttribute, arg: &InkArg, range: TextRange) -> Option<Hover> {
+    let value = arg.value()?;
+    let value_range = value.text_range();
+    if !value_range.contains_range(range) {
+        return None;
+    }
+
+    let content = match arg.kind() {
+        InkArgKind::Env | InkArgKind::Environment => {
+            let path = EnvArg::cast(arg.clone())?.as_path_with_inaccurate_text_range()?;
+            let item = utils::resolve_type_item(&path, ink_attr.syntax())?;
+            item_content(&item)
+        }
+        InkArgKind::Selector => {
+            let selector = value.as_u32()?;
+            format!(
+                "`{value}`\n\nDecimal: `{selector}`\n\nHex: `{}`",
+                ink_analyzer_ir::format_selector(selector)
+            )
+        }
+        InkArgKind::Namespace => {
+            let namespace = value.as_string()?;
+            format!(
+                "Namespace prefix used in selector computation \
+                (i.e. selectors are computed from `{namespace}::<callable name>`)."
+            )
+        }
+        _ => return None,
+    };
+    Some(Hover {
+        range: value_range,
+        content,
+    })
+}
+
+/// Returns the declaration (i.e. excluding attributes and rustdoc) and documentation of an item.
+fn item_content(item: &ast::Item) -> String {
+    let item_range = item.syntax().text_range();
+    let declaration_start = item
+        .syntax()
+        .children_with_tokens()
+        .find(|elem| {
+            !matches!(
+                elem.kind(),
+                SyntaxKind::ATTR | SyntaxKind::COMMENT | SyntaxKind::WHITESPACE
+            )
+        })
+        .map_or(item_range.start(), |elem| elem.text_range().start());
+    let declaration = item
+        .syntax()
+        .text()
+        .slice(TextRange::new(
+            declaration_start - item_range.start(),
+            item_range.len(),
+        ))
+        .to_string();
+    let docs = item
+        .doc_comments()
+        .filter_map(|comment| comment.doc_comment().map(|doc| doc.trim().to_string()))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    format!(
+        "```rust\n{declaration}\n```{}",
+        if docs.is_empty() {
+            String::new()
+        } else {
+            format!("\n\n{docs}")
+        }
+    )
+}
+
 /// Returns documentation for the ink! attribute kind.
 pub fn content(attr_kind: &InkAttributeKind) -> &str {
     match attr_kind {
@@ -490,4 +577,145 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn arg_value_hover_works() {
+        let code = r#"
+            /// My environment.
+            #[derive(Clone)]
+            pub enum MyEnvironment {}
+
+            #[ink::contract(env = crate::MyEnvironment)]
+            mod my_contract {
+                #[ink(namespace = "my_namespace")]
+                impl MyContract {
+                    #[ink(message, selector = 0xDEADBEEF)]
+                    pub fn my_message(&self) {}
+
+                    #[ink(message, selector = 10)]
+                    pub fn my_message2(&self) {}
+
+                    #[ink(message, selector = _)]
+                    pub fn my_message3(&self) {}
+                }
+            }
+
+            #[ink::contract(env = crate::UnknownEnvironment)]
+            mod my_contract2 {}
+        "#;
+        let file = InkFile::parse(code);
+
+        for (pat, expected_content, expected_range_pats) in [
+            // ink! environment paths.
+            (
+                Some("env = cr"),
+                "```rust\npub enum MyEnvironment {}\n```\n\nMy environment.",
+                (Some("<-crate::MyEnvironment"), Some("crate::MyEnvironment")),
+            ),
+            (
+                Some("crate::My"),
+                "```rust\npub enum MyEnvironment {}\n```\n\nMy environment.",
+                (Some("<-crate::MyEnvironment"), Some("crate::MyEnvironment")),
+            ),
+            // Unresolved ink! environment paths fallback to the ink! attribute argument docs.
+            (
+                Some("crate::Unknown"),
+                content(&InkAttributeKind::Arg(InkArgKind::Env)),
+                (Some("<-env = crate::Unknown"), Some("<- = crate::Unknown")),
+            ),
+            // Integer selectors.
+            (
+                Some("0xDEAD"),
+                "`0xDEADBEEF`\n\nDecimal: `3735928559`\n\nHex: `0xdeadbeef`",
+                (Some("<-0xDEADBEEF"), Some("0xDEADBEEF")),
+            ),
+            (
+                Some("selector = 1"),
+                "`10`\n\nDecimal: `10`\n\nHex: `0x0000000a`",
+                (Some("<-10"), Some("selector = 10")),
+            ),
+            // Wildcard selectors fallback to the ink! attribute argument docs.
+            (
+                Some("selector = _"),
+                content(&InkAttributeKind::Arg(InkArgKind::Selector)),
+                (Some("<-selector = _"), Some("<- = _")),
+            ),
+            // Namespaces.
+            (
+                Some(r#""my_name"#),
+                "Namespace prefix used in selector computation \
+                (i.e. selectors are computed from `my_namespace::<callable name>`).",
+                (Some(r#"<-"my_namespace""#), Some(r#""my_namespace""#)),
+            ),
+        ] {
+            let offset = TextSize::from(parse_offset_at(code, pat).unwrap() as u32);
+
+            let result = hover(&file, TextRange::new(offset, offset));
+
+            let (range_start_pat, range_end_pat) = expected_range_pats;
+            let expected_range = TextRange::new(
+                TextSize::from(parse_offset_at(code, range_start_pat).unwrap() as u32),
+                TextSize::from(parse_offset_at(code, range_end_pat).unwrap() as u32),
+            );
+            assert_eq!(
+                result
+                    .as_ref()
+                    .map(|hover_result| (hover_result.content.as_str(), hover_result.range)),
+                Some((expected_content, expected_range)),
+                "pat: {pat:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn content_works() {
+        for (attr_kind, expected_attr) in [
+            InkArgKind::AdditionalContracts,
+            InkArgKind::Anonymous,
+            InkArgKind::Constructor,
+            InkArgKind::Default,
+            InkArgKind::Derive,
+            InkArgKind::Env,
+            InkArgKind::Environment,
+            InkArgKind::Event,
+            InkArgKind::Extension,
+            InkArgKind::HandleStatus,
+            InkArgKind::Impl,
+            InkArgKind::KeepAttr,
+            InkArgKind::Message,
+            InkArgKind::Namespace,
+            InkArgKind::Payable,
+            InkArgKind::Selector,
+            InkArgKind::Storage,
+            InkArgKind::Topic,
+        ]
+        .into_iter()
+        .map(|arg_kind| (InkAttributeKind::Arg(arg_kind), arg_kind.to_string()))
+        .chain(
+            [
+                InkMacroKind::ChainExtension,
+                InkMacroKind::Contract,
+                InkMacroKind::StorageItem,
+                InkMacroKind::Test,
+                InkMacroKind::TraitDefinition,
+                InkMacroKind::E2ETest,
+            ]
+            .into_iter()
+            .map(|macro_kind| {
+                (
+                    InkAttributeKind::Macro(macro_kind),
+                    format!("#[{}", macro_kind.path_as_str()),
+                )
+            }),
+        ) {
+            // Every known ink! attribute kind has a description that references the attribute.
+            let doc = content(&attr_kind);
+            assert!(doc.contains("# Description"), "attr: {attr_kind:?}");
+            assert!(doc.contains(&expected_attr), "attr: {attr_kind:?}");
+        }
+
+        // Unknown ink! attribute kinds have no hover content.
+        assert!(content(&InkAttributeKind::Arg(InkArgKind::Unknown)).is_empty());
+        assert!(content(&InkAttributeKind::Macro(InkMacroKind::Unknown)).is_empty());
+    }
 }
@@ -0,0 +1,89 @@
+//! ink! trait definition implementations.
+
+use ink_analyzer_ir::syntax::{AstNode, TextRange, TextSize};
+use ink_analyzer_ir::{InkEntity, InkFile, IsInkTrait};
+
+/// Returns the text ranges of all `impl` items (in the same file) that implement
+/// the ink! trait definition at the given offset (if any).
+pub fn goto_implementation(file: &InkFile, offset: TextSize) -> Vec<TextRange> {
+    let item_at_offset = file.item_at_offset(offset);
+    let Some(focused_token) = item_at_offset.focused_token() else {
+        return Vec::new();
+    };
+    let focused_range = focused_token.text_range();
+
+    file.trait_definitions()
+        .iter()
+        .find(|trait_definition| {
+            trait_definition.trait_item().is_some_and(|trait_item| {
+                trait_item
+                    .syntax()
+                    .text_range()
+                    .contains_range(focused_range)
+            })
+        })
+        .map(|trait_definition| {
+            trait_definition
+                .implementors()
+                .iter()
+                .filter_map(|ink_impl| ink_impl.impl_item())
+                .map(|impl_item| impl_item.syntax().text_range())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_utils::parse_offset_at;
+
+    #[test]
+    fn goto_implementation_works() {
+        let trait_code = test_utils::read_source_code("trait_definitions/erc20_trait");
+        let impl_start_pat = "<-impl BaseErc20 for Erc20 {";
+        let impl_end_pat = "// ink! trait definition implementation end.";
+        // Marks the end of the `impl` item (i.e. the first item after it) to simplify range assertions.
+        let code = trait_code.replacen(
+            "    #[ink(impl)]\n    impl Erc20 {",
+            &format!("    {impl_end_pat}\n    #[ink(impl)]\n    impl Erc20 {{"),
+            1,
+        );
+        assert_ne!(code, trait_code);
+        let file = InkFile::parse(&code);
+
+        let expected_range = TextRange::new(
+            TextSize::from(parse_offset_at(&code, Some(impl_start_pat)).unwrap() as u32),
+            TextSize::from(
+                parse_offset_at(&code, Some(&format!("<-\n\n    {impl_end_pat}"))).unwrap() as u32,
+            ),
+        );
+
+        for pat in [
+            // ink! trait definition attribute.
+            "<-#[ink::trait_definition]",
+            // ink! trait definition name.
+            "pub trait Base",
+            // ink! trait definition message.
+            "fn total_supply(&self) -> Balance;",
+        ] {
+            let offset = TextSize::from(parse_offset_at(&code, Some(pat)).unwrap() as u32);
+            assert_eq!(
+                goto_implementation(&file, offset),
+                vec![expected_range],
+                "pat: {pat}"
+            );
+        }
+
+        // Not an ink! trait definition.
+        let offset =
+            TextSize::from(parse_offset_at(&code, Some("<-impl BaseErc20")).unwrap() as u32);
+        assert!(goto_implementation(&file, offset).is_empty());
+
+        // No implementations.
+        let code = trait_code.replace("impl BaseErc20 for Erc20 {", "impl Erc20 {");
+        let file = InkFile::parse(&code);
+        let offset = TextSize::from(parse_offset_at(&code, Some("pub trait Base")).unwrap() as u32);
+        assert!(goto_implementation(&file, offset).is_empty());
+    }
+}
@@ -1,7 +1,11 @@
-//! ink! attribute argument inlay hints.
+//! ink! attribute argument and selector inlay hints.
 
-use ink_analyzer_ir::syntax::{AstToken, TextRange, TextSize};
-use ink_analyzer_ir::{InkArgValueKind, InkEntity, InkFile};
+use ink_analyzer_ir::ast::HasName;
+use ink_analyzer_ir::syntax::{AstNode, AstToken, TextRange, TextSize};
+use ink_analyzer_ir::{
+    Constructor, HasInkImplParent, InkArgKind, InkArgValueKind, InkAttribute, InkAttributeKind,
+    InkEntity, InkFile, IsInkCallable, IsInkFn, Message,
+};
 
 /// An ink! attribute argument inlay hint.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -14,6 +18,17 @@ pub struct InlayHint {
     pub range: TextRange,
     /// Extra details about the inlay hint.
     pub detail: Option<String>,
+    /// Kind of the inlay hint.
+    pub kind: InlayHintKind,
+}
+
+/// The kind of an ink! inlay hint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InlayHintKind {
+    /// Type of an ink! attribute argument value.
+    Type,
+    /// Selector of an ink! constructor or ink! message.
+    Selector,
 }
 
 /// Computes ink! attribute argument inlay hints for the given text range (if any).
@@ -46,14 +61,81 @@ pub fn inlay_hints(file: &InkFile, range: Option<TextRange>) -> Vec<InlayHint> {
                                 .name()
                                 .map_or(arg.text_range(), |name| name.syntax().text_range()),
                             detail: (!doc.is_empty()).then_some(doc.to_string()),
+                            kind: InlayHintKind::Type,
                         })
                     })?
                 })
+                .chain(selector_hint(&attr, range))
                 .collect::<Vec<InlayHint>>()
         })
         .collect()
 }
 
+/// Returns an inlay hint for the computed selector of the ink! constructor or ink! message
+/// annotated by the ink! attribute (if any).
+///
+/// Hints are only shown for ink! callables in `impl` blocks without an explicit integer selector.
+fn selector_hint(attr: &InkAttribute, range: Option<TextRange>) -> Option<InlayHint> {
+    let (selector_arg, computed_selector, fn_item) = match attr.kind() {
+        InkAttributeKind::Arg(InkArgKind::Constructor) => {
+            let constructor = Constructor::cast(attr.syntax().clone())?;
+            constructor.parent_impl_item()?;
+            (
+                constructor.selector_arg(),
+                constructor.computed_selector(),
+                constructor.fn_item()?.clone(),
+            )
+        }
+        InkAttributeKind::Arg(InkArgKind::Message) => {
+            let message = Message::cast(attr.syntax().clone())?;
+            message.parent_impl_item()?;
+            (
+                message.selector_arg(),
+                message.computed_selector(),
+                message.fn_item()?.clone(),
+            )
+        }
+        _ => return None,
+    };
+
+    let (value, detail) = match selector_arg {
+        // Explicit integer selectors don't need a hint.
+        Some(selector_arg) if !selector_arg.is_wildcard() => return None,
+        Some(_) => ("wildcard".to_string(), "wildcard selector"),
+        None => (
+            ink_analyzer_ir::format_selector(u32::from_be_bytes(computed_selector?)),
+            "computed selector",
+        ),
+    };
+
+    // The hint is positioned at the end of the `fn` signature.
+    let position = fn_item
+        .ret_type()
+        .map(|ret_type| ret_type.syntax().text_range().end())
+        .or(fn_item
+            .param_list()
+            .map(|param_list| param_list.syntax().text_range().end()))?;
+    let hint_range = TextRange::new(
+        fn_item
+            .name()
+            .map_or(fn_item.syntax().text_range().start(), |name| {
+                name.syntax().text_range().start()
+            }),
+        position,
+    );
+
+    // Filters out ink! callables that aren't in the selection range.
+    range
+        .map_or(true, |range| range.contains_range(hint_range))
+        .then_some(InlayHint {
+            label: format!("/* selector: {value} */"),
+            position,
+            range: hint_range,
+            detail: Some(detail.to_string()),
+            kind: InlayHintKind::Selector,
+        })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -228,6 +310,124 @@ mod tests {
                     ),
                 ],
             ),
+            // ink! constructor and ink! message selectors.
+            (
+                r#"
+                    impl MyContract {
+                        #[ink(constructor)]
+                        pub fn my_constructor() -> Self {}
+
+                        #[ink(message)]
+                        pub fn my_message(&self) {}
+                    }
+                "#,
+                None,
+                vec![
+                    (
+                        "/* selector: 0xe11c2faf */",
+                        Some("my_constructor() -> Self"),
+                        (Some("<-my_constructor"), Some("my_constructor() -> Self")),
+                    ),
+                    (
+                        "/* selector: 0x6a469e03 */",
+                        Some("my_message(&self)"),
+                        (Some("<-my_message"), Some("my_message(&self)")),
+                    ),
+                ],
+            ),
+            (
+                r#"
+                    #[ink(namespace="my_namespace")]
+                    impl MyContract {
+                        #[ink(message)]
+                        pub fn my_message(&self) {}
+                    }
+                "#,
+                None,
+                vec![
+                    (
+                        "&str",
+                        Some("namespace"),
+                        (Some("<-namespace"), Some("namespace")),
+                    ),
+                    (
+                        "/* selector: 0xabe89c04 */",
+                        Some("my_message(&self)"),
+                        (Some("<-my_message"), Some("my_message(&self)")),
+                    ),
+                ],
+            ),
+            (
+                r#"
+                    impl MyTrait for MyContract {
+                        #[ink(message)]
+                        fn my_message(&self) {}
+                    }
+                "#,
+                None,
+                vec![(
+                    "/* selector: 0x04c49446 */",
+                    Some("my_message(&self)"),
+                    (Some("<-my_message"), Some("my_message(&self)")),
+                )],
+            ),
+            (
+                r#"
+                    impl MyContract {
+                        #[ink(constructor, selector=1)]
+                        pub fn my_constructor() -> Self {}
+
+                        #[ink(message, selector=_)]
+                        pub fn my_message(&self) {}
+                    }
+                "#,
+                None,
+                vec![
+                    (
+                        "u32 | _",
+                        Some("<-=1"),
+                        (Some("<-selector=1"), Some("<-=1")),
+                    ),
+                    (
+                        "u32 | _",
+                        Some("<-=_"),
+                        (Some("<-selector=_"), Some("<-=_")),
+                    ),
+                    (
+                        "/* selector: wildcard */",
+                        Some("my_message(&self)"),
+                        (Some("<-my_message"), Some("my_message(&self)")),
+                    ),
+                ],
+            ),
+            (
+                r#"
+                    impl MyContract {
+                        #[ink(constructor)]
+                        pub fn my_constructor() -> Self {}
+
+                        #[ink(message)]
+                        pub fn my_message(&self) {}
+                    }
+                "#,
+                Some((Some("<-#[ink(message)]"), Some("->"))),
+                vec![(
+                    "/* selector: 0x6a469e03 */",
+                    Some("my_message(&self)"),
+                    (Some("<-my_message"), Some("my_message(&self)")),
+                )],
+            ),
+            (
+                r#"
+                    #[ink::trait_definition]
+                    pub trait MyTrait {
+                        #[ink(message)]
+                        fn my_message(&self);
+                    }
+                "#,
+                None,
+                vec![],
+            ),
         ] {
             let range = selection_range_pat.map(|(pat_start, pat_end)| {
                 TextRange::new(
@@ -0,0 +1,200 @@
+//! ink! entity linked editing ranges.
+
+use ink_analyzer_ir::ast::HasName;
+use ink_analyzer_ir::syntax::{AstNode, SyntaxKind, TextRange, TextSize};
+use ink_analyzer_ir::{ast, Contract, InkEntity, InkFile, IsInkFn, IsInkStruct};
+
+/// Returns the text ranges of all names that should be edited simultaneously (if any)
+/// with the name at the given offset.
+///
+/// **NOTE**: Linked editing ranges must have identical text, so the ink! contract `mod` name
+/// is only linked with the ink! storage `struct` name and `impl` self types if their text matches.
+pub fn linked_editing_ranges(file: &InkFile, offset: TextSize) -> Option<Vec<TextRange>> {
+    let item_at_offset = file.item_at_offset(offset);
+    let focused_token = item_at_offset.focused_token()?;
+    if focused_token.kind() != SyntaxKind::IDENT {
+        return None;
+    }
+    let focused_range = focused_token.text_range();
+
+    file.contracts()
+        .iter()
+        .filter(|contract| contract.syntax().text_range().contains_range(focused_range))
+        .find_map(|contract| {
+            let module_name = contract.module().and_then(HasName::name);
+            let storage_names = storage_name_ranges(contract);
+            let is_module_name = module_name
+                .as_ref()
+                .is_some_and(|name| name.syntax().text_range() == focused_range);
+            let is_storage_name = storage_names
+                .iter()
+                .any(|(_, range)| *range == focused_range);
+            if is_module_name || is_storage_name {
+                // ink! contract `mod` name, ink! storage `struct` name or `impl` self type.
+                let focused_text = focused_token.text();
+                Some(
+                    module_name
+                        .iter()
+                        .map(|name| (name.to_string(), name.syntax().text_range()))
+                        .chain(storage_names)
+                        .filter_map(|(text, range)| (text == focused_text).then_some(range))
+                        .collect(),
+                )
+            } else {
+                // ink! constructor and ink! message names aren't referenced by name elsewhere.
+                contract
+                    .constructors()
+                    .iter()
+                    .filter_map(IsInkFn::fn_item)
+                    .chain(contract.messages().iter().filter_map(IsInkFn::fn_item))
+                    .filter_map(HasName::name)
+                    .find(|name| name.syntax().text_range() == focused_range)
+                    .map(|name| vec![name.syntax().text_range()])
+            }
+        })
+}
+
+/// Returns the text and text ranges of the ink! storage `struct` name and
+/// all `impl` self types that reference it by name for the ink! contract.
+fn storage_name_ranges(contract: &Contract) -> Vec<(String, TextRange)> {
+    let Some(storage_name) = contract
+        .storage()
+        .and_then(IsInkStruct::struct_item)
+        .and_then(HasName::name)
+    else {
+        return Vec::new();
+    };
+    let storage_name_text = storage_name.to_string();
+    let impl_self_ty_ranges = contract
+        .syntax()
+        .descendants()
+        .filter_map(ast::Impl::cast)
+        .filter_map(|impl_item| match impl_item.self_ty()? {
+            ast::Type::PathType(path_type) => path_type.path()?.segment()?.name_ref(),
+            _ => None,
+        })
+        .filter(|name_ref| name_ref.text() == storage_name_text)
+        .map(|name_ref| name_ref.syntax().text_range());
+
+    [storage_name.syntax().text_range()]
+        .into_iter()
+        .chain(impl_self_ty_ranges)
+        .map(|range| (storage_name_text.clone(), range))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_utils::parse_offset_at;
+
+    #[test]
+    fn linked_editing_ranges_works() {
+        let code = test_utils::read_source_code("contracts/flipper");
+        let file = InkFile::parse(&code);
+
+        for (pat, expected_ranges) in [
+            // ink! storage `struct` name.
+            (
+                Some("pub struct Flip"),
+                Some(vec![
+                    (
+                        Some("<-Flipper {\n        value"),
+                        Some("pub struct Flipper"),
+                    ),
+                    (
+                        Some("<-Flipper {\n        /// Creates"),
+                        Some("impl Flipper"),
+                    ),
+                ]),
+            ),
+            // `impl` self type.
+            (
+                Some("impl Flip"),
+                Some(vec![
+                    (
+                        Some("<-Flipper {\n        value"),
+                        Some("pub struct Flipper"),
+                    ),
+                    (
+                        Some("<-Flipper {\n        /// Creates"),
+                        Some("impl Flipper"),
+                    ),
+                ]),
+            ),
+            // ink! contract `mod` name (doesn't match the ink! storage `struct` name).
+            (
+                Some("pub mod flip"),
+                Some(vec![(Some("<-flipper {"), Some("pub mod flipper"))]),
+            ),
+            // ink! message name.
+            (
+                Some("pub fn fl"),
+                Some(vec![(Some("<-flip(&mut self)"), Some("pub fn flip"))]),
+            ),
+            // ink! constructor name.
+            (
+                Some("pub fn new_def"),
+                Some(vec![(
+                    Some("<-new_default() -> Self"),
+                    Some("pub fn new_default"),
+                )]),
+            ),
+            // Not an ink! entity name.
+            (Some("<-init_value: bool"), None),
+            (Some("<-pub mod"), None),
+        ] {
+            let offset = TextSize::from(parse_offset_at(&code, pat).unwrap() as u32);
+            let results = linked_editing_ranges(&file, offset);
+
+            assert_eq!(
+                results,
+                expected_ranges.map(|ranges| ranges
+                    .into_iter()
+                    .map(|(start_pat, end_pat)| TextRange::new(
+                        TextSize::from(parse_offset_at(&code, start_pat).unwrap() as u32),
+                        TextSize::from(parse_offset_at(&code, end_pat).unwrap() as u32),
+                    ))
+                    .collect::<Vec<TextRange>>()),
+                "pat: {pat:?}"
+            );
+        }
+
+        // ink! contract `mod` name that matches the ink! storage `struct` name.
+        let code = r#"
+            #[ink::contract]
+            mod Flipper {
+                #[ink(storage)]
+                pub struct Flipper {}
+
+                impl Flipper {}
+            }
+        "#;
+        let file = InkFile::parse(code);
+        let expected_ranges: Vec<TextRange> = [
+            (
+                "<-Flipper {\n                #[ink(storage)]",
+                "mod Flipper",
+            ),
+            ("<-Flipper {}\n\n", "pub struct Flipper"),
+            ("<-Flipper {}\n            }", "impl Flipper"),
+        ]
+        .into_iter()
+        .map(|(start_pat, end_pat)| {
+            TextRange::new(
+                TextSize::from(parse_offset_at(code, Some(start_pat)).unwrap() as u32),
+                TextSize::from(parse_offset_at(code, Some(end_pat)).unwrap() as u32),
+            )
+        })
+        .collect();
+        // Results are the same regardless of which of the linked names is focused.
+        for pat in ["mod Flip", "pub struct Flip", "impl Flip"] {
+            let offset = TextSize::from(parse_offset_at(code, Some(pat)).unwrap() as u32);
+            assert_eq!(
+                linked_editing_ranges(&file, offset),
+                Some(expected_ranges.clone()),
+                "pat: {pat}"
+            );
+        }
+    }
+}
@@ -0,0 +1,171 @@
+//! ink! on type formatting.
+
+use ink_analyzer_ir::syntax::{SyntaxKind, SyntaxNode, TextRange, TextSize};
+use ink_analyzer_ir::{ChainExtension, Contract, InkEntity, InkFile, TraitDefinition};
+
+use super::text_edit::TextEdit;
+use super::utils;
+
+/// Computes formatting edits after the given character is typed at the given offset
+/// (i.e. the offset right after the typed character).
+///
+/// Currently, only new lines (i.e. `\n`) typed right after the opening curly brace (i.e. `{`)
+/// of an ink! contract `mod`, ink! chain extension `trait` or ink! trait definition `trait` body
+/// are handled, by indenting the new line one level deeper than the ink! entity
+/// (and moving the closing curly brace to the next line if it directly follows the new line).
+pub fn on_type_formatting(file: &InkFile, offset: TextSize, typed_char: char) -> Vec<TextEdit> {
+    if typed_char != '\n' {
+        return Vec::new();
+    }
+
+    // Only whitespace tokens that contain the new line are considered.
+    let Some(whitespace) = file
+        .syntax()
+        .token_at_offset(offset)
+        .left_biased()
+        .filter(|token| token.kind() == SyntaxKind::WHITESPACE)
+    else {
+        return Vec::new();
+    };
+    let ws_start = whitespace.text_range().start();
+    let ws_text = whitespace.text();
+    let (before, after) = ws_text.split_at(usize::from(offset - ws_start));
+    // The new line must be the first (and only) one after the opening curly brace.
+    if !before.ends_with('\n') || before.matches('\n').count() != 1 {
+        return Vec::new();
+    }
+
+    // Only the bodies of ink! contracts, ink! chain extensions and ink! trait definitions are handled.
+    let Some(ink_item) = whitespace
+        .prev_token()
+        .filter(|token| token.kind() == SyntaxKind::L_CURLY)
+        .and_then(|l_curly| l_curly.parent())
+        .filter(|list| {
+            matches!(
+                list.kind(),
+                SyntaxKind::ITEM_LIST | SyntaxKind::ASSOC_ITEM_LIST
+            )
+        })
+        .and_then(|list| list.parent())
+        .filter(is_ink_block_item)
+    else {
+        return Vec::new();
+    };
+
+    // Replaces the current indenting of the new line (if any) with the expected indenting,
+    // and moves the closing curly brace (if it's on the same line) to the next line.
+    let item_indent = utils::item_indenting(&ink_item).unwrap_or_default();
+    let indent = format!("{item_indent}    ");
+    let current_indent: String = after
+        .chars()
+        .take_while(|char| *char != '\n' && char.is_whitespace())
+        .collect();
+    let range = TextRange::new(offset, offset + TextSize::of(current_indent.as_str()));
+    if !after.contains('\n') {
+        // The rest of the line isn't empty (e.g. it contains the closing curly brace).
+        vec![TextEdit::replace(format!("{indent}\n{item_indent}"), range)]
+    } else if current_indent != indent {
+        vec![TextEdit::replace(indent, range)]
+    } else {
+        Vec::new()
+    }
+}
+
+/// Returns true if the syntax node is an ink! contract, ink! chain extension or ink! trait definition.
+fn is_ink_block_item(node: &SyntaxNode) -> bool {
+    Contract::can_cast(node) || ChainExtension::can_cast(node) || TraitDefinition::can_cast(node)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_utils::parse_offset_at;
+
+    #[test]
+    fn on_type_formatting_works() {
+        for (code, pat, expected_result) in [
+            // (code, pat, Option<(text, pat_start, pat_end)>) where:
+            // code = source code (i.e. after the new line is typed),
+            // pat = substring used to find the offset right after the typed new line (see `test_utils::parse_offset_at` doc),
+            // text = the replacement text for the text edit,
+            // pat_start = substring used to find the start of the text edit range (see `test_utils::parse_offset_at` doc),
+            // pat_end = substring used to find the end of the text edit range (see `test_utils::parse_offset_at` doc).
+            (
+                "#[ink::contract]\nmod my_contract {\n}",
+                "{\n",
+                Some(("    \n", "{\n", "{\n")),
+            ),
+            (
+                "#[ink::contract]\nmod my_contract {\n  \n}",
+                "{\n",
+                Some(("    ", "{\n", "{\n  ")),
+            ),
+            (
+                "#[ink::contract]\nmod my_contract {\n    \n}",
+                "{\n",
+                None,
+            ),
+            (
+                "mod outer {\n    #[ink::contract]\n    mod my_contract {\n    }\n}",
+                "my_contract {\n",
+                Some((
+                    "        \n    ",
+                    "my_contract {\n",
+                    "my_contract {\n    ",
+                )),
+            ),
+            (
+                "mod a {\n    mod b {\n        #[ink::contract]\n        mod my_contract {\n        }\n    }\n}",
+                "my_contract {\n",
+                Some((
+                    "            \n        ",
+                    "my_contract {\n",
+                    "my_contract {\n        ",
+                )),
+            ),
+            (
+                "#[ink::chain_extension]\npub trait MyChainExtension {\n}",
+                "{\n",
+                Some(("    \n", "{\n", "{\n")),
+            ),
+            (
+                "mod my_mod {\n    #[ink::trait_definition]\n    pub trait MyTrait {\n    }\n}",
+                "MyTrait {\n",
+                Some(("        \n    ", "MyTrait {\n", "MyTrait {\n    ")),
+            ),
+            // Ignored.
+            ("mod my_mod {\n}", "{\n", None),
+            ("#[ink::contract]\nmod my_contract {\n\n}", "{\n\n", None),
+            (
+                "#[ink::contract]\nmod my_contract {\n    #[ink(storage)]\n    pub struct MyContract {\n    }\n}",
+                "MyContract {\n",
+                None,
+            ),
+        ] {
+            let file = InkFile::parse(code);
+            let offset = TextSize::from(parse_offset_at(code, Some(pat)).unwrap() as u32);
+
+            let results = on_type_formatting(&file, offset, '\n');
+            assert_eq!(
+                results
+                    .iter()
+                    .map(|edit| (edit.text.as_str(), edit.range))
+                    .collect::<Vec<_>>(),
+                expected_result
+                    .into_iter()
+                    .map(|(text, start_pat, end_pat)| (
+                        text,
+                        TextRange::new(
+                            TextSize::from(parse_offset_at(code, Some(start_pat)).unwrap() as u32),
+                            TextSize::from(parse_offset_at(code, Some(end_pat)).unwrap() as u32),
+                        )
+                    ))
+                    .collect::<Vec<_>>(),
+                "code: {code}"
+            );
+
+            // Other characters are ignored.
+            assert!(on_type_formatting(&file, offset, '{').is_empty());
+        }
+    }
+}
@@ -0,0 +1,515 @@
+//! ink! entity renames.
+
+use ink_analyzer_ir::ast::HasName;
+use ink_analyzer_ir::syntax::{AstNode, SyntaxKind, SyntaxNode, TextRange, TextSize};
+use ink_analyzer_ir::{
+    ast, Constructor, Contract, Event, HasInkImplParent, InkEntity, InkFile, IsInkFn, IsInkStruct,
+    Message, TraitDefinition,
+};
+
+use crate::analysis::utils as analysis_utils;
+use crate::{utils, TextEdit};
+
+/// The result of a pre-rename check (i.e. the range and current text of the name to rename).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PrepareRenameResult {
+    /// Range of the name to rename.
+    pub range: TextRange,
+    /// Current text of the name to rename.
+    pub placeholder: String,
+}
+
+/// Returns the range and current text of the name at the given offset (if any)
+/// if the name can be renamed.
+///
+/// Only names of ink! contract `mod`s, ink! messages, ink! constructors, ink! events
+/// and ink! trait definitions can be renamed (i.e. ink! attributes themselves can't be renamed).
+pub fn prepare_rename(file: &InkFile, offset: TextSize) -> Option<PrepareRenameResult> {
+    let item_at_offset = file.item_at_offset(offset);
+    let focused_token = item_at_offset.focused_token()?;
+    if focused_token.kind() != SyntaxKind::IDENT {
+        return None;
+    }
+
+    let name = focused_token.parent().and_then(ast::Name::cast)?;
+    let item = name.syntax().parent()?;
+    let is_renameable = match item.kind() {
+        SyntaxKind::MODULE => Contract::can_cast(&item),
+        SyntaxKind::FN => Message::can_cast(&item) || Constructor::can_cast(&item),
+        SyntaxKind::STRUCT => Event::can_cast(&item),
+        SyntaxKind::TRAIT => TraitDefinition::can_cast(&item),
+        _ => false,
+    };
+    is_renameable.then(|| PrepareRenameResult {
+        range: focused_token.text_range(),
+        placeholder: focused_token.text().to_string(),
+    })
+}
+
+/// Returns the text edits (sorted by descending offset) for renaming the ink! entity
+/// whose name is at the given offset (if any) to the new name.
+///
+/// In addition to the name of the ink! entity, references in the same file are also renamed, i.e.
+/// - for ink! contract `mod`s: paths to the `mod` (e.g. in `use` items),
+///   and the ink! storage `struct` (and its references) if its name is the pascal case equivalent
+///   of the `mod` name (e.g. `MyContract` for `my_contract`).
+/// - for ink! messages and ink! constructors: calls via `self.<name>(..)`, `Self::<name>(..)`
+///   and `<StorageName>::<name>(..)` in the ink! contract, and equivalent ink! messages in
+///   the implemented ink! trait definition and/or its implementations.
+/// - for ink! events and ink! trait definitions: paths that resolve to the `struct` or `trait` item.
+///
+/// Returns an empty list if the name at the offset can't be renamed or the new name isn't a valid identifier.
+pub fn rename(file: &InkFile, offset: TextSize, new_name: &str) -> Vec<TextEdit> {
+    let Some(prepare_result) = prepare_rename(file, offset) else {
+        return Vec::new();
+    };
+    if !is_valid_ident(new_name) || new_name == prepare_result.placeholder {
+        return Vec::new();
+    }
+    let old_name = prepare_result.placeholder.as_str();
+    let Some(item) = file
+        .syntax()
+        .covering_element(prepare_result.range)
+        .ancestors()
+        .find(|node| ast::Name::can_cast(node.kind()))
+        .and_then(|name| name.parent())
+    else {
+        return Vec::new();
+    };
+
+    let mut edits: Vec<(TextRange, String)> = vec![(prepare_result.range, new_name.to_string())];
+    let mut add_edits = |ranges: Vec<TextRange>, text: &str| {
+        edits.extend(ranges.into_iter().map(|range| (range, text.to_string())));
+    };
+    if let Some(contract) = Contract::cast(item.clone()) {
+        add_edits(
+            path_references::<ast::Module>(file, &item, old_name),
+            new_name,
+        );
+
+        // Renames the ink! storage `struct` if it follows the naming convention.
+        let old_storage_name = utils::pascal_case(old_name);
+        let new_storage_name = utils::pascal_case(new_name);
+        let storage_name = contract
+            .storage()
+            .and_then(IsInkStruct::struct_item)
+            .and_then(HasName::name);
+        if let Some(storage_name) = storage_name {
+            if storage_name.to_string() == old_storage_name && old_storage_name != new_storage_name
+            {
+                let struct_item = storage_name.syntax().parent();
+                add_edits(
+                    std::iter::once(storage_name.syntax().text_range())
+                        .chain(struct_item.iter().flat_map(|struct_item| {
+                            path_references::<ast::Struct>(file, struct_item, &old_storage_name)
+                        }))
+                        .collect(),
+                    &new_storage_name,
+                );
+            }
+        }
+    } else if let Some(message) = Message::cast(item.clone()) {
+        let mut fn_items: Vec<ast::Fn> = Vec::new();
+        if let Some(fn_item) = message.fn_item() {
+            fn_items.push(fn_item.clone());
+        }
+        // Equivalent ink! messages in the ink! trait definition and/or its implementations.
+        let trait_definition = ink_analyzer_ir::ink_parent::<TraitDefinition>(message.syntax())
+            .or_else(|| {
+                message
+                    .parent_impl_item()
+                    .and_then(|impl_item| {
+                        ink_analyzer_ir::InkImpl::cast(impl_item.syntax().clone())
+                    })
+                    .and_then(|ink_impl| ink_impl.trait_definition())
+            });
+        if let Some(trait_definition) = trait_definition {
+            let declarations = trait_definition
+                .messages()
+                .iter()
+                .filter_map(|message| message.fn_item().cloned());
+            let implementations =
+                trait_definition
+                    .implementors()
+                    .into_iter()
+                    .flat_map(|ink_impl| {
+                        ink_impl
+                            .impl_item()
+                            .and_then(ast::Impl::assoc_item_list)
+                            .into_iter()
+                            .flat_map(|item_list| item_list.assoc_items())
+                            .filter_map(|item| match item {
+                                ast::AssocItem::Fn(fn_item) => Some(fn_item),
+                                _ => None,
+                            })
+                            .collect::<Vec<ast::Fn>>()
+                    });
+            for fn_item in declarations.chain(implementations) {
+                let is_equivalent = fn_item
+                    .name()
+                    .is_some_and(|name| name.to_string() == old_name);
+                if is_equivalent && !fn_items.contains(&fn_item) {
+                    fn_items.push(fn_item);
+                }
+            }
+        }
+        for fn_item in &fn_items {
+            add_edits(
+                fn_item
+                    .name()
+                    .map(|name| name.syntax().text_range())
+                    .into_iter()
+                    .chain(
+                        ink_analyzer_ir::ink_ancestor_of_kind::<Contract>(fn_item.syntax())
+                            .map(|contract| callable_references(&contract, old_name))
+                            .unwrap_or_default(),
+                    )
+                    .collect(),
+                new_name,
+            );
+        }
+    } else if Constructor::can_cast(&item) {
+        add_edits(
+            ink_analyzer_ir::ink_ancestor_of_kind::<Contract>(&item)
+                .map(|contract| callable_references(&contract, old_name))
+                .unwrap_or_default(),
+            new_name,
+        );
+    } else if Event::can_cast(&item) {
+        add_edits(
+            path_references::<ast::Struct>(file, &item, old_name),
+            new_name,
+        );
+    } else if TraitDefinition::can_cast(&item) {
+        add_edits(
+            path_references::<ast::Trait>(file, &item, old_name),
+            new_name,
+        );
+    }
+
+    // Sorts edits by descending offset (and removes duplicates).
+    edits.sort_by(|(a, _), (b, _)| b.start().cmp(&a.start()));
+    edits.dedup_by_key(|(range, _)| *range);
+    edits
+        .into_iter()
+        .map(|(range, text)| TextEdit::replace(text, range))
+        .collect()
+}
+
+/// Returns the text ranges of the last segments of all paths in the file
+/// that resolve to the target item.
+fn path_references<T>(file: &InkFile, target: &SyntaxNode, name: &str) -> Vec<TextRange>
+where
+    T: AstNode + HasName,
+{
+    file.syntax()
+        .descendants()
+        .filter_map(ast::Path::cast)
+        .filter_map(|path| {
+            let name_ref = path.segment()?.name_ref()?;
+            (name_ref.to_string() == name
+                && ink_analyzer_ir::resolve_item::<T>(&path, path.syntax())
+                    .is_some_and(|item| item.syntax() == target))
+            .then(|| name_ref.syntax().text_range())
+        })
+        .collect()
+}
+
+/// Returns the text ranges of the names of calls to an ink! callable in the ink! contract
+/// (see [`analysis_utils::callable_references`]).
+fn callable_references(contract: &Contract, name: &str) -> Vec<TextRange> {
+    analysis_utils::callable_references(contract, name)
+        .iter()
+        .map(|name_ref| name_ref.syntax().text_range())
+        .collect()
+}
+
+/// Returns true if the text is a valid (non-keyword) Rust identifier.
+fn is_valid_ident(text: &str) -> bool {
+    let mut chars = text.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+        && text != "_"
+        && SyntaxKind::from_keyword(text).is_none()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_utils::parse_offset_at;
+
+    #[test]
+    fn prepare_rename_works() {
+        let code = r#"
+            #[ink::trait_definition]
+            pub trait MyTrait {
+                #[ink(message)]
+                fn my_trait_message(&self);
+            }
+
+            #[ink::contract]
+            mod my_contract {
+                #[ink(storage)]
+                pub struct MyContract {}
+
+                #[ink(event)]
+                pub struct MyEvent {}
+
+                pub struct NotAnEvent {}
+
+                impl MyContract {
+                    #[ink(constructor)]
+                    pub fn new() -> Self {}
+
+                    #[ink(message)]
+                    pub fn my_message(&self) {}
+
+                    pub fn my_helper(&self) {}
+                }
+            }
+
+            mod not_a_contract {}
+        "#;
+        let file = InkFile::parse(code);
+
+        for (pat, expected_name) in [
+            // ink! contract `mod` name.
+            ("mod my_con", Some("my_contract")),
+            // ink! message names.
+            ("pub fn my_mes", Some("my_message")),
+            ("fn my_trait_mes", Some("my_trait_message")),
+            // ink! constructor name.
+            ("pub fn ne", Some("new")),
+            // ink! event name.
+            ("pub struct MyEv", Some("MyEvent")),
+            // ink! trait definition name.
+            ("pub trait MyTr", Some("MyTrait")),
+            // ink! attribute keywords.
+            ("#[ink::con", None),
+            ("#[ink(mes", None),
+            ("#[ink(eve", None),
+            ("#[ink::trait_def", None),
+            // Keywords and types.
+            ("pub f", None),
+            ("-> Se", None),
+            // Non-ink! entities.
+            ("pub struct NotAn", None),
+            ("pub fn my_hel", None),
+            ("mod not_a_con", None),
+        ] {
+            let offset = TextSize::from(parse_offset_at(code, Some(pat)).unwrap() as u32);
+            let result = prepare_rename(&file, offset);
+
+            assert_eq!(
+                result.as_ref().map(|it| it.placeholder.as_str()),
+                expected_name,
+                "pat: {pat}"
+            );
+            if let Some(result) = result {
+                assert_eq!(&code[result.range], result.placeholder, "pat: {pat}");
+                assert!(result.range.contains_inclusive(offset), "pat: {pat}");
+            }
+        }
+    }
+
+    // Applies the edits (in order) to the code.
+    fn apply_edits(code: &str, edits: &[TextEdit]) -> String {
+        let mut result = code.to_string();
+        for edit in edits {
+            result.replace_range(
+                usize::from(edit.range.start())..usize::from(edit.range.end()),
+                &edit.text,
+            );
+        }
+        result
+    }
+
+    #[test]
+    fn rename_works() {
+        let code = r#"
+            #[ink::trait_definition]
+            pub trait MyTrait {
+                #[ink(message)]
+                fn my_trait_message(&self);
+            }
+
+            #[ink::contract]
+            mod my_contract {
+                use super::MyTrait;
+
+                #[ink(storage)]
+                pub struct MyContract {}
+
+                #[ink(event)]
+                pub struct MyEvent {}
+
+                impl MyContract {
+                    #[ink(constructor)]
+                    pub fn new() -> Self {
+                        Self {}
+                    }
+
+                    #[ink(constructor)]
+                    pub fn default() -> Self {
+                        Self::new()
+                    }
+
+                    #[ink(message)]
+                    pub fn my_message(&self) {
+                        self.helper();
+                        self.env().emit_event(MyEvent {});
+                    }
+
+                    pub fn helper(&self) {
+                        self.my_message();
+                        MyContract::my_message(self);
+                    }
+                }
+
+                impl MyTrait for MyContract {
+                    #[ink(message)]
+                    fn my_trait_message(&self) {}
+                }
+
+                #[cfg(test)]
+                mod tests {
+                    use super::*;
+
+                    #[ink::test]
+                    fn it_works() {
+                        let contract = MyContract::new();
+                        contract.my_message();
+                    }
+                }
+            }
+
+            use my_contract::MyContract;
+        "#;
+        let file = InkFile::parse(code);
+
+        for (pat, new_name, expected_n_edits, expected_replacements) in [
+            // ink! contract `mod` (including the ink! storage `struct` naming convention).
+            (
+                "mod my_con",
+                "flipper",
+                // `mod` name, `use` path, storage name, 2 `impl` self types, `MyContract::` path (x2), `use` path.
+                8,
+                vec![
+                    ("mod my_contract", "mod flipper"),
+                    ("use my_contract::MyContract;", "use flipper::Flipper;"),
+                    ("pub struct MyContract", "pub struct Flipper"),
+                    ("impl MyContract {", "impl Flipper {"),
+                    ("impl MyTrait for MyContract", "impl MyTrait for Flipper"),
+                ],
+            ),
+            // ink! constructor.
+            (
+                "pub fn ne",
+                "create",
+                // Declaration, `Self::new()` and `MyContract::new()` calls.
+                3,
+                vec![
+                    ("pub fn new()", "pub fn create()"),
+                    ("Self::new()", "Self::create()"),
+                    ("MyContract::new()", "MyContract::create()"),
+                ],
+            ),
+            // ink! message.
+            (
+                "pub fn my_mes",
+                "get",
+                // Declaration, `self.my_message()` and `MyContract::my_message` calls.
+                3,
+                vec![
+                    ("pub fn my_message(&self)", "pub fn get(&self)"),
+                    ("self.my_message();", "self.get();"),
+                    ("MyContract::my_message(self)", "MyContract::get(self)"),
+                ],
+            ),
+            // ink! trait definition message (from the declaration).
+            (
+                "fn my_trait_mes",
+                "flip",
+                // Declaration and implementation.
+                2,
+                vec![
+                    ("fn my_trait_message(&self);", "fn flip(&self);"),
+                    ("fn my_trait_message(&self) {}", "fn flip(&self) {}"),
+                ],
+            ),
+            // ink! trait definition message (from the implementation).
+            (
+                "<-my_trait_message(&self) {",
+                "flip",
+                2,
+                vec![
+                    ("fn my_trait_message(&self);", "fn flip(&self);"),
+                    ("fn my_trait_message(&self) {}", "fn flip(&self) {}"),
+                ],
+            ),
+            // ink! event.
+            (
+                "pub struct MyEv",
+                "Flipped",
+                // Declaration and struct expression.
+                2,
+                vec![
+                    ("pub struct MyEvent {}", "pub struct Flipped {}"),
+                    ("emit_event(MyEvent {})", "emit_event(Flipped {})"),
+                ],
+            ),
+            // ink! trait definition.
+            (
+                "pub trait MyTr",
+                "Flip",
+                // Declaration, `use` path and `impl` trait path.
+                3,
+                vec![
+                    ("pub trait MyTrait", "pub trait Flip"),
+                    ("use super::MyTrait;", "use super::Flip;"),
+                    ("impl MyTrait for", "impl Flip for"),
+                ],
+            ),
+        ] {
+            let offset = TextSize::from(parse_offset_at(code, Some(pat)).unwrap() as u32);
+            let edits = rename(&file, offset, new_name);
+
+            assert_eq!(edits.len(), expected_n_edits, "pat: {pat}");
+            // Edits are sorted by descending offset (and don't overlap).
+            assert!(
+                edits
+                    .windows(2)
+                    .all(|pair| pair[1].range.end() <= pair[0].range.start()),
+                "pat: {pat}"
+            );
+            let new_code = apply_edits(code, &edits);
+            for (old_text, new_text) in expected_replacements {
+                assert!(!new_code.contains(old_text), "pat: {pat}, text: {old_text}");
+                assert!(new_code.contains(new_text), "pat: {pat}, text: {new_text}");
+            }
+        }
+
+        // Invalid positions and names.
+        for (pat, new_name) in [
+            // ink! attribute keyword.
+            ("#[ink(mes", "get"),
+            // Non-ink! entity.
+            ("pub fn hel", "get"),
+            // Invalid identifiers.
+            ("pub fn my_mes", ""),
+            ("pub fn my_mes", "1get"),
+            ("pub fn my_mes", "get-value"),
+            ("pub fn my_mes", "fn"),
+            // Same name.
+            ("pub fn my_mes", "my_message"),
+        ] {
+            let offset = TextSize::from(parse_offset_at(code, Some(pat)).unwrap() as u32);
+            assert!(
+                rename(&file, offset, new_name).is_empty(),
+                "pat: {pat}, new name: {new_name}"
+            );
+        }
+    }
+}
@@ -0,0 +1,346 @@
+//! ink! attribute semantic tokens.
+
+use ink_analyzer_ir::syntax::{AstNode, AstToken, SyntaxKind, TextRange};
+use ink_analyzer_ir::{InkAttribute, InkAttributeKind, InkEntity, InkFile, InkMacroKind};
+
+/// An ink! attribute semantic token.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SemanticToken {
+    /// Range of the semantic token.
+    pub range: TextRange,
+    /// Type of the semantic token.
+    pub token_type: SemanticTokenType,
+    /// Modifiers of the semantic token.
+    pub modifiers: Vec<SemanticTokenModifier>,
+}
+
+/// The type of an ink! attribute semantic token.
+///
+/// Variants correspond to LSP semantic token types.
+///
+/// Ref: <https://microsoft.github.io/language-server-protocol/specifications/lsp/3.17/specification/#semanticTokenTypes>.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SemanticTokenType {
+    /// The `ink` or `ink_e2e` path prefix (e.g. `ink` in `#[ink::contract]` or `#[ink(message)]`).
+    Namespace,
+    /// An ink! attribute macro name (e.g. `contract` in `#[ink::contract]`).
+    Macro,
+    /// An ink! attribute argument name (e.g. `message` in `#[ink(message)]`).
+    Decorator,
+    /// A string ink! attribute argument value (e.g. `"my_namespace"` in `#[ink(namespace = "my_namespace")]`).
+    String,
+    /// An integer ink! attribute argument value (e.g. `1` in `#[ink(selector = 1)]`).
+    Number,
+    /// A boolean or wildcard ink! attribute argument value (e.g. `_` in `#[ink(selector = _)]`).
+    Keyword,
+    /// A path ink! attribute argument value (e.g. `MyEnvironment` in `#[ink::contract(env = MyEnvironment)]`).
+    Type,
+}
+
+/// A modifier of an ink! attribute semantic token.
+///
+/// Variants correspond to LSP semantic token modifiers.
+///
+/// Ref: <https://microsoft.github.io/language-server-protocol/specifications/lsp/3.17/specification/#semanticTokenModifiers>.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SemanticTokenModifier {
+    /// A name defined by ink! (i.e. the `ink` or `ink_e2e` path prefix, ink! attribute macro names
+    /// and ink! attribute argument names, but not user provided ink! attribute argument values).
+    DefaultLibrary,
+}
+
+/// Computes semantic tokens for all ink! attributes in the file.
+///
+/// Tokens are returned in the order in which they appear in the file.
+pub fn semantic_tokens(file: &InkFile) -> Vec<SemanticToken> {
+    file.tree()
+        .ink_attrs_in_scope()
+        .flat_map(|attr| attr_semantic_tokens(&attr))
+        .collect()
+}
+
+/// Computes semantic tokens for an ink! attribute.
+fn attr_semantic_tokens(attr: &InkAttribute) -> Vec<SemanticToken> {
+    let mut results = vec![SemanticToken {
+        range: attr.ink().syntax().text_range(),
+        token_type: SemanticTokenType::Namespace,
+        modifiers: vec![SemanticTokenModifier::DefaultLibrary],
+    }];
+
+    // Adds ink! attribute macro name (if any).
+    if !matches!(attr.kind(), InkAttributeKind::Macro(InkMacroKind::Unknown)) {
+        if let Some(ink_macro) = attr.ink_macro() {
+            results.push(SemanticToken {
+                range: ink_macro.syntax().text_range(),
+                token_type: SemanticTokenType::Macro,
+                modifiers: vec![SemanticTokenModifier::DefaultLibrary],
+            });
+        }
+    }
+
+    // Adds ink! attribute argument names and values.
+    for arg in attr.args().iter().filter(|arg| !arg.is_unknown()) {
+        if let Some(name) = arg.name() {
+            results.push(SemanticToken {
+                range: name.syntax().text_range(),
+                token_type: SemanticTokenType::Decorator,
+                modifiers: vec![SemanticTokenModifier::DefaultLibrary],
+            });
+        }
+        if let Some(value) = arg.value() {
+            let token_type = match value.kind() {
+                SyntaxKind::STRING => Some(SemanticTokenType::String),
+                SyntaxKind::INT_NUMBER => Some(SemanticTokenType::Number),
+                SyntaxKind::TRUE_KW | SyntaxKind::FALSE_KW | SyntaxKind::UNDERSCORE => {
+                    Some(SemanticTokenType::Keyword)
+                }
+                SyntaxKind::PATH => Some(SemanticTokenType::Type),
+                _ => None,
+            };
+            if let Some(token_type) = token_type {
+                results.push(SemanticToken {
+                    range: value.text_range(),
+                    token_type,
+                    modifiers: Vec::new(),
+                });
+            }
+        }
+    }
+
+    results
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ink_analyzer_ir::syntax::TextSize;
+    use test_utils::parse_offset_at;
+
+    #[test]
+    fn semantic_tokens_works() {
+        for (code, expected_results) in [
+            // (code, [(token_type, range_pat_start, range_pat_end)]) where:
+            // code = source code,
+            // token_type = the semantic token type,
+            // range_pat_start = substring used to find the start of the token range (see `test_utils::parse_offset_at` doc),
+            // range_pat_end = substring used to find the end of the token range (see `test_utils::parse_offset_at` doc).
+
+            // Control tests.
+            ("// Nothing", vec![]),
+            ("#[cfg(test)]", vec![]),
+            ("#[foo::contract]", vec![]),
+            // ink! attribute macros.
+            (
+                "#[ink::contract]",
+                vec![
+                    (SemanticTokenType::Namespace, Some("<-ink"), Some("ink")),
+                    (
+                        SemanticTokenType::Macro,
+                        Some("<-contract"),
+                        Some("contract"),
+                    ),
+                ],
+            ),
+            (
+                "#[ink_e2e::test]",
+                vec![
+                    (
+                        SemanticTokenType::Namespace,
+                        Some("<-ink_e2e"),
+                        Some("ink_e2e"),
+                    ),
+                    (SemanticTokenType::Macro, Some("<-test"), Some("test")),
+                ],
+            ),
+            (
+                r#"#[ink::contract(env=my::env::Types, keep_attr="foo,bar")]"#,
+                vec![
+                    (SemanticTokenType::Namespace, Some("<-ink"), Some("ink")),
+                    (
+                        SemanticTokenType::Macro,
+                        Some("<-contract"),
+                        Some("contract"),
+                    ),
+                    (SemanticTokenType::Decorator, Some("<-env"), Some("env")),
+                    (
+                        SemanticTokenType::Type,
+                        Some("<-my::env::Types"),
+                        Some("my::env::Types"),
+                    ),
+                    (
+                        SemanticTokenType::Decorator,
+                        Some("<-keep_attr"),
+                        Some("keep_attr"),
+                    ),
+                    (
+                        SemanticTokenType::String,
+                        Some(r#"<-"foo,bar""#),
+                        Some(r#""foo,bar""#),
+                    ),
+                ],
+            ),
+            (
+                "#[ink::storage_item(derive=true)]",
+                vec![
+                    (SemanticTokenType::Namespace, Some("<-ink"), Some("ink")),
+                    (
+                        SemanticTokenType::Macro,
+                        Some("<-storage_item"),
+                        Some("storage_item"),
+                    ),
+                    (
+                        SemanticTokenType::Decorator,
+                        Some("<-derive"),
+                        Some("derive"),
+                    ),
+                    (SemanticTokenType::Keyword, Some("<-true"), Some("true")),
+                ],
+            ),
+            // ink! attribute arguments.
+            (
+                "#[ink(storage)]",
+                vec![
+                    (SemanticTokenType::Namespace, Some("<-ink"), Some("ink")),
+                    (
+                        SemanticTokenType::Decorator,
+                        Some("<-storage"),
+                        Some("storage"),
+                    ),
+                ],
+            ),
+            (
+                "#[ink(message, payable, selector=0xA)]",
+                vec![
+                    (SemanticTokenType::Namespace, Some("<-ink"), Some("ink")),
+                    (
+                        SemanticTokenType::Decorator,
+                        Some("<-message"),
+                        Some("message"),
+                    ),
+                    (
+                        SemanticTokenType::Decorator,
+                        Some("<-payable"),
+                        Some("payable"),
+                    ),
+                    (
+                        SemanticTokenType::Decorator,
+                        Some("<-selector"),
+                        Some("selector"),
+                    ),
+                    (SemanticTokenType::Number, Some("<-0xA"), Some("0xA")),
+                ],
+            ),
+            (
+                "#[ink(constructor, selector=_)]",
+                vec![
+                    (SemanticTokenType::Namespace, Some("<-ink"), Some("ink")),
+                    (
+                        SemanticTokenType::Decorator,
+                        Some("<-constructor"),
+                        Some("constructor"),
+                    ),
+                    (
+                        SemanticTokenType::Decorator,
+                        Some("<-selector"),
+                        Some("selector"),
+                    ),
+                    (SemanticTokenType::Keyword, Some("<-_"), Some("_")),
+                ],
+            ),
+            // Unknown ink! attribute arguments are ignored.
+            (
+                "#[ink(message, xyz)]",
+                vec![
+                    (SemanticTokenType::Namespace, Some("<-ink"), Some("ink")),
+                    (
+                        SemanticTokenType::Decorator,
+                        Some("<-message"),
+                        Some("message"),
+                    ),
+                ],
+            ),
+            // Multiple ink! attributes.
+            (
+                r#"
+                #[ink::contract]
+                mod my_contract {
+                    #[ink(storage)]
+                    pub struct MyContract {}
+                }
+                "#,
+                vec![
+                    (SemanticTokenType::Namespace, Some("<-ink"), Some("ink")),
+                    (
+                        SemanticTokenType::Macro,
+                        Some("<-contract"),
+                        Some("contract"),
+                    ),
+                    (
+                        SemanticTokenType::Namespace,
+                        Some("<-ink(storage)"),
+                        Some("<-(storage)"),
+                    ),
+                    (
+                        SemanticTokenType::Decorator,
+                        Some("<-storage"),
+                        Some("storage"),
+                    ),
+                ],
+            ),
+        ] {
+            let results = semantic_tokens(&InkFile::parse(code));
+
+            assert_eq!(
+                results
+                    .into_iter()
+                    .map(|item| (item.token_type, item.range))
+                    .collect::<Vec<(SemanticTokenType, TextRange)>>(),
+                expected_results
+                    .into_iter()
+                    .map(|(token_type, range_pat_start, range_pat_end)| (
+                        token_type,
+                        TextRange::new(
+                            TextSize::from(parse_offset_at(code, range_pat_start).unwrap() as u32),
+                            TextSize::from(parse_offset_at(code, range_pat_end).unwrap() as u32)
+                        )
+                    ))
+                    .collect::<Vec<(SemanticTokenType, TextRange)>>(),
+                "code: {code}"
+            );
+        }
+    }
+
+    #[test]
+    fn semantic_token_modifiers_works() {
+        let code = r#"#[ink::contract(env=my::env::Types, keep_attr="foo,bar")]"#;
+        let results = semantic_tokens(&InkFile::parse(code));
+
+        // Only names defined by ink! (i.e. not argument values) are marked as "default library".
+        assert_eq!(
+            results
+                .into_iter()
+                .map(|item| (item.token_type, item.modifiers))
+                .collect::<Vec<(SemanticTokenType, Vec<SemanticTokenModifier>)>>(),
+            vec![
+                (
+                    SemanticTokenType::Namespace,
+                    vec![SemanticTokenModifier::DefaultLibrary]
+                ),
+                (
+                    SemanticTokenType::Macro,
+                    vec![SemanticTokenModifier::DefaultLibrary]
+                ),
+                (
+                    SemanticTokenType::Decorator,
+                    vec![SemanticTokenModifier::DefaultLibrary]
+                ),
+                (SemanticTokenType::Type, vec![]),
+                (
+                    SemanticTokenType::Decorator,
+                    vec![SemanticTokenModifier::DefaultLibrary]
+                ),
+                (SemanticTokenType::String, vec![]),
+            ]
+        );
+    }
+}
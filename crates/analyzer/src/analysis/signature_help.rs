@@ -831,4 +831,31 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn parameter_details_works() {
+        for (code, pat) in [
+            ("#[ink::contract()]", "contract("),
+            ("#[ink::trait_definition()]", "trait_definition("),
+            ("#[ink::storage_item()]", "storage_item("),
+            ("#[ink_e2e::test()]", "test("),
+            ("#[ink(message)]", "message"),
+            ("#[ink(extension=1)]", "extension"),
+        ] {
+            let offset = TextSize::from(parse_offset_at(code, Some(pat)).unwrap() as u32);
+            let results = signature_help(&InkFile::parse(code), offset);
+
+            // Verifies that all parameters are documented.
+            assert!(!results.is_empty(), "code: {code}");
+            for result in results {
+                assert!(
+                    result
+                        .parameters
+                        .iter()
+                        .all(|param| param.detail.as_ref().is_some_and(|doc| !doc.is_empty())),
+                    "code: {code}"
+                );
+            }
+        }
+    }
 }
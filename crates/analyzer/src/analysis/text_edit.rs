@@ -1,7 +1,7 @@
 //! A text edit.
 
 use ink_analyzer_ir::syntax::{AstNode, SyntaxKind, SyntaxToken, TextRange, TextSize};
-use ink_analyzer_ir::{InkEntity, InkFile};
+use ink_analyzer_ir::{InkAttribute, InkEntity, InkFile};
 use once_cell::sync::Lazy;
 use regex::Regex;
 
@@ -60,18 +60,80 @@ impl TextEdit {
             snippet: None,
         }
     }
+
+    /// Creates a text edit for removing an ink! attribute
+    /// (including its trailing whitespace i.e. the new line and indenting before the next token).
+    pub fn remove_attribute(attr: &InkAttribute) -> Self {
+        let range = attr.syntax().text_range();
+        Self::delete(TextRange::new(
+            range.start(),
+            attr.syntax()
+                .last_token()
+                .and_then(|token| token.next_token())
+                .filter(|token| token.kind() == SyntaxKind::WHITESPACE)
+                .map_or(range.end(), |token| token.text_range().end()),
+        ))
+    }
+}
+
+/// Options for formatting text edits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FormatOptions {
+    /// Number of spaces per indenting level (ignored if `use_tabs` is true).
+    pub indent_size: usize,
+    /// Use tabs (instead of spaces) for indenting.
+    pub use_tabs: bool,
+    /// Maximum line length (if any).
+    ///
+    /// **NOTE**: This is currently not enforced (i.e. long lines in edits are not wrapped).
+    pub max_line_length: Option<usize>,
+}
+
+impl Default for FormatOptions {
+    fn default() -> Self {
+        Self {
+            indent_size: 4,
+            use_tabs: false,
+            max_line_length: None,
+        }
+    }
+}
+
+impl FormatOptions {
+    /// Returns the whitespace for a single indenting level.
+    pub fn indent(&self) -> String {
+        if self.use_tabs {
+            "\t".to_string()
+        } else {
+            " ".repeat(self.indent_size)
+        }
+    }
 }
 
 /// Format text edits (i.e. add indenting and new lines based on context).
 pub fn format_edits<'a>(
     edits: impl Iterator<Item = TextEdit> + 'a,
     file: &'a InkFile,
+    options: &'a FormatOptions,
 ) -> impl Iterator<Item = TextEdit> + 'a {
-    edits.map(|item| format_edit(item, file))
+    edits.map(|item| format_edit_with_options(item, file, *options))
 }
 
 /// Format text edit (i.e. add indenting and new lines based on context).
-pub fn format_edit(mut edit: TextEdit, file: &InkFile) -> TextEdit {
+pub fn format_edit(edit: TextEdit, file: &InkFile) -> TextEdit {
+    format_edit_with_options(edit, file, FormatOptions::default())
+}
+
+/// Format text edit (i.e. add indenting and new lines based on context)
+/// using the given formatting options.
+///
+/// **NOTE**: Indenting is inferred from the surrounding code when possible,
+/// the formatting options are used otherwise (e.g. for edits inside top-level items).
+pub fn format_edit_with_options(
+    mut edit: TextEdit,
+    file: &InkFile,
+    options: FormatOptions,
+) -> TextEdit {
     // Adds affixes to a text edit.
     fn add_affixes(edit: &mut TextEdit, prefix: Option<String>, suffix: Option<String>) {
         if prefix.is_some() || suffix.is_some() {
@@ -245,8 +307,12 @@ pub fn format_edit(mut edit: TextEdit, file: &InkFile) -> TextEdit {
                                 "\n{}",
                                 (!edit.text.starts_with(' ') && !edit.text.starts_with('\t'))
                                     .then(|| {
-                                        ink_analyzer_ir::parent_ast_item(&token_before)
-                                            .map(|it| utils::item_children_indenting(it.syntax()))
+                                        ink_analyzer_ir::parent_ast_item(&token_before).map(|it| {
+                                            utils::item_children_indenting_with_options(
+                                                it.syntax(),
+                                                &options,
+                                            )
+                                        })
                                     })
                                     .flatten()
                                     .as_deref()
@@ -698,6 +764,79 @@ mod contract {}",
         }
     }
 
+    #[test]
+    fn format_with_options_works() {
+        let two_spaces = FormatOptions {
+            indent_size: 2,
+            ..Default::default()
+        };
+        let tabs = FormatOptions {
+            use_tabs: true,
+            ..Default::default()
+        };
+        for (input, output, source, pat, options) in [
+            // (input, output, source, pat, options) where:
+            // pat = substring used to find the edit offset (see `test_utils::parse_offset_at` doc).
+
+            // Indenting for the children of top-level items is based on the formatting options.
+            (
+                "struct MyContract {}",
+                "\n    struct MyContract {}\n",
+                "mod contract {\n}",
+                Some("mod contract {"),
+                FormatOptions::default(),
+            ),
+            (
+                "struct MyContract {}",
+                "\n  struct MyContract {}\n",
+                "mod contract {\n}",
+                Some("mod contract {"),
+                two_spaces,
+            ),
+            (
+                "struct MyContract {}",
+                "\n\tstruct MyContract {}\n",
+                "mod contract {\n}",
+                Some("mod contract {"),
+                tabs,
+            ),
+            // Indenting for the children of nested items is based on the surrounding code.
+            (
+                "status: bool,",
+                "\n    status: bool,\n",
+                "mod contract {\n  struct MyContract {\n  }\n}",
+                Some("struct MyContract {"),
+                two_spaces,
+            ),
+            (
+                "status: bool,",
+                "\n\t\tstatus: bool,\n",
+                "mod contract {\n\tstruct MyContract {\n\t}\n}",
+                Some("struct MyContract {"),
+                tabs,
+            ),
+            (
+                "struct MyEvent {}",
+                "\n\n\tstruct MyEvent {}",
+                "mod contract {\n\tstruct MyContract {}\n}",
+                Some("struct MyContract {}"),
+                tabs,
+            ),
+        ] {
+            let file = InkFile::parse(source);
+            let offset = TextSize::from(parse_offset_at(source, pat).unwrap() as u32);
+            let range = TextRange::new(offset, offset);
+            let edit = TextEdit::insert(input.to_string(), offset);
+            let result = format_edit_with_options(edit, &file, options);
+            let expected = TextEdit {
+                text: output.to_string(),
+                range,
+                snippet: None,
+            };
+            assert_eq!(result, expected, "source: {source:?}");
+        }
+    }
+
     #[test]
     fn format_delete_works() {
         for (start_pat_input, end_pat_input, pat_range_output, source) in [
@@ -1041,4 +1180,67 @@ mod contract {}",
             assert_eq!(result, expected);
         }
     }
+
+    #[test]
+    fn remove_attribute_works() {
+        for (source, expected_output) in [
+            ("#[ink::contract]\nmod contract {}", "mod contract {}"),
+            (
+                r"
+#[ink::contract]
+mod contract {}
+",
+                r"
+mod contract {}
+",
+            ),
+            (
+                r"
+mod contract {
+    impl MyContract {
+        #[ink(message)]
+        pub fn message(&self) {}
+    }
+}",
+                r"
+mod contract {
+    impl MyContract {
+        pub fn message(&self) {}
+    }
+}",
+            ),
+            (
+                r"
+mod contract {
+    impl MyContract {
+        #[ink(message)]
+        #[doc(hidden)]
+        pub fn message(&self) {}
+    }
+}",
+                r"
+mod contract {
+    impl MyContract {
+        #[doc(hidden)]
+        pub fn message(&self) {}
+    }
+}",
+            ),
+            (
+                "#[ink(message)] pub fn message(&self) {}",
+                "pub fn message(&self) {}",
+            ),
+        ] {
+            let file = InkFile::parse(source);
+            let attr = file.tree().ink_attrs_in_scope().next().unwrap();
+
+            let edit = TextEdit::remove_attribute(&attr);
+            let mut output = source.to_string();
+            output.replace_range(
+                usize::from(edit.range.start())..usize::from(edit.range.end()),
+                &edit.text,
+            );
+            assert_eq!(output, expected_output, "source: {source}");
+        }
+    }
 }
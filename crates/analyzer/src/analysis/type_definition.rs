@@ -0,0 +1,131 @@
+//! ink! storage field type definition.
+
+use ink_analyzer_ir::syntax::{AstNode, TextRange, TextSize};
+use ink_analyzer_ir::{ast, InkEntity, InkFile, IsInkStruct};
+
+use crate::analysis::utils as analysis_utils;
+
+/// Returns the text range of the declaration of the type at the given offset (if any)
+/// in an ink! storage field.
+///
+/// **NOTE**: Only types declared in the same file (i.e. `struct`s, `enum`s, `union`s
+/// and type aliases) can be resolved, so external types (e.g. `ink::storage::Mapping`) return `None`.
+pub fn type_definition(file: &InkFile, offset: TextSize) -> Option<TextRange> {
+    let item_at_offset = file.item_at_offset(offset);
+    let focused_token = item_at_offset.focused_token()?;
+    let path_type = focused_token
+        .parent_ancestors()
+        .take_while(|node| !ast::Item::can_cast(node.kind()))
+        .find_map(ast::PathType::cast)?;
+
+    // Only handles types in ink! storage fields.
+    let is_storage_field_type = file.contracts().iter().any(|contract| {
+        contract
+            .storage()
+            .and_then(IsInkStruct::struct_item)
+            .and_then(ast::Struct::field_list)
+            .is_some_and(|field_list| {
+                field_list
+                    .syntax()
+                    .text_range()
+                    .contains_range(path_type.syntax().text_range())
+            })
+    });
+    if !is_storage_field_type {
+        return None;
+    }
+
+    let path = path_type.path()?;
+    analysis_utils::resolve_type_item(&path, path_type.syntax())
+        .map(|item| item.syntax().text_range())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_utils::parse_offset_at;
+
+    #[test]
+    fn type_definition_works() {
+        let code = r#"
+            #[ink::contract]
+            mod my_contract {
+                use ink::storage::Mapping;
+
+                #[derive(scale::Decode, scale::Encode)]
+                pub struct MyInfo {
+                    value: u8,
+                }
+
+                pub enum MyStatus {
+                    Active,
+                    Inactive,
+                }
+
+                pub type MyAlias = u32;
+
+                #[ink(storage)]
+                pub struct MyContract {
+                    info: MyInfo,
+                    status: Option<MyStatus>,
+                    alias: MyAlias,
+                    balances: Mapping<AccountId, Balance>,
+                    flag: bool,
+                }
+
+                pub struct NotStorage {
+                    info: MyInfo,
+                }
+            }
+        "#;
+        let file = InkFile::parse(code);
+
+        for (pat, expected_start_pat, expected_end_pat) in [
+            // Struct.
+            (
+                "info: My",
+                Some("<-#[derive(scale::Decode"),
+                Some("value: u8,\n                }"),
+            ),
+            // Enum (as a generic argument).
+            (
+                "Option<My",
+                Some("<-pub enum MyStatus"),
+                Some("Inactive,\n                }"),
+            ),
+            // Type alias.
+            (
+                "alias: My",
+                Some("<-pub type MyAlias"),
+                Some("pub type MyAlias = u32;"),
+            ),
+        ] {
+            let offset = TextSize::from(parse_offset_at(code, Some(pat)).unwrap() as u32);
+            let expected_range = TextRange::new(
+                TextSize::from(parse_offset_at(code, expected_start_pat).unwrap() as u32),
+                TextSize::from(parse_offset_at(code, expected_end_pat).unwrap() as u32),
+            );
+            assert_eq!(
+                type_definition(&file, offset),
+                Some(expected_range),
+                "pat: {pat}"
+            );
+        }
+
+        for pat in [
+            // External types.
+            "balances: Map",
+            "Mapping<Acc",
+            "Option",
+            // Primitive types.
+            "flag: bo",
+            // Field name.
+            "<-info: MyInfo",
+            // Non-storage field.
+            "NotStorage {\n                    info: My",
+        ] {
+            let offset = TextSize::from(parse_offset_at(code, Some(pat)).unwrap() as u32);
+            assert_eq!(type_definition(&file, offset), None, "pat: {pat}");
+        }
+    }
+}
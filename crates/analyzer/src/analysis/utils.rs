@@ -14,8 +14,12 @@ use once_cell::sync::Lazy;
 use regex::Regex;
 use std::collections::HashSet;
 
+use super::completions::CompletionContext;
+use super::text_edit::FormatOptions;
 use crate::utils;
 
+pub use ink_analyzer_ir::{valid_ink_args_by_syntax_kind, valid_ink_macros_by_syntax_kind};
+
 /// Returns valid sibling ink! argument kinds for the given ink! attribute kind.
 ///
 /// (i.e argument kinds that don't conflict with the given ink! attribute kind,
@@ -247,61 +251,6 @@ pub fn valid_quasi_direct_descendant_ink_macros(attr_kind: InkAttributeKind) ->
     }
 }
 
-/// Returns valid ink! argument kinds for the given syntax kind.
-///
-/// (i.e argument kinds that can be applied to the given syntax kind,
-/// e.g for the `impl` syntax kind, this would be `impl` and `namespace`.
-pub fn valid_ink_args_by_syntax_kind(syntax_kind: SyntaxKind) -> Vec<InkArgKind> {
-    match syntax_kind {
-        // `env` and `keep_attr` can only be applied to a `mod` as siblings of an `ink::contract` macro.
-        SyntaxKind::MODULE | SyntaxKind::MOD_KW => Vec::new(),
-        // `keep_attr` and `namespace` can only be applied to a `trait` as siblings of an `ink::trait_definition` macro.
-        SyntaxKind::TRAIT | SyntaxKind::TRAIT_KW => Vec::new(),
-        // `derive` can only be applied to an ADT (`enum`, `struct` or `union`) as a sibling of an `ink::storage_item` macro.
-        SyntaxKind::STRUCT | SyntaxKind::STRUCT_KW => vec![
-            InkArgKind::Anonymous,
-            InkArgKind::Event,
-            InkArgKind::Storage,
-        ],
-        SyntaxKind::ENUM | SyntaxKind::ENUM_KW | SyntaxKind::UNION | SyntaxKind::UNION_KW => {
-            Vec::new()
-        }
-        SyntaxKind::RECORD_FIELD => vec![InkArgKind::Topic],
-        SyntaxKind::FN | SyntaxKind::FN_KW => vec![
-            InkArgKind::Constructor,
-            InkArgKind::Default,
-            InkArgKind::Extension,
-            InkArgKind::HandleStatus,
-            InkArgKind::Message,
-            InkArgKind::Payable,
-            InkArgKind::Selector,
-        ],
-        SyntaxKind::IMPL | SyntaxKind::IMPL_KW => vec![InkArgKind::Impl, InkArgKind::Namespace],
-        _ => Vec::new(),
-    }
-}
-
-/// Returns valid ink! macro kinds for the given syntax kind.
-///
-/// (i.e macro kinds that can be applied to the given syntax kind,
-/// e.g for the `module` syntax kind, this would be `contract`.
-pub fn valid_ink_macros_by_syntax_kind(syntax_kind: SyntaxKind) -> Vec<InkMacroKind> {
-    match syntax_kind {
-        SyntaxKind::MODULE | SyntaxKind::MOD_KW => vec![InkMacroKind::Contract],
-        SyntaxKind::TRAIT | SyntaxKind::TRAIT_KW => {
-            vec![InkMacroKind::ChainExtension, InkMacroKind::TraitDefinition]
-        }
-        SyntaxKind::ENUM
-        | SyntaxKind::ENUM_KW
-        | SyntaxKind::STRUCT
-        | SyntaxKind::STRUCT_KW
-        | SyntaxKind::UNION
-        | SyntaxKind::UNION_KW => vec![InkMacroKind::StorageItem],
-        SyntaxKind::FN | SyntaxKind::FN_KW => vec![InkMacroKind::Test, InkMacroKind::E2ETest],
-        _ => Vec::new(),
-    }
-}
-
 /// Returns the primary ink! attribute candidate for the syntax node (if any),
 /// a boolean flag indicating whether its the first ink! attribute.
 ///
@@ -554,6 +503,25 @@ pub fn ink_arg_insert_text(
     arg_kind: InkArgKind,
     insert_offset_option: Option<TextSize>,
     parent_attr_option: Option<&SyntaxNode>,
+) -> (String, Option<String>) {
+    ink_arg_insert_text_with_context(
+        arg_kind,
+        insert_offset_option,
+        parent_attr_option,
+        &CompletionContext::default(),
+    )
+}
+
+/// Returns the insert text and snippet (if appropriate) for ink! attribute argument
+/// (see [`ink_arg_insert_text`] doc), using the completion context to compute better default values.
+///
+/// (i.e. the next free selector for `selector`, the environment of a parent ink! entity for `env`
+/// and the trait or `mod` name for `namespace`).
+pub fn ink_arg_insert_text_with_context(
+    arg_kind: InkArgKind,
+    insert_offset_option: Option<TextSize>,
+    parent_attr_option: Option<&SyntaxNode>,
+    context: &CompletionContext,
 ) -> (String, Option<String>) {
     // Determines whether or not to insert the `=` symbol after the ink! attribute argument name.
     let insert_equal_token = match InkArgValueKind::from(arg_kind) {
@@ -594,6 +562,14 @@ pub fn ink_arg_insert_text(
             .unwrap_or(true),
     };
     let mut text = format!("{arg_kind}{}", if insert_equal_token { " = " } else { "" });
+    // Uses a value derived from the completion context (if any).
+    let context_value = insert_equal_token
+        .then(|| ink_arg_value_from_context(arg_kind, context))
+        .flatten();
+    if let Some(value) = context_value {
+        let snippet = format!("{text}${{1:{value}}}");
+        return (format!("{text}{value}"), Some(snippet));
+    }
     // Creates (if appropriate) a snippet with tab stops and/or placeholders where applicable.
     let snippet = insert_equal_token.then_some(format!(
         "{text}{}",
@@ -632,6 +608,71 @@ pub fn ink_arg_insert_text(
     (text, snippet)
 }
 
+/// Returns the value (if any) for an ink! attribute argument derived from the completion context.
+fn ink_arg_value_from_context(arg_kind: InkArgKind, context: &CompletionContext) -> Option<String> {
+    match arg_kind {
+        // Uses the next free selector after the max explicit selector (if any),
+        // skipping the composed selectors of all ink! constructors and ink! messages
+        // (i.e. only if there are any existing selectors).
+        InkArgKind::Selector => {
+            if context.existing_selectors.is_empty() {
+                return None;
+            }
+            let existing: HashSet<u32> = context
+                .existing_selectors
+                .iter()
+                .map(|bytes| u32::from_be_bytes(*bytes))
+                .collect();
+            // Falls back to the default value if there are no explicit selectors
+            // and the default value is free.
+            if context.explicit_selectors.is_empty() && !existing.contains(&1) {
+                return None;
+            }
+            let start = context
+                .explicit_selectors
+                .iter()
+                .max()
+                .map_or(1, |max| max.saturating_add(1));
+            // Wraps around after `u32::MAX`, and at most `existing.len() + 1` candidates
+            // need to be checked to find a free selector.
+            (start..=u32::MAX)
+                .chain(1..start)
+                .take(existing.len() + 1)
+                .find(|selector| !existing.contains(selector))
+                .map(ink_analyzer_ir::format_selector)
+        }
+        // Uses the environment of the ink! attribute's item or its ancestors (if any).
+        InkArgKind::Env | InkArgKind::Environment => context
+            .covering_attribute
+            .as_ref()
+            .or(context.parent_entity.as_ref())?
+            .syntax()
+            .ancestors()
+            .flat_map(|node| ink_analyzer_ir::ink_attrs(&node))
+            .flat_map(|attr| attr.args().to_vec())
+            .find(|arg| matches!(arg.kind(), InkArgKind::Env | InkArgKind::Environment))
+            .and_then(|arg| arg.value().map(ToString::to_string)),
+        // Uses the trait or `mod` name of the ink! attribute's item (if any).
+        InkArgKind::Namespace => {
+            let item =
+                ink_analyzer_ir::parent_ast_item(context.covering_attribute.as_ref()?.syntax())?;
+            let name = match item {
+                ast::Item::Impl(impl_item) => match impl_item.trait_()? {
+                    ast::Type::PathType(path_type) => {
+                        path_type.path()?.segment()?.name_ref()?.to_string()
+                    }
+                    _ => return None,
+                },
+                ast::Item::Trait(trait_item) => trait_item.name()?.to_string(),
+                ast::Item::Module(module) => module.name()?.to_string(),
+                _ => return None,
+            };
+            Some(format!(r#""{name}""#))
+        }
+        _ => None,
+    }
+}
+
 /// Returns the insert offset for an ink! attribute.
 pub fn ink_attribute_insert_offset(node: &SyntaxNode) -> TextSize {
     ink_analyzer_ir::ink_attrs(node)
@@ -860,9 +901,16 @@ pub fn end_indenting(whitespace: &str) -> String {
 
 /// Returns appropriate indenting (preceding whitespace) for the syntax node's children.
 pub fn item_children_indenting(node: &SyntaxNode) -> String {
+    item_children_indenting_with_options(node, &FormatOptions::default())
+}
+
+/// Returns appropriate indenting (preceding whitespace) for the syntax node's children,
+/// falling back to a single indenting level (based on the formatting options)
+/// if the syntax node has no indenting.
+pub fn item_children_indenting_with_options(node: &SyntaxNode, options: &FormatOptions) -> String {
     item_indenting(node)
         .and_then(|ident| (!ident.is_empty()).then_some(format!("{ident}{ident}")))
-        .unwrap_or("    ".to_string())
+        .unwrap_or_else(|| options.indent())
 }
 
 /// Returns the deepest syntax element that fully covers text range (if any).
@@ -1200,12 +1248,9 @@ pub fn ink_arg_and_delimiter_removal_range(
         }));
 
     // Determines the parent attribute for the argument.
-    if let Some(attr) = parent_attr_option.cloned().or(last_token_option
-        .as_ref()
-        .and_then(|token| {
-            ink_analyzer_ir::closest_ancestor_ast_type::<SyntaxToken, ast::Attr>(token)
-        })
-        .and_then(InkAttribute::cast))
+    if let Some(attr) = parent_attr_option
+        .cloned()
+        .or_else(|| arg.surrounding_ink_attribute())
     {
         if attr.args().len() == 1 {
             match attr.kind() {
@@ -1561,6 +1606,48 @@ pub fn resolve_contract_name(contract: &Contract) -> Option<String> {
             .map(utils::pascal_case))
 }
 
+/// Returns the name references of calls to an ink! callable in the ink! contract
+/// (i.e. `self.<name>(..)`, `Self::<name>(..)` and `<StorageName>::<name>(..)`).
+pub fn callable_references(contract: &Contract, name: &str) -> Vec<ast::NameRef> {
+    let storage_name = contract
+        .storage()
+        .and_then(IsInkStruct::struct_item)
+        .and_then(HasName::name)
+        .map(|name| name.to_string());
+    contract
+        .syntax()
+        .descendants()
+        .filter_map(|node| {
+            if let Some(call) = ast::MethodCallExpr::cast(node.clone()) {
+                let is_self_receiver = call
+                    .receiver()
+                    .is_some_and(|receiver| receiver.syntax().to_string() == "self");
+                call.name_ref()
+                    .filter(|name_ref| is_self_receiver && name_ref.to_string() == name)
+            } else if let Some(path_expr) = ast::PathExpr::cast(node) {
+                let path = path_expr.path()?;
+                let qualifier = path.qualifier()?.to_string();
+                let name_ref = path.segment()?.name_ref()?;
+                (name_ref.to_string() == name
+                    && (qualifier == "Self" || Some(&qualifier) == storage_name.as_ref()))
+                .then_some(name_ref)
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+/// Returns the type item (i.e. a `struct`, `enum`, `union` or type alias) declared in the same file
+/// that the path resolves to (if any).
+pub fn resolve_type_item(path: &ast::Path, ref_node: &SyntaxNode) -> Option<ast::Item> {
+    ink_analyzer_ir::resolve_item::<ast::Adt>(path, ref_node)
+        .map(ast::Item::from)
+        .or_else(|| {
+            ink_analyzer_ir::resolve_item::<ast::TypeAlias>(path, ref_node).map(ast::Item::from)
+        })
+}
+
 /// Applies indenting to a snippet.
 pub fn apply_indenting(input: &str, indent: &str) -> String {
     if indent.is_empty() {
@@ -1646,12 +1733,7 @@ where
 
 /// Compares two node for equality while ignoring trivia (i.e. whitespace and comments).
 pub fn is_trivia_insensitive_eq(a: &SyntaxNode, b: &SyntaxNode) -> bool {
-    let strip_trivia = |node: &SyntaxNode| {
-        node.children_with_tokens()
-            .filter(|node| !node.kind().is_trivia())
-            .join("")
-    };
-    strip_trivia(a) == strip_trivia(b)
+    ink_analyzer_ir::strip_trivia(a) == ink_analyzer_ir::strip_trivia(b)
 }
 
 /// Returns true if the syntax node is a trait definition implementation message.
@@ -19,6 +19,10 @@ topic: bool,"#;
 pub const TOPIC_SNIPPET: &str = r#"#[ink(topic)]
 ${1:topic}: ${2:bool},"#;
 
+pub const SCALE_DERIVE_PLAIN: &str = r#"#[ink::scale_derive(Encode, Decode, TypeInfo)]"#;
+pub const SCALE_DERIVE_SNIPPET: &str =
+    r#"#[ink::scale_derive(${1:Encode}, ${2:Decode}, ${3:TypeInfo})]"#;
+
 pub const CONSTRUCTOR_PLAIN: &str = r#"#[ink(constructor)]
 pub fn new() -> Self {
     todo!()
@@ -60,13 +64,13 @@ pub fn ${1:it_works}() {
 }"#;
 
 pub const INK_E2E_TEST_PLAIN: &str = r#"#[ink_e2e::test]
-pub fn it_works(mut client: ink_e2e::Client<C, E>) -> std::result::Result<(), Box<dyn std::error::Error>> {
+pub async fn it_works(mut client: ink_e2e::Client<C, E>) -> std::result::Result<(), Box<dyn std::error::Error>> {
     todo!();
 
     Ok(())
 }"#;
 pub const INK_E2E_TEST_SNIPPET: &str = r#"#[ink_e2e::test]
-pub fn ${1:it_works}(${2:mut client: ink_e2e::Client<C, E>})${3: -> std::result::Result<(), Box<dyn std::error::Error>>} {
+pub async fn ${1:it_works}(${2:mut client: ink_e2e::Client<C, E>})${3: -> std::result::Result<(), Box<dyn std::error::Error>>} {
     ${4:todo!();}
 
     Ok(())
@@ -108,7 +112,7 @@ pub mod my_contract {
         type E2EResult<T> = std::result::Result<T, Box<dyn std::error::Error>>;
 
         #[ink_e2e::test]
-        pub fn it_works(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+        pub async fn it_works(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
             todo!();
 
             Ok(())
@@ -153,7 +157,7 @@ pub mod ${1:my_contract} {
         type E2EResult<T> = std::result::Result<T, Box<dyn std::error::Error>>;
 
         #[ink_e2e::test]
-        pub fn ${12:it_works}(${13:mut client: ink_e2e::Client<C, E>})${14: -> E2EResult<()>} {
+        pub async fn ${12:it_works}(${13:mut client: ink_e2e::Client<C, E>})${14: -> E2EResult<()>} {
             ${15:todo!();}
 
             Ok(())
@@ -36,6 +36,10 @@
 //!     let completions = analysis.completions(position);
 //!     dbg!(&completions);
 //!
+//!     // Computes completions without explicitly creating an analysis snapshot.
+//!     let completions = ink_analyzer::completions_at(code, position);
+//!     dbg!(&completions);
+//!
 //!     // Sets the focus range.
 //!     let range = TextRange::new(position, TextSize::from(25));
 //!
@@ -72,9 +76,75 @@ mod test_utils;
 
 pub use self::{
     analysis::{
-        Action, ActionKind, Analysis, Completion, Diagnostic, Hover, InlayHint, Severity,
-        SignatureHelp, TextEdit,
+        Action, ActionKind, Analysis, CallHierarchyIncomingCall, CallHierarchyItem,
+        CodeActionContext, CodeLens, Completion, CompletionContext, CompletionTriggerKind,
+        Diagnostic, DocumentSymbol, FoldingRange, FoldingRangeKind, FormatOptions, Hover,
+        InlayHint, InlayHintKind, PrepareRenameResult, SemanticToken, SemanticTokenModifier,
+        SemanticTokenType, Severity, SignatureHelp, SymbolKind, TextEdit, WorkspaceAnalysis,
     },
     codegen::{new_project, Error, Project, ProjectFile},
 };
 pub use ink_analyzer_ir::syntax::{TextRange, TextSize};
+
+/// Computes ink! attribute completions for the given code at the given offset.
+///
+/// This is a shorthand for `Analysis::new(code).completions(offset)`,
+/// which covers ink! attribute macro completions (e.g. after `#[ink::`),
+/// ink! attribute argument completions (e.g. after `#[ink(`)
+/// and ink! environment argument value completions (i.e. after `env=` or `environment=`).
+pub fn completions_at(code: &str, offset: TextSize) -> Vec<Completion> {
+    Analysis::new(code).completions(offset)
+}
+
+/// Computes diagnostics for the given code and serializes them as a JSON array.
+///
+/// Each diagnostic is serialized as an object with a `message`, a `severity`
+/// (i.e. `"error"`, `"warning"` or `"hint"`), a `range` (i.e. `{ "start": N, "end": N }`)
+/// and a list of `quickfixes` (each with a `label` and a list of `edits`).
+#[cfg(feature = "serde")]
+pub fn all_diagnostics_as_json(code: &str) -> String {
+    use serde_json::{json, Value};
+
+    let range_json = |range: TextRange| -> Value {
+        json!({
+            "start": u32::from(range.start()),
+            "end": u32::from(range.end()),
+        })
+    };
+    let diagnostics: Vec<Value> = Analysis::new(code)
+        .diagnostics()
+        .into_iter()
+        .map(|diagnostic| {
+            json!({
+                "message": diagnostic.message,
+                "severity": match diagnostic.severity {
+                    Severity::Error => "error",
+                    Severity::Warning => "warning",
+                    Severity::Hint => "hint",
+                },
+                "range": range_json(diagnostic.range),
+                "quickfixes": diagnostic
+                    .quickfixes
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|action| {
+                        json!({
+                            "label": action.label,
+                            "edits": action
+                                .edits
+                                .into_iter()
+                                .map(|edit| {
+                                    json!({
+                                        "text": edit.text,
+                                        "range": range_json(edit.range),
+                                    })
+                                })
+                                .collect::<Vec<Value>>(),
+                        })
+                    })
+                    .collect::<Vec<Value>>(),
+            })
+        })
+        .collect();
+    Value::Array(diagnostics).to_string()
+}
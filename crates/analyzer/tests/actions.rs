@@ -1,6 +1,6 @@
 //! integration tests for ink! analyzer actions.
 
-use ink_analyzer::{Analysis, TextRange, TextSize};
+use ink_analyzer::{ActionKind, Analysis, TextRange, TextSize};
 use test_utils::{PartialMatchStr, TestCaseParams, TestCaseResults};
 
 // The high-level methodology for code/intent actions test cases is:
@@ -95,3 +95,45 @@ fn actions_works() {
         }
     }
 }
+
+// Verifies that actions can be filtered by kind.
+#[test]
+fn actions_with_kinds_works() {
+    // Replaces the self reference receiver of an ink! message with a self receiver,
+    // so that there are both quickfixes (for the resulting diagnostic) and refactoring actions.
+    let code = test_utils::read_source_code("contracts/erc20").replacen(
+        "pub fn total_supply(&self)",
+        "pub fn total_supply(self)",
+        1,
+    );
+    let analysis = Analysis::new(&code);
+    let offset = TextSize::from(code.find("pub fn total_supply").unwrap() as u32);
+    let range = TextRange::new(offset, offset);
+
+    let all_actions = analysis.actions(range);
+    for kinds in [
+        vec![ActionKind::QuickFix],
+        vec![ActionKind::Refactor],
+        vec![ActionKind::QuickFix, ActionKind::Refactor],
+    ] {
+        let results = analysis.actions_with_kinds(range, &kinds);
+        assert!(!results.is_empty(), "kinds: {kinds:?}");
+        assert!(
+            results.iter().all(|action| kinds.contains(&action.kind)),
+            "kinds: {kinds:?}"
+        );
+        // Filtered actions are the actions of the given kinds.
+        assert_eq!(
+            results,
+            all_actions
+                .iter()
+                .filter(|action| kinds.contains(&action.kind))
+                .cloned()
+                .collect::<Vec<_>>(),
+            "kinds: {kinds:?}"
+        );
+    }
+
+    // No actions for no kinds.
+    assert!(analysis.actions_with_kinds(range, &[]).is_empty());
+}
@@ -76,3 +76,55 @@ fn completions_works() {
         }
     }
 }
+
+// Verifies that `completions_at` computes the same completions as `Analysis::completions`
+// for each completion context.
+#[test]
+fn completions_at_works() {
+    for (code, pat, expected_label) in [
+        // (code, pat, Option<expected_label>) where:
+        // code = source code,
+        // pat = substring used to find the cursor offset (see `test_utils::parse_offset_at` doc),
+        // expected_label = the label of a completion that's expected to be returned (if any).
+
+        // ink! attribute macro completions.
+        ("#[ink::", None, Some("::contract")),
+        ("#[ink::co", None, Some("contract")),
+        // ink! attribute argument completions.
+        (
+            "#[ink::contract]\nmod my_contract {\n    #[ink(\n    pub struct MyContract {}\n}",
+            Some("#[ink("),
+            Some("storage"),
+        ),
+        ("#[ink::contract(", None, Some("env = crate::")),
+        // ink! environment argument value completions.
+        (
+            "#[ink::contract(env=",
+            None,
+            Some("ink::env::DefaultEnvironment"),
+        ),
+        (
+            "#[ink::contract(env=ink::",
+            None,
+            Some("ink::env::DefaultEnvironment"),
+        ),
+        // `namespace` values are string literals, so there are no value completions.
+        (r#"#[ink::trait_definition(namespace="#, None, None),
+    ] {
+        let offset = TextSize::from(test_utils::parse_offset_at(code, pat).unwrap() as u32);
+
+        let results = ink_analyzer::completions_at(code, offset);
+        assert_eq!(
+            results,
+            Analysis::new(code).completions(offset),
+            "code: {code}"
+        );
+        match expected_label {
+            Some(label) => assert!(
+                results.iter().any(|completion| completion.label == label),
+                "code: {code}"
+            ),
+            None => assert!(results.is_empty(), "code: {code}"),
+        }
+    }
+}
@@ -1,6 +1,6 @@
 //! integration tests for ink! analyzer diagnostics.
 
-use ink_analyzer::{Analysis, TextRange, TextSize};
+use ink_analyzer::{Analysis, Severity, TextRange, TextSize, WorkspaceAnalysis};
 use test_utils::{PartialMatchStr, TestCaseResults};
 
 // The high-level methodology for diagnostics test cases is:
@@ -109,3 +109,197 @@ fn diagnostics_works() {
         }
     }
 }
+
+#[test]
+fn diagnostics_with_severity_filter_works() {
+    for test_group in test_utils::fixtures::diagnostics_fixtures() {
+        let original_code = test_utils::read_source_code(test_group.source);
+
+        for test_case in test_group.test_cases {
+            let mut test_code = original_code.clone();
+            if let Some(modifications) = test_case.modifications {
+                test_utils::apply_test_modifications(&mut test_code, &modifications);
+            }
+
+            let analysis = Analysis::new(&test_code);
+            let results = analysis.diagnostics();
+
+            // Filtering by the lowest severity returns all diagnostics.
+            assert_eq!(
+                analysis.diagnostics_with_severity_filter(Severity::Hint),
+                results,
+                "source: {}",
+                test_group.source
+            );
+            // Filtering by higher severities returns the subset of diagnostics with at least that severity.
+            for min_severity in [Severity::Warning, Severity::Error] {
+                let filtered_results = analysis.diagnostics_with_severity_filter(min_severity);
+                assert!(
+                    filtered_results.iter().all(|it| results.contains(it)),
+                    "source: {}",
+                    test_group.source
+                );
+                assert_eq!(
+                    filtered_results,
+                    results
+                        .iter()
+                        .filter(|it| it.severity >= min_severity)
+                        .cloned()
+                        .collect::<Vec<_>>(),
+                    "source: {}",
+                    test_group.source
+                );
+            }
+        }
+    }
+}
+
+// Verifies that workspace diagnostics detect implementations of ink! trait definitions (from another file)
+// that are out of sync with the (changed) ink! trait definition.
+#[test]
+fn workspace_diagnostics_works() {
+    let contract_code = r#"
+        #[ink::contract]
+        mod flipper {
+            #[ink(storage)]
+            pub struct Flipper {
+                value: bool,
+            }
+
+            impl Flipper {
+                #[ink(constructor)]
+                pub fn new() -> Self {
+                    Self { value: false }
+                }
+            }
+
+            impl crate::traits::Flip for Flipper {
+                #[ink(message)]
+                fn flip(&mut self) {
+                    self.value = !self.value;
+                }
+
+                #[ink(message)]
+                fn get(&self) -> bool {
+                    self.value
+                }
+            }
+        }
+    "#;
+    let original_trait_code = r#"
+        #[ink::trait_definition]
+        pub trait Flip {
+            #[ink(message)]
+            fn flip(&mut self);
+
+            #[ink(message)]
+            fn get(&self) -> bool;
+        }
+    "#;
+
+    // The implementation matches the original ink! trait definition.
+    let results = Analysis::workspace_diagnostics(&[
+        ("traits.rs", original_trait_code),
+        ("lib.rs", contract_code),
+    ]);
+    assert_eq!(results.len(), 2);
+    assert!(results.values().all(Vec::is_empty), "{results:?}");
+
+    // Changes the ink! trait definition
+    // (i.e. adds a parameter to `flip`, renames `get` to `value` and adds a `reset` message).
+    let changed_trait_code = original_trait_code
+        .replace("fn flip(&mut self);", "fn flip(&mut self, times: u8);")
+        .replace(
+            "fn get(&self) -> bool;",
+            "fn value(&self) -> bool;\n\n            #[ink(message)]\n            fn reset(&mut self);",
+        );
+    let workspace = WorkspaceAnalysis::new(&[
+        ("traits.rs", &changed_trait_code),
+        ("lib.rs", contract_code),
+    ]);
+    let results = workspace.diagnostics();
+
+    // The ink! trait definition file is still valid.
+    assert!(results["traits.rs"].is_empty());
+    assert_eq!(workspace.diagnostics_for("traits.rs"), results["traits.rs"]);
+
+    // The implementation file has errors for the mismatched parameter list of `flip`,
+    // the undeclared `get` method and the missing `value` and `reset` messages.
+    let contract_results = &results["lib.rs"];
+    assert_eq!(workspace.diagnostics_for("lib.rs"), *contract_results);
+    assert_eq!(
+        contract_results
+            .iter()
+            .map(|diagnostic| (&contract_code[diagnostic.range], diagnostic.severity))
+            .collect::<Vec<_>>(),
+        vec![
+            ("(&mut self)", Severity::Error),
+            ("get", Severity::Error),
+            ("crate::traits::Flip", Severity::Error),
+        ]
+    );
+    assert!(contract_results[2].message.contains("`value`, `reset`"));
+
+    // Unknown files have no diagnostics.
+    assert!(workspace.diagnostics_for("unknown.rs").is_empty());
+}
+
+// Verifies that diagnostics serialized as JSON have the expected structure.
+#[test]
+#[cfg(feature = "serde")]
+fn all_diagnostics_as_json_works() {
+    // Missing ink! storage item and ink! constructor.
+    let code = r#"
+        #[ink::contract]
+        mod my_contract {
+            impl MyContract {
+                #[ink(message)]
+                pub fn my_message(&self) {}
+            }
+        }
+    "#;
+
+    let output = ink_analyzer::all_diagnostics_as_json(code);
+    let json: serde_json::Value = serde_json::from_str(&output).unwrap();
+    let diagnostics = json.as_array().unwrap();
+
+    // Diagnostics match the analysis results.
+    let expected_results = Analysis::new(code).diagnostics();
+    assert!(!expected_results.is_empty());
+    assert_eq!(diagnostics.len(), expected_results.len());
+    for (diagnostic, expected_diagnostic) in diagnostics.iter().zip(expected_results.iter()) {
+        assert_eq!(diagnostic["message"], expected_diagnostic.message.as_str());
+        assert_eq!(diagnostic["severity"], "error");
+        assert_eq!(
+            diagnostic["range"]["start"],
+            u32::from(expected_diagnostic.range.start())
+        );
+        assert_eq!(
+            diagnostic["range"]["end"],
+            u32::from(expected_diagnostic.range.end())
+        );
+
+        let quickfixes = diagnostic["quickfixes"].as_array().unwrap();
+        let expected_quickfixes = expected_diagnostic
+            .quickfixes
+            .as_deref()
+            .unwrap_or_default();
+        assert_eq!(quickfixes.len(), expected_quickfixes.len());
+        for (quickfix, expected_quickfix) in quickfixes.iter().zip(expected_quickfixes) {
+            assert_eq!(quickfix["label"], expected_quickfix.label.as_str());
+            let edits = quickfix["edits"].as_array().unwrap();
+            assert_eq!(edits.len(), expected_quickfix.edits.len());
+            for (edit, expected_edit) in edits.iter().zip(&expected_quickfix.edits) {
+                assert_eq!(edit["text"], expected_edit.text.as_str());
+                assert!(edit["range"]["start"].is_u64());
+                assert!(edit["range"]["end"].is_u64());
+            }
+        }
+    }
+
+    // No diagnostics for valid code.
+    assert_eq!(
+        ink_analyzer::all_diagnostics_as_json(&test_utils::read_source_code("contracts/flipper")),
+        "[]"
+    );
+}
@@ -0,0 +1,45 @@
+//! Benchmarks for re-parsing an ink! file after a text edit.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use ink_analyzer_ir::syntax::{TextRange, TextSize};
+use ink_analyzer_ir::InkFile;
+
+// Returns the source code of an ink! contract with `n` ink! messages (i.e. `5n + 10` lines).
+fn source_code(n: usize) -> String {
+    let messages: Vec<String> = (1..=n)
+        .map(|idx| {
+            format!(
+                "        #[ink(message)]\n        pub fn my_message_{idx}(&self) -> u32 {{\n            self.value + {idx}\n        }}\n\n"
+            )
+        })
+        .collect();
+    let messages = messages.concat();
+    format!(
+        "#[ink::contract]\nmod my_contract {{\n    #[ink(storage)]\n    pub struct MyContract {{\n        value: u32,\n    }}\n\n    impl MyContract {{\n{messages}    }}\n}}\n"
+    )
+}
+
+fn parse_after_edit(c: &mut Criterion) {
+    let code = source_code(98);
+    assert_eq!(code.lines().count(), 500);
+    let file = InkFile::parse(&code);
+
+    // Inserts a single character in the body of the last ink! message.
+    let offset = TextSize::from((code.rfind("self.value").unwrap() + "self.value".len()) as u32);
+    let range = TextRange::new(offset, offset);
+    let text = "s";
+    let mut new_code = code.clone();
+    new_code.insert_str(usize::from(offset), text);
+
+    let mut group = c.benchmark_group("re-parse a 500 line ink! contract after a 1 character edit");
+    group.bench_function("InkFile::parse", |b| {
+        b.iter(|| black_box(InkFile::parse(&new_code)))
+    });
+    group.bench_function("InkFile::parse_incremental", |b| {
+        b.iter(|| black_box(InkFile::parse_incremental(&file, range, text)))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, parse_after_edit);
+criterion_main!(benches);
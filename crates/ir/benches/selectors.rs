@@ -0,0 +1,54 @@
+//! Benchmarks for looking up ink! messages by selector.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use ink_analyzer_ir::{Contract, InkFile, IsInkCallable};
+
+// Returns the source code of an ink! contract with `n` ink! messages.
+fn source_code(n: usize) -> String {
+    let messages: Vec<String> = (1..=n)
+        .map(|idx| {
+            format!("        #[ink(message)]\n        pub fn my_message_{idx}(&self) {{}}\n\n")
+        })
+        .collect();
+    let messages = messages.concat();
+    format!(
+        "#[ink::contract]\nmod my_contract {{\n    #[ink(storage)]\n    pub struct MyContract {{}}\n\n    impl MyContract {{\n{messages}    }}\n}}\n"
+    )
+}
+
+fn message_by_selector(c: &mut Criterion) {
+    let file = InkFile::parse(&source_code(20));
+    let contract: &Contract = file.contracts().first().unwrap();
+    assert_eq!(contract.messages().len(), 20);
+    let selectors: Vec<[u8; 4]> = contract
+        .messages()
+        .iter()
+        .filter_map(|message| message.composed_selector())
+        .map(|selector| selector.to_bytes())
+        .collect();
+    assert_eq!(selectors.len(), 20);
+
+    let mut group = c.benchmark_group("ink! message lookup by selector for 20 ink! messages");
+    group.bench_function("Contract::find_message_by_selector", |b| {
+        b.iter(|| {
+            for selector in &selectors {
+                black_box(contract.find_message_by_selector(*selector));
+            }
+        })
+    });
+    group.bench_function("linear scan", |b| {
+        b.iter(|| {
+            for selector in &selectors {
+                black_box(contract.messages().iter().find(|message| {
+                    message
+                        .composed_selector()
+                        .is_some_and(|it| it.to_bytes() == *selector)
+                }));
+            }
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, message_by_selector);
+criterion_main!(benches);
@@ -0,0 +1,58 @@
+//! Benchmarks for resolving the ink! trait definitions implemented by ink! impls.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use ink_analyzer_ir::{InkEntity, InkFile, InkImpl};
+
+// Returns the source code of a file with `n` ink! trait definitions, and an ink! contract
+// with `n` ink! impls (each implementing one of the ink! trait definitions).
+fn source_code(n: usize) -> String {
+    let trait_definitions: Vec<String> = (1..=n)
+        .map(|idx| {
+            format!(
+                "#[ink::trait_definition]\npub trait MyTrait{idx} {{\n    #[ink(message)]\n    fn my_message_{idx}(&self);\n}}\n\n"
+            )
+        })
+        .collect();
+    let ink_impls: Vec<String> = (1..=n)
+        .map(|idx| {
+            format!(
+                "    impl super::MyTrait{idx} for MyContract {{\n        #[ink(message)]\n        fn my_message_{idx}(&self) {{}}\n    }}\n\n"
+            )
+        })
+        .collect();
+    let trait_definitions = trait_definitions.concat();
+    let ink_impls = ink_impls.concat();
+    format!(
+        "{trait_definitions}#[ink::contract]\nmod my_contract {{\n    #[ink(storage)]\n    pub struct MyContract {{}}\n\n{ink_impls}}}\n"
+    )
+}
+
+fn trait_definitions_for_impls(c: &mut Criterion) {
+    let file = InkFile::parse(&source_code(10));
+    let ink_impls: Vec<InkImpl> = file
+        .syntax()
+        .descendants()
+        .filter_map(InkImpl::cast)
+        .collect();
+    assert_eq!(ink_impls.len(), 10);
+
+    let mut group = c.benchmark_group("trait definitions for 10 ink! impls");
+    group.bench_function("InkImpl::trait_definition", |b| {
+        b.iter(|| {
+            for ink_impl in &ink_impls {
+                black_box(ink_impl.trait_definition());
+            }
+        })
+    });
+    group.bench_function("InkFile::trait_definitions_for_impl", |b| {
+        b.iter(|| {
+            for ink_impl in &ink_impls {
+                black_box(file.trait_definitions_for_impl(ink_impl));
+            }
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, trait_definitions_for_impls);
+criterion_main!(benches);
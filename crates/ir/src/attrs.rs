@@ -5,7 +5,7 @@ pub mod meta;
 pub mod parser;
 
 use itertools::Itertools;
-use ra_ap_syntax::{ast, AstNode, Direction, SyntaxNode};
+use ra_ap_syntax::{ast, AstNode, Direction, SyntaxKind, SyntaxNode};
 use std::cmp::Ordering;
 use std::fmt;
 
@@ -113,6 +113,16 @@ impl InkAttribute {
         &self.args
     }
 
+    /// Returns the unknown ink! attribute arguments (if any).
+    pub fn unknown_args(&self) -> impl Iterator<Item = &InkArg> {
+        self.args.iter().filter(|arg| arg.is_unknown())
+    }
+
+    /// Returns true if the ink! attribute has any unknown ink! attribute arguments.
+    pub fn has_unknown_args(&self) -> bool {
+        self.unknown_args().next().is_some()
+    }
+
     /// Returns the ink! path segment node.
     pub fn ink(&self) -> &ast::PathSegment {
         &self.ink
@@ -170,6 +180,17 @@ pub enum InkAttributeKind {
     Arg(InkArgKind),
 }
 
+impl fmt::Display for InkAttributeKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            // e.g `#[ink::contract]`.
+            InkAttributeKind::Macro(macro_kind) => write!(f, "#[{macro_kind}]"),
+            // e.g `#[ink(storage)]`.
+            InkAttributeKind::Arg(arg_kind) => write!(f, "#[ink({arg_kind})]"),
+        }
+    }
+}
+
 impl Ord for InkAttributeKind {
     fn cmp(&self, other: &Self) -> Ordering {
         match (self, other) {
@@ -253,26 +274,12 @@ impl From<(&str, &str)> for InkMacroKind {
 
 impl fmt::Display for InkMacroKind {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "{}",
-            match self {
-                // `#[ink::chain_extension]`
-                InkMacroKind::ChainExtension => "chain_extension",
-                // `#[ink::contract]`
-                InkMacroKind::Contract => "contract",
-                // `#[ink::storage_item]`
-                InkMacroKind::StorageItem => "storage_item",
-                // `#[ink::test]`
-                InkMacroKind::Test => "test",
-                // `#[ink::trait_definition]`
-                InkMacroKind::TraitDefinition => "trait_definition",
-                // `#[ink_e2e::test]`
-                InkMacroKind::E2ETest => "e2e test",
-                // unknown ink! attribute path (i.e unknown ink! attribute macro).
-                InkMacroKind::Unknown => "unknown",
-            }
-        )
+        match self {
+            // unknown ink! attribute path (i.e unknown ink! attribute macro).
+            InkMacroKind::Unknown => write!(f, "unknown"),
+            // Full path of the ink! attribute macro (e.g `ink::contract` for `#[ink::contract]`).
+            _ => write!(f, "{}", self.path_as_str()),
+        }
     }
 }
 
@@ -344,6 +351,61 @@ impl InkMacroKind {
     }
 }
 
+/// Returns valid ink! argument kinds for the given syntax kind.
+///
+/// (i.e argument kinds that can be applied to the given syntax kind,
+/// e.g for the `impl` syntax kind, this would be `impl` and `namespace`.
+pub fn valid_ink_args_by_syntax_kind(syntax_kind: SyntaxKind) -> Vec<InkArgKind> {
+    match syntax_kind {
+        // `env` and `keep_attr` can only be applied to a `mod` as siblings of an `ink::contract` macro.
+        SyntaxKind::MODULE | SyntaxKind::MOD_KW => Vec::new(),
+        // `keep_attr` and `namespace` can only be applied to a `trait` as siblings of an `ink::trait_definition` macro.
+        SyntaxKind::TRAIT | SyntaxKind::TRAIT_KW => Vec::new(),
+        // `derive` can only be applied to an ADT (`enum`, `struct` or `union`) as a sibling of an `ink::storage_item` macro.
+        SyntaxKind::STRUCT | SyntaxKind::STRUCT_KW => vec![
+            InkArgKind::Anonymous,
+            InkArgKind::Event,
+            InkArgKind::Storage,
+        ],
+        SyntaxKind::ENUM | SyntaxKind::ENUM_KW | SyntaxKind::UNION | SyntaxKind::UNION_KW => {
+            Vec::new()
+        }
+        SyntaxKind::RECORD_FIELD => vec![InkArgKind::Topic],
+        SyntaxKind::FN | SyntaxKind::FN_KW => vec![
+            InkArgKind::Constructor,
+            InkArgKind::Default,
+            InkArgKind::Extension,
+            InkArgKind::HandleStatus,
+            InkArgKind::Message,
+            InkArgKind::Payable,
+            InkArgKind::Selector,
+        ],
+        SyntaxKind::IMPL | SyntaxKind::IMPL_KW => vec![InkArgKind::Impl, InkArgKind::Namespace],
+        _ => Vec::new(),
+    }
+}
+
+/// Returns valid ink! macro kinds for the given syntax kind.
+///
+/// (i.e macro kinds that can be applied to the given syntax kind,
+/// e.g for the `module` syntax kind, this would be `contract`.
+pub fn valid_ink_macros_by_syntax_kind(syntax_kind: SyntaxKind) -> Vec<InkMacroKind> {
+    match syntax_kind {
+        SyntaxKind::MODULE | SyntaxKind::MOD_KW => vec![InkMacroKind::Contract],
+        SyntaxKind::TRAIT | SyntaxKind::TRAIT_KW => {
+            vec![InkMacroKind::ChainExtension, InkMacroKind::TraitDefinition]
+        }
+        SyntaxKind::ENUM
+        | SyntaxKind::ENUM_KW
+        | SyntaxKind::STRUCT
+        | SyntaxKind::STRUCT_KW
+        | SyntaxKind::UNION
+        | SyntaxKind::UNION_KW => vec![InkMacroKind::StorageItem],
+        SyntaxKind::FN | SyntaxKind::FN_KW => vec![InkMacroKind::Test, InkMacroKind::E2ETest],
+        _ => Vec::new(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -726,22 +788,192 @@ mod tests {
             // Converts the ink! attribute to an array of tuples with
             // ink! attribute argument kind and an inner array of tuples with
             // ink! attribute argument kind and meta value syntax kind for easy comparisons.
-            let actual_ink_attr: Option<(InkAttributeKind, Vec<(InkArgKind, Option<SyntaxKind>)>)> =
-                possible_ink_attr.map(|ink_attr| {
-                    (
-                        // ink! attribute kind.
-                        *ink_attr.kind(),
-                        // array tuples of ink! attribute argument kind and meta value syntax kind.
-                        ink_attr
-                            .args()
-                            .iter()
-                            .map(|arg| (*arg.kind(), arg.value().map(|value| value.kind())))
-                            .collect(),
-                    )
-                });
+            let actual_ink_attr = possible_ink_attr.map(|ink_attr| {
+                (
+                    // ink! attribute kind.
+                    *ink_attr.kind(),
+                    // array tuples of ink! attribute argument kind and meta value syntax kind.
+                    ink_attr
+                        .args()
+                        .iter()
+                        .map(|arg| (*arg.kind(), arg.value().map(|value| value.kind())))
+                        .collect::<Vec<(InkArgKind, Option<SyntaxKind>)>>(),
+                )
+            });
 
             // actual arguments should match expected arguments.
             assert_eq!(actual_ink_attr, expected_ink_attr);
         }
     }
+
+    #[test]
+    fn display_works() {
+        // ink! attribute macros.
+        for (macro_kind, expected_display) in [
+            (InkMacroKind::ChainExtension, "ink::chain_extension"),
+            (InkMacroKind::Contract, "ink::contract"),
+            (InkMacroKind::StorageItem, "ink::storage_item"),
+            (InkMacroKind::Test, "ink::test"),
+            (InkMacroKind::TraitDefinition, "ink::trait_definition"),
+            (InkMacroKind::E2ETest, "ink_e2e::test"),
+            (InkMacroKind::Unknown, "unknown"),
+        ] {
+            assert_eq!(macro_kind.to_string(), expected_display);
+            assert_eq!(
+                InkAttributeKind::Macro(macro_kind).to_string(),
+                format!("#[{expected_display}]")
+            );
+        }
+
+        // ink! attribute arguments.
+        for (arg_kind, expected_display) in [
+            (InkArgKind::AdditionalContracts, "additional_contracts"),
+            (InkArgKind::Anonymous, "anonymous"),
+            (InkArgKind::Constructor, "constructor"),
+            (InkArgKind::Default, "default"),
+            (InkArgKind::Derive, "derive"),
+            (InkArgKind::Env, "env"),
+            (InkArgKind::Environment, "environment"),
+            (InkArgKind::Event, "event"),
+            (InkArgKind::Extension, "extension"),
+            (InkArgKind::HandleStatus, "handle_status"),
+            (InkArgKind::Impl, "impl"),
+            (InkArgKind::KeepAttr, "keep_attr"),
+            (InkArgKind::Message, "message"),
+            (InkArgKind::Namespace, "namespace"),
+            (InkArgKind::Payable, "payable"),
+            (InkArgKind::Selector, "selector"),
+            (InkArgKind::Storage, "storage"),
+            (InkArgKind::Topic, "topic"),
+            (InkArgKind::Unknown, "unknown"),
+        ] {
+            assert_eq!(arg_kind.to_string(), expected_display);
+            assert_eq!(
+                InkAttributeKind::Arg(arg_kind).to_string(),
+                format!("#[ink({expected_display})]")
+            );
+        }
+
+        // Usage in messages.
+        let attr_kind = InkAttributeKind::Macro(InkMacroKind::Contract);
+        assert_eq!(
+            format!("Add {attr_kind} attribute"),
+            "Add #[ink::contract] attribute"
+        );
+        let attr_kind = InkAttributeKind::Arg(InkArgKind::Selector);
+        assert_eq!(
+            format!("Add {} attribute", attr_kind),
+            "Add #[ink(selector)] attribute"
+        );
+    }
+
+    #[test]
+    fn unknown_args_works() {
+        for (code, expected_unknown_args) in [
+            (
+                quote_as_str! {
+                    #[ink(message, bogus_arg)]
+                },
+                vec!["bogus_arg"],
+            ),
+            (
+                quote_as_str! {
+                    #[ink(bogus_arg, selector=1, other_bogus_arg=2)]
+                },
+                vec!["bogus_arg", "other_bogus_arg = 2"],
+            ),
+            (
+                quote_as_str! {
+                    #[ink::contract(env=my::env::Types, bogus_arg)]
+                },
+                vec!["bogus_arg"],
+            ),
+            (
+                quote_as_str! {
+                    #[ink(message, payable)]
+                },
+                vec![],
+            ),
+            (
+                quote_as_str! {
+                    #[ink::contract]
+                },
+                vec![],
+            ),
+        ] {
+            let ink_attr = parse_first_ink_attribute(code);
+
+            assert_eq!(
+                ink_attr.has_unknown_args(),
+                !expected_unknown_args.is_empty(),
+                "code: {code}"
+            );
+            assert_eq!(
+                ink_attr
+                    .unknown_args()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>(),
+                expected_unknown_args,
+                "code: {code}"
+            );
+            for arg in ink_attr.args() {
+                assert_eq!(
+                    arg.is_unknown(),
+                    *arg.kind() == InkArgKind::Unknown,
+                    "code: {code}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn ink_arg_is_explicit_value_works() {
+        for (code, expected_results) in [
+            (
+                quote_as_str! {
+                    #[ink(message, payable, selector=1)]
+                },
+                vec![
+                    (InkArgKind::Message, false),
+                    (InkArgKind::Payable, false),
+                    (InkArgKind::Selector, true),
+                ],
+            ),
+            (
+                quote_as_str! {
+                    #[ink(constructor)]
+                },
+                vec![(InkArgKind::Constructor, false)],
+            ),
+            (
+                quote_as_str! {
+                    #[ink(extension=1, handle_status=false)]
+                },
+                vec![
+                    (InkArgKind::Extension, true),
+                    (InkArgKind::HandleStatus, true),
+                ],
+            ),
+            // Missing value.
+            (
+                quote_as_str! {
+                    #[ink(selector=)]
+                },
+                vec![(InkArgKind::Selector, false)],
+            ),
+        ] {
+            let ink_attr = parse_first_ink_attribute(code);
+
+            let results: Vec<(InkArgKind, bool)> = ink_attr
+                .args()
+                .iter()
+                .map(|arg| {
+                    // `is_flag` is the logical inverse of `is_explicit_value`.
+                    assert_eq!(arg.is_flag(), !arg.is_explicit_value());
+                    (*arg.kind(), arg.is_explicit_value())
+                })
+                .collect();
+            assert_eq!(results, expected_results, "code: {code}");
+        }
+    }
 }
@@ -1,10 +1,11 @@
 //! ink! attribute argument IR.
 
-use ra_ap_syntax::{AstToken, TextRange};
+use ra_ap_syntax::{ast, AstToken, TextRange};
 use std::cmp::Ordering;
 use std::fmt;
 
 use super::meta::{MetaName, MetaNameValue, MetaOption, MetaValue};
+use crate::InkAttribute;
 
 /// An ink! attribute argument.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -13,6 +14,8 @@ pub struct InkArg {
     kind: InkArgKind,
     /// Meta item for ink! attribute argument.
     meta: MetaNameValue,
+    /// AST node for the ink! attribute that contains the ink! attribute argument (if known).
+    attr: Option<ast::Attr>,
 }
 
 impl From<MetaNameValue> for InkArg {
@@ -24,21 +27,43 @@ impl From<MetaNameValue> for InkArg {
                 InkArgKind::Unknown
             },
             meta,
+            attr: None,
         }
     }
 }
 
 impl InkArg {
+    /// Creates an ink! attribute argument from a meta item and the ink! attribute that contains it.
+    pub(crate) fn new(meta: MetaNameValue, attr: ast::Attr) -> Self {
+        Self {
+            attr: Some(attr),
+            ..Self::from(meta)
+        }
+    }
+
     /// Returns the ink! attribute argument kind.
     pub fn kind(&self) -> &InkArgKind {
         &self.kind
     }
 
+    /// Returns true if the ink! attribute argument kind is unknown.
+    pub fn is_unknown(&self) -> bool {
+        self.kind == InkArgKind::Unknown
+    }
+
     /// Returns the meta item for ink! attribute argument.
     pub fn meta(&self) -> &MetaNameValue {
         &self.meta
     }
 
+    /// Returns the ink! attribute that contains the ink! attribute argument (if any).
+    ///
+    /// Returns `None` for ink! attribute arguments created directly from a meta item
+    /// (i.e. via `From<MetaNameValue>`).
+    pub fn surrounding_ink_attribute(&self) -> Option<InkAttribute> {
+        self.attr.clone().and_then(InkAttribute::cast)
+    }
+
     /// Returns the text range of the ink! attribute argument.
     pub fn text_range(&self) -> TextRange {
         self.meta.text_range()
@@ -57,6 +82,34 @@ impl InkArg {
     pub fn value(&self) -> Option<&MetaValue> {
         self.meta.value().result().ok()
     }
+
+    /// Converts the value (if any) into a `u32`,
+    /// if it's an integer literal in either decimal (e.g. `42`) or hexadecimal (e.g. `0x2A`) notation.
+    pub fn value_as_u32(&self) -> Option<u32> {
+        self.value()?.as_u32()
+    }
+
+    /// Converts the value (if any) into a `bool`, if it's a boolean literal (i.e. `true` or `false`).
+    pub fn value_as_bool(&self) -> Option<bool> {
+        self.value()?.as_boolean()
+    }
+
+    /// Returns true if the ink! attribute argument has an explicit value
+    /// (e.g. `selector = 1` or `handle_status = false`).
+    ///
+    /// **NOTE**: Arguments with a separator (i.e. `=` symbol) but no valid value
+    /// (e.g. `selector =`) are not considered to have an explicit value.
+    pub fn is_explicit_value(&self) -> bool {
+        self.meta.eq().is_some() && self.value().is_some()
+    }
+
+    /// Returns true if the ink! attribute argument is a flag (i.e. has no explicit value)
+    /// (e.g. `payable` or `constructor`).
+    ///
+    /// **NOTE**: This is the logical inverse of `is_explicit_value`.
+    pub fn is_flag(&self) -> bool {
+        !self.is_explicit_value()
+    }
 }
 
 impl fmt::Display for InkArg {
@@ -413,3 +466,94 @@ impl InkArgValueKind {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::*;
+    use crate::{InkEntity, InkFile};
+    use ra_ap_syntax::AstNode;
+
+    #[test]
+    fn typed_value_accessors_works() {
+        for (code, expected_u32, expected_bool) in [
+            // Decimal integers.
+            ("#[ink(selector = 0)]", Some(0), None),
+            ("#[ink(selector = 42)]", Some(42), None),
+            ("#[ink(selector = 4294967295)]", Some(u32::MAX), None),
+            // Hexadecimal integers.
+            ("#[ink(selector = 0x0)]", Some(0), None),
+            ("#[ink(selector = 0x2A)]", Some(42), None),
+            ("#[ink(selector = 0x2a)]", Some(42), None),
+            ("#[ink(selector = 0xFFFFFFFF)]", Some(u32::MAX), None),
+            // Booleans.
+            ("#[ink(handle_status = true)]", None, Some(true)),
+            ("#[ink(handle_status = false)]", None, Some(false)),
+            // Out of range integers.
+            ("#[ink(selector = 4294967296)]", None, None),
+            ("#[ink(selector = 0x100000000)]", None, None),
+            ("#[ink(selector = -1)]", None, None),
+            // Hex prefix without digits.
+            ("#[ink(selector = 0x)]", None, None),
+            // Non-literal and non-matching values.
+            ("#[ink(selector = _)]", None, None),
+            (r#"#[ink(selector = "42")]"#, None, None),
+            ("#[ink(handle_status = True)]", None, None),
+            (r#"#[ink(handle_status = "true")]"#, None, None),
+            // No values.
+            ("#[ink(selector)]", None, None),
+            ("#[ink(selector =)]", None, None),
+            ("#[ink(handle_status)]", None, None),
+        ] {
+            let ink_attr = parse_first_ink_attribute(code);
+            let arg = &ink_attr.args()[0];
+
+            assert_eq!(arg.value_as_u32(), expected_u32, "code: {code}");
+            assert_eq!(arg.value_as_bool(), expected_bool, "code: {code}");
+        }
+    }
+
+    #[test]
+    fn surrounding_ink_attribute_works() {
+        let code = r#"
+            #[ink::contract(env=crate::MyEnvironment, keep_attr="foo,bar")]
+            mod my_contract {
+                impl MyContract {
+                    #[ink(message, payable)]
+                    #[ink(selector=1)]
+                    #[ink(default)]
+                    pub fn my_message(&self) {}
+                }
+            }
+        "#;
+        let file = InkFile::parse(code);
+        let ink_attrs: Vec<_> = file
+            .syntax()
+            .descendants()
+            .filter_map(ast::Attr::cast)
+            .filter_map(InkAttribute::cast)
+            .collect();
+        assert_eq!(ink_attrs.len(), 4);
+
+        for ink_attr in ink_attrs {
+            for arg in ink_attr.args() {
+                // The back-reference points to the ink! attribute that contains the argument.
+                let surrounding_ink_attr = arg.surrounding_ink_attribute().unwrap();
+                assert_eq!(
+                    surrounding_ink_attr.syntax(),
+                    ink_attr.syntax(),
+                    "arg: {arg}"
+                );
+                assert_eq!(surrounding_ink_attr.kind(), ink_attr.kind(), "arg: {arg}");
+
+                // There's no back-reference for arguments created directly from meta items.
+                assert!(
+                    InkArg::from(arg.meta().clone())
+                        .surrounding_ink_attribute()
+                        .is_none(),
+                    "arg: {arg}"
+                );
+            }
+        }
+    }
+}
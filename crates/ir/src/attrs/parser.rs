@@ -11,7 +11,7 @@ pub fn parse_ink_args(attr: &ast::Attr) -> Vec<InkArg> {
     if let Some(token_tree) = attr.token_tree() {
         parse_meta_items(&token_tree)
             .into_iter()
-            .map(InkArg::from)
+            .map(|meta| InkArg::new(meta, attr.clone()))
             .collect()
     } else {
         Vec::new()
@@ -2,8 +2,10 @@
 
 use ra_ap_syntax::ast;
 use ra_ap_syntax::ast::HasName;
+use std::collections::HashSet;
 
-use crate::traits::IsInkTrait;
+use crate::traits::{InkEntity, IsInkTrait};
+use crate::tree::ast_ext;
 use crate::Extension;
 
 /// An ink! chain extension.
@@ -35,6 +37,33 @@ impl ChainExtension {
                     })
             })?
     }
+
+    /// Returns the ADT (i.e. struct, enum or union) that the `ErrorCode` associated type resolves to (if any).
+    ///
+    /// Returns `None` if the `ErrorCode` type isn't a path (e.g. `()`) or
+    /// can't be resolved to an ADT item in the current file (e.g. an external type).
+    pub fn resolved_error_code_type(&self) -> Option<ast::Adt> {
+        ast_ext::resolve_item(
+            &ast_ext::path_from_type(&self.error_code()?.ty()?)?,
+            self.syntax(),
+        )
+    }
+
+    /// Returns the ink! extension with the given id (if any).
+    ///
+    /// **NOTE**: If the id is used by multiple ink! extensions (which is an error),
+    /// the first ink! extension is returned.
+    pub fn extension_by_id(&self, id: u32) -> Option<&Extension> {
+        self.extensions
+            .iter()
+            .find(|extension| extension.id() == Some(id))
+    }
+
+    /// Returns the smallest positive id that isn't used by any ink! extension in the ink! chain extension.
+    pub fn next_unused_extension_id(&self) -> u32 {
+        let used_ids: HashSet<u32> = self.extensions.iter().filter_map(Extension::id).collect();
+        (1..).find(|id| !used_ids.contains(id)).unwrap_or_default()
+    }
 }
 
 #[cfg(test)]
@@ -70,4 +99,179 @@ mod tests {
         // `trait` item exists.
         assert!(chain_extension.trait_item().is_some());
     }
+
+    #[test]
+    fn extension_lookup_works() {
+        for (code, expected_ids, expected_next_id) in [
+            // No extensions.
+            (
+                quote_as_str! {
+                    #[ink::chain_extension]
+                    pub trait MyChainExtension {
+                        type ErrorCode = ();
+                    }
+                },
+                vec![],
+                1,
+            ),
+            // 1 extension.
+            (
+                quote_as_str! {
+                    #[ink::chain_extension]
+                    pub trait MyChainExtension {
+                        #[ink(extension=1)]
+                        fn my_extension();
+                    }
+                },
+                vec![1],
+                2,
+            ),
+            (
+                quote_as_str! {
+                    #[ink::chain_extension]
+                    pub trait MyChainExtension {
+                        #[ink(extension=5)]
+                        fn my_extension();
+                    }
+                },
+                vec![5],
+                1,
+            ),
+            // Many extensions (including gaps, overlaps and invalid ids).
+            (
+                quote_as_str! {
+                    #[ink::chain_extension]
+                    pub trait MyChainExtension {
+                        #[ink(extension=1)]
+                        fn my_extension();
+
+                        #[ink(extension=0x2)]
+                        fn my_extension2();
+
+                        #[ink(extension=2)]
+                        fn my_extension3();
+
+                        #[ink(extension=4)]
+                        fn my_extension4();
+
+                        #[ink(extension="3")]
+                        fn my_extension5();
+                    }
+                },
+                vec![1, 2, 4],
+                3,
+            ),
+        ] {
+            let chain_extension: ChainExtension = first_ink_entity_of_type(code);
+
+            for id in 0..=5 {
+                let extension = chain_extension.extension_by_id(id);
+                if expected_ids.contains(&id) {
+                    // Returns the first ink! extension with the id.
+                    assert_eq!(
+                        extension,
+                        chain_extension
+                            .extensions()
+                            .iter()
+                            .find(|extension| extension.id() == Some(id)),
+                        "code: {code}, id: {id}"
+                    );
+                    assert!(extension.is_some(), "code: {code}, id: {id}");
+                } else {
+                    assert!(extension.is_none(), "code: {code}, id: {id}");
+                }
+            }
+
+            assert_eq!(
+                chain_extension.next_unused_extension_id(),
+                expected_next_id,
+                "code: {code}"
+            );
+        }
+
+        // Returns the first ink! extension for overlapping ids.
+        let chain_extension: ChainExtension = first_ink_entity_of_type(quote_as_str! {
+            #[ink::chain_extension]
+            pub trait MyChainExtension {
+                #[ink(extension=2)]
+                fn my_extension();
+
+                #[ink(extension=2)]
+                fn my_extension2();
+            }
+        });
+        assert_eq!(
+            chain_extension.extension_by_id(2),
+            chain_extension.extensions().first()
+        );
+    }
+
+    #[test]
+    fn resolved_error_code_type_works() {
+        for (code, expected_name) in [
+            // `ErrorCode` type defined in the same file.
+            (
+                quote_as_str! {
+                    #[ink::chain_extension]
+                    pub trait MyChainExtension {
+                        type ErrorCode = MyErrorCode;
+                    }
+
+                    pub enum MyErrorCode {
+                        InvalidKey,
+                    }
+                },
+                Some("MyErrorCode"),
+            ),
+            (
+                quote_as_str! {
+                    #[ink::chain_extension]
+                    pub trait MyChainExtension {
+                        type ErrorCode = crate::MyErrorCode;
+                    }
+
+                    pub struct MyErrorCode;
+                },
+                Some("MyErrorCode"),
+            ),
+            // External `ErrorCode` type.
+            (
+                quote_as_str! {
+                    #[ink::chain_extension]
+                    pub trait MyChainExtension {
+                        type ErrorCode = other_crate::MyErrorCode;
+                    }
+                },
+                None,
+            ),
+            // Unit `ErrorCode` type.
+            (
+                quote_as_str! {
+                    #[ink::chain_extension]
+                    pub trait MyChainExtension {
+                        type ErrorCode = ();
+                    }
+                },
+                None,
+            ),
+            // No `ErrorCode` type.
+            (
+                quote_as_str! {
+                    #[ink::chain_extension]
+                    pub trait MyChainExtension {
+                    }
+                },
+                None,
+            ),
+        ] {
+            let chain_extension: ChainExtension = first_ink_entity_of_type(code);
+
+            let resolved_name = chain_extension
+                .resolved_error_code_type()
+                .as_ref()
+                .and_then(HasName::name)
+                .map(|name| name.to_string());
+            assert_eq!(resolved_name.as_deref(), expected_name, "code: {code}");
+        }
+    }
 }
@@ -105,4 +105,68 @@ mod tests {
             assert!(constructor.fn_item().is_some());
         }
     }
+
+    #[test]
+    fn computed_selector_works() {
+        for (code, expected_selector) in [
+            (
+                quote_as_str! {
+                    impl MyContract {
+                        #[ink(constructor)]
+                        pub fn my_constructor() -> Self {}
+                    }
+                },
+                // First 4-bytes of Blake2b-256 hash of "my_constructor".
+                Some([0xE1, 0x1C, 0x2F, 0xAF]),
+            ),
+            (
+                quote_as_str! {
+                    impl MyContract {
+                        #[ink(constructor, selector=1)]
+                        pub fn my_constructor() -> Self {}
+                    }
+                },
+                // Manually provided selectors are ignored.
+                Some([0xE1, 0x1C, 0x2F, 0xAF]),
+            ),
+            (
+                quote_as_str! {
+                    #[ink(namespace="my_namespace")]
+                    impl MyContract {
+                        #[ink(constructor)]
+                        pub fn my_constructor() -> Self {}
+                    }
+                },
+                // First 4-bytes of Blake2b-256 hash of "my_namespace::my_constructor".
+                Some([0x3A, 0x73, 0x91, 0x09]),
+            ),
+            (
+                quote_as_str! {
+                    impl MyTrait for MyContract {
+                        #[ink(constructor)]
+                        fn my_constructor() -> Self {}
+                    }
+                },
+                // First 4-bytes of Blake2b-256 hash of "MyTrait::my_constructor".
+                Some([0x23, 0x5E, 0x72, 0x0C]),
+            ),
+            (
+                quote_as_str! {
+                    impl MyContract {
+                        #[ink(constructor, selector=_)]
+                        pub fn my_constructor() -> Self {}
+                    }
+                },
+                // Wildcard selectors have no computed selector.
+                None,
+            ),
+        ] {
+            let constructor: Constructor = first_ink_entity_of_type(code);
+            assert_eq!(
+                constructor.computed_selector(),
+                expected_selector,
+                "code: {code}"
+            );
+        }
+    }
 }
@@ -1,8 +1,21 @@
 //! ink! contract IR.
 
+use blake2::digest::consts::U8;
+use blake2::digest::Digest;
+use blake2::Blake2b;
+use itertools::Itertools;
 use ra_ap_syntax::ast;
+use ra_ap_syntax::ast::HasName;
+use ra_ap_syntax::AstNode;
+use std::collections::{HashMap, HashSet};
+use std::sync::OnceLock;
 
-use crate::{Constructor, Event, InkE2ETest, InkImpl, InkTest, Message, Storage};
+use crate::selector;
+use crate::traits::{InkEntity, IsInkCallable, IsInkFn, IsInkStruct};
+use crate::tree::{ast_ext, utils};
+use crate::{
+    Constructor, Event, InkE2ETest, InkFile, InkImpl, InkTest, Message, Storage, TraitDefinition,
+};
 
 /// An ink! contract.
 #[ink_analyzer_macro::entity(macro_kind = Contract)]
@@ -27,6 +40,9 @@ pub struct Contract {
     tests: Vec<InkTest>,
     // ink! e2e tests.
     e2e_tests: Vec<InkE2ETest>,
+    // Lazily built selector indices for ink! constructors and ink! messages.
+    #[initializer(default)]
+    selector_indices: SelectorIndices,
 }
 
 impl_has_ink_environment!(Contract, Env);
@@ -35,6 +51,467 @@ impl Contract {
     impl_pub_ast_type_getter!(module, Module);
 
     impl_pub_ink_arg_getter!(keep_attr_arg, KeepAttr, keep_attr);
+
+    /// Returns a report of the required ink! components that are present in (or missing from) the ink! contract.
+    ///
+    /// Ref: <https://github.com/paritytech/ink/blob/v4.1.0/crates/ink/ir/src/ir/item_mod.rs#L328-L330>.
+    pub fn verify_completeness(&self) -> ContractCompleteness {
+        let has_storage = self.storage.is_some();
+        let has_constructor = !self.constructors.is_empty();
+        let has_message = !self.messages.is_empty();
+        let missing = [
+            (has_storage, "ink! storage definition"),
+            (has_constructor, "at least one ink! constructor"),
+            (has_message, "at least one ink! message"),
+        ]
+        .into_iter()
+        .filter_map(|(is_present, description)| (!is_present).then_some(description))
+        .collect();
+
+        ContractCompleteness {
+            has_storage,
+            has_constructor,
+            has_message,
+            missing,
+        }
+    }
+
+    /// Returns the ink! events in scope of the ink! contract, i.e. ink! events defined in the ink! contract,
+    /// followed by ink! events defined in the file outside of any ink! entity
+    /// that are referenced (by name) by paths in the ink! contract's `mod` item.
+    ///
+    /// **NOTE**: ink! 4.x requires ink! events to be defined in the ink! contract's `mod` item,
+    /// so external ink! events are only ever present for invalid (or future ink! versions') code.
+    /// Only `#[ink(event)]` annotated items are included (i.e. ink! 5.x `#[ink::event]` items are excluded).
+    pub fn events_in_scope(&self, file: &InkFile) -> Vec<Event> {
+        let referenced_names: HashSet<String> = self
+            .syntax()
+            .descendants()
+            .filter_map(ast::Path::cast)
+            .filter_map(|path| path.segment()?.name_ref())
+            .map(|name_ref| name_ref.to_string())
+            .collect();
+        let is_referenced = |event: &Event| {
+            event
+                .struct_item()
+                .and_then(HasName::name)
+                .is_some_and(|name| referenced_names.contains(&name.to_string()))
+        };
+        self.events
+            .iter()
+            .cloned()
+            .chain(utils::ink_closest_descendants::<Event>(file.syntax()).filter(is_referenced))
+            .collect()
+    }
+
+    /// Returns a stable hash of the ink! contract's interface
+    /// (i.e. the names, parameter types, return types and composed selectors of
+    /// all ink! constructors and ink! messages).
+    ///
+    /// **NOTE**: The hash is independent of formatting, comments and the order of ink! callables,
+    /// so it only changes for (potentially breaking) changes to the interface.
+    pub fn source_hash(&self) -> u64 {
+        let signatures = self
+            .constructors
+            .iter()
+            .map(|constructor| format!("constructor {}", callable_signature(constructor)))
+            .chain(
+                self.messages
+                    .iter()
+                    .map(|message| format!("message {}", callable_signature(message))),
+            )
+            .sorted()
+            .join("\n");
+
+        // Computes the BLAKE-2b 64-bit hash of the signatures.
+        let mut hasher = <Blake2b<U8>>::new();
+        hasher.update(signatures.into_bytes());
+        u64::from_be_bytes(hasher.finalize().into())
+    }
+
+    /// Returns the ink! impl (if any) that implements the given ink! trait definition.
+    ///
+    /// **NOTE**: If the ink! trait definition is implemented multiple times (which is an error),
+    /// the first ink! impl is returned, see [`Self::impls_for_trait`] for all of them.
+    pub fn impl_for_trait(&self, trait_definition: &TraitDefinition) -> Option<&InkImpl> {
+        self.impls
+            .iter()
+            .find(|ink_impl| ink_impl.trait_definition().as_ref() == Some(trait_definition))
+    }
+
+    /// Returns all ink! impls that implement the given ink! trait definition.
+    pub fn impls_for_trait(&self, trait_definition: &TraitDefinition) -> Vec<&InkImpl> {
+        self.impls
+            .iter()
+            .filter(|ink_impl| ink_impl.trait_definition().as_ref() == Some(trait_definition))
+            .collect()
+    }
+
+    /// Returns a lookup table of composed selectors to ink! messages.
+    ///
+    /// **NOTE**: For overlapping selectors (which are also a diagnostic error),
+    /// the table contains the first ink! message encountered.
+    pub fn message_selector_table(&self) -> HashMap<[u8; 4], &Message> {
+        selector_table(&self.messages)
+    }
+
+    /// Returns a lookup table of composed selectors to ink! constructors.
+    ///
+    /// **NOTE**: For overlapping selectors (which are also a diagnostic error),
+    /// the table contains the first ink! constructor encountered.
+    pub fn constructor_selector_table(&self) -> HashMap<[u8; 4], &Constructor> {
+        selector_table(&self.constructors)
+    }
+
+    /// Returns a map of composed selectors to ink! messages
+    /// (see [`Self::message_selector_table`] doc).
+    pub fn message_selector_map(&self) -> HashMap<[u8; 4], &Message> {
+        self.message_selector_table()
+    }
+
+    /// Returns a map of composed selectors to ink! constructors
+    /// (see [`Self::constructor_selector_table`] doc).
+    pub fn constructor_selector_map(&self) -> HashMap<[u8; 4], &Constructor> {
+        self.constructor_selector_table()
+    }
+
+    /// Returns the ink! message with the given composed selector (if any).
+    ///
+    /// **NOTE**: The lookup table is built on first use and reused by subsequent lookups.
+    /// For overlapping selectors (which are also a diagnostic error),
+    /// the first ink! message encountered is returned.
+    pub fn find_message_by_selector(&self, selector: [u8; 4]) -> Option<&Message> {
+        let idx = self
+            .selector_indices
+            .messages
+            .get_or_init(|| selector_index(&self.messages))
+            .get(&selector)?;
+        self.messages.get(*idx)
+    }
+
+    /// Returns the ink! constructor with the given composed selector (if any).
+    ///
+    /// **NOTE**: The lookup table is built on first use and reused by subsequent lookups.
+    /// For overlapping selectors (which are also a diagnostic error),
+    /// the first ink! constructor encountered is returned.
+    pub fn find_constructor_by_selector(&self, selector: [u8; 4]) -> Option<&Constructor> {
+        let idx = self
+            .selector_indices
+            .constructors
+            .get_or_init(|| selector_index(&self.constructors))
+            .get(&selector)?;
+        self.constructors.get(*idx)
+    }
+
+    /// Returns the selector conflicts among ink! constructors and among ink! messages
+    /// (i.e. pairs of ink! callables of the same kind with the same composed selector).
+    ///
+    /// Each conflict pairs an ink! callable with the first ink! callable (of the same kind)
+    /// that has the same selector. Conflicts for ink! constructors are returned first,
+    /// and conflicts are otherwise ordered by the position of the second ink! callable.
+    ///
+    /// **NOTE**: Overlaps between ink! constructor and ink! message selectors are allowed.
+    pub fn callable_selector_conflicts(&self) -> Vec<SelectorConflict> {
+        let mut conflicts = selector_conflicts(&self.constructors, CallableRef::Constructor);
+        conflicts.extend(selector_conflicts(&self.messages, CallableRef::Message));
+        conflicts
+    }
+
+    /// Returns a Rust expression for instantiating the ink! contract (from another ink! contract)
+    /// using the given ink! constructor (e.g. `MyContractRef::new(a, b).code_hash(code_hash).instantiate()`).
+    ///
+    /// **NOTE**: Parameter names are preserved from the ink! constructor's parameter list.
+    ///
+    /// Ref: <https://use.ink/basics/cross-contract-calling#contractref>.
+    ///
+    /// Ref: <https://github.com/paritytech/ink/blob/v4.1.0/crates/env/src/call/create_builder.rs#L200-L279>.
+    pub fn constructor_invocation_snippet(&self, constructor: &Constructor) -> String {
+        let contract_name = self
+            .storage
+            .as_ref()
+            .and_then(Storage::struct_item)
+            .and_then(HasName::name)
+            .map_or("Contract".to_string(), |name| name.to_string());
+        let fn_item = constructor.fn_item();
+        let constructor_name = fn_item
+            .and_then(HasName::name)
+            .map_or("new".to_string(), |name| name.to_string());
+        let args = fn_item
+            .map(ast_ext::fn_param_names)
+            .unwrap_or_default()
+            .join(", ");
+
+        format!("{contract_name}Ref::{constructor_name}({args}).code_hash(code_hash).instantiate()")
+    }
+
+    /// Returns an ABI for the ink! contract as JSON in the ink! metadata format
+    /// (i.e. with a `version` field, and `spec.constructors` and `spec.messages` arrays).
+    ///
+    /// **NOTE**: Types aren't resolved, so parameter and return types are described
+    /// using their AST-level representation (i.e. as written in the source code).
+    ///
+    /// Ref: <https://use.ink/basics/metadata>.
+    #[cfg(feature = "serde")]
+    pub fn generate_abi_json(&self) -> String {
+        use serde_json::json;
+
+        let name = self
+            .module()
+            .and_then(HasName::name)
+            .map(|name| name.to_string());
+        json!({
+            "version": "4",
+            "contract": {
+                "name": name,
+            },
+            "spec": {
+                "constructors": self
+                    .constructors
+                    .iter()
+                    .map(|constructor| abi::callable_json(constructor, None))
+                    .collect::<Vec<_>>(),
+                "messages": self
+                    .messages
+                    .iter()
+                    .map(|message| abi::callable_json(message, Some(message.is_mutating())))
+                    .collect::<Vec<_>>(),
+            },
+        })
+        .to_string()
+    }
+}
+
+/// Utilities for generating an ABI (in the ink! metadata format) for ink! callables.
+#[cfg(feature = "serde")]
+mod abi {
+    use ra_ap_syntax::ast::{HasDocComments, HasName};
+    use ra_ap_syntax::{ast, AstNode};
+    use serde_json::{json, Value};
+
+    use crate::selector;
+    use crate::traits::IsInkCallable;
+
+    /// Returns the ABI for an ink! callable
+    /// (`mutates` is only included for ink! messages).
+    pub fn callable_json<T>(callable: &T, mutates: Option<bool>) -> Value
+    where
+        T: IsInkCallable,
+    {
+        let fn_item = callable.fn_item();
+        let args: Vec<Value> = fn_item
+            .and_then(ast::Fn::param_list)
+            .map(|param_list| {
+                param_list
+                    .params()
+                    .map(|param| {
+                        json!({
+                            "label": param.pat().map(|pat| pat.syntax().to_string()),
+                            "type": param.ty().as_ref().map(type_json),
+                        })
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        let docs: Vec<String> = fn_item
+            .map(|fn_item| {
+                fn_item
+                    .doc_comments()
+                    .filter_map(|comment| comment.doc_comment().map(ToString::to_string))
+                    .collect()
+            })
+            .unwrap_or_default();
+        let mut spec = json!({
+            "label": fn_item.and_then(HasName::name).map(|name| name.to_string()),
+            "selector": callable
+                .composed_selector()
+                .map(|selector| selector::format_selector(selector.into_be_u32())),
+            "payable": callable.payable_arg().is_some(),
+            "default": callable.default_arg().is_some(),
+            "args": args,
+            "returnType": fn_item
+                .and_then(ast::Fn::ret_type)
+                .and_then(|ret_type| ret_type.ty())
+                .as_ref()
+                .map(type_json),
+            "docs": docs,
+        });
+        if let Some(mutates) = mutates {
+            spec["mutates"] = json!(mutates);
+        }
+        spec
+    }
+
+    /// Returns the ABI type description for a type
+    /// (i.e. the path segments of the type as the display name, and the type as written).
+    fn type_json(ty: &ast::Type) -> Value {
+        let display_name: Vec<String> = match ty {
+            ast::Type::PathType(path_type) => path_type
+                .path()
+                .map(|path| {
+                    path.segments()
+                        .filter_map(|segment| segment.name_ref())
+                        .map(|name_ref| name_ref.to_string())
+                        .collect()
+                })
+                .unwrap_or_default(),
+            _ => vec![ty.syntax().to_string()],
+        };
+        json!({
+            "displayName": display_name,
+            "type": ty.syntax().to_string(),
+        })
+    }
+}
+
+/// Returns a normalized signature (i.e. with whitespace and comments removed) for an ink! callable
+/// (e.g. `my_message(&self,u8)->bool@0x00000001`).
+fn callable_signature<T>(callable: &T) -> String
+where
+    T: IsInkCallable,
+{
+    let fn_item = callable.fn_item();
+    let name = fn_item
+        .and_then(HasName::name)
+        .map(|name| name.to_string())
+        .unwrap_or_default();
+    let params = fn_item
+        .and_then(ast::Fn::param_list)
+        .map(|param_list| {
+            param_list
+                .self_param()
+                .map(|self_param| ast_ext::strip_trivia(self_param.syntax()))
+                .into_iter()
+                .chain(param_list.params().map(|param| {
+                    param
+                        .ty()
+                        .map(|ty| ast_ext::strip_trivia(ty.syntax()))
+                        .unwrap_or_default()
+                }))
+                .join(",")
+        })
+        .unwrap_or_default();
+    let ret_type = fn_item
+        .and_then(ast::Fn::ret_type)
+        .and_then(|ret_type| ret_type.ty())
+        .map(|ty| ast_ext::strip_trivia(ty.syntax()))
+        .unwrap_or_default();
+    let selector = callable
+        .composed_selector()
+        .map(|selector| selector::format_selector(selector.into_be_u32()))
+        .unwrap_or_default();
+    format!("{name}({params})->{ret_type}@{selector}")
+}
+
+/// Returns a lookup table of composed selectors to ink! callables,
+/// keeping the first ink! callable encountered for overlapping selectors.
+fn selector_table<T>(callables: &[T]) -> HashMap<[u8; 4], &T>
+where
+    T: IsInkCallable,
+{
+    selector_index(callables)
+        .into_iter()
+        .map(|(selector, idx)| (selector, &callables[idx]))
+        .collect()
+}
+
+/// Returns a lookup table of composed selectors to indices of ink! callables,
+/// keeping the first ink! callable encountered for overlapping selectors.
+fn selector_index<T>(callables: &[T]) -> HashMap<[u8; 4], usize>
+where
+    T: IsInkCallable,
+{
+    let mut index = HashMap::new();
+    for (idx, callable) in callables.iter().enumerate() {
+        if let Some(selector) = callable.composed_selector() {
+            index.entry(selector.to_bytes()).or_insert(idx);
+        }
+    }
+    index
+}
+
+/// Lazily built lookup tables of composed selectors to indices of ink! constructors and ink! messages
+/// (see [`Contract::find_constructor_by_selector`] and [`Contract::find_message_by_selector`]).
+#[derive(Debug, Clone, Default)]
+struct SelectorIndices {
+    constructors: OnceLock<HashMap<[u8; 4], usize>>,
+    messages: OnceLock<HashMap<[u8; 4], usize>>,
+}
+
+// Selector indices are derived from the ink! contract's callables,
+// so they're ignored when comparing ink! contracts.
+impl PartialEq for SelectorIndices {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for SelectorIndices {}
+
+/// Returns the selector conflicts for a list of ink! callables of the same kind.
+fn selector_conflicts<T>(
+    callables: &[T],
+    callable_ref: fn(T) -> CallableRef,
+) -> Vec<SelectorConflict>
+where
+    T: IsInkCallable + Clone,
+{
+    let mut conflicts = Vec::new();
+    let mut seen: HashMap<[u8; 4], &T> = HashMap::new();
+    let callables_with_selectors = callables.iter().filter_map(|callable| {
+        callable
+            .composed_selector()
+            .map(|selector| (callable, selector.to_bytes()))
+    });
+    for (idx, (callable, selector)) in callables_with_selectors.enumerate() {
+        match seen.get(&selector) {
+            Some(first) => conflicts.push(SelectorConflict {
+                selector,
+                first: callable_ref((*first).clone()),
+                second: callable_ref(callable.clone()),
+                second_index: idx,
+            }),
+            None => {
+                seen.insert(selector, callable);
+            }
+        }
+    }
+    conflicts
+}
+
+/// A reference to an ink! callable entity (i.e. an ink! message or ink! constructor).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CallableRef {
+    /// An ink! message.
+    Message(Message),
+    /// An ink! constructor.
+    Constructor(Constructor),
+}
+
+/// A selector conflict between two ink! callables of the same kind in an ink! contract.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SelectorConflict {
+    /// The conflicting composed selector.
+    pub selector: [u8; 4],
+    /// The first ink! callable with the selector.
+    pub first: CallableRef,
+    /// A subsequent ink! callable with the same selector.
+    pub second: CallableRef,
+    /// The position of the subsequent ink! callable among the ink! callables (of the same kind)
+    /// with a composed selector.
+    pub second_index: usize,
+}
+
+/// A report of the required ink! components that are present in (or missing from) an ink! contract.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContractCompleteness {
+    /// Whether the ink! contract has an ink! storage definition.
+    pub has_storage: bool,
+    /// Whether the ink! contract has at least one ink! constructor.
+    pub has_constructor: bool,
+    /// Whether the ink! contract has at least one ink! message.
+    pub has_message: bool,
+    /// Human-readable descriptions of the missing required ink! components (if any).
+    pub missing: Vec<&'static str>,
 }
 
 #[cfg(test)]
@@ -42,7 +519,7 @@ mod tests {
     use super::*;
     use crate::test_utils::*;
     use crate::traits::{HasInkEnvironment, InkEntity};
-    use test_utils::quote_as_str;
+    use test_utils::{quote_as_str, quote_as_string};
 
     #[test]
     fn cast_works() {
@@ -181,4 +658,757 @@ mod tests {
         // `mod` item exists.
         assert!(contract.module().is_some());
     }
+
+    #[test]
+    fn verify_completeness_works() {
+        for (
+            code,
+            expected_has_storage,
+            expected_has_constructor,
+            expected_has_message,
+            expected_missing,
+        ) in [
+            // Complete contract.
+            (
+                quote_as_str! {
+                    #[ink::contract]
+                    mod my_contract {
+                        #[ink(storage)]
+                        pub struct MyContract {}
+
+                        impl MyContract {
+                            #[ink(constructor)]
+                            pub fn my_constructor() -> Self {}
+
+                            #[ink(message)]
+                            pub fn my_message(&self) {}
+                        }
+                    }
+                },
+                true,
+                true,
+                true,
+                vec![],
+            ),
+            // Missing storage.
+            (
+                quote_as_str! {
+                    #[ink::contract]
+                    mod my_contract {
+                        impl MyContract {
+                            #[ink(constructor)]
+                            pub fn my_constructor() -> Self {}
+
+                            #[ink(message)]
+                            pub fn my_message(&self) {}
+                        }
+                    }
+                },
+                false,
+                true,
+                true,
+                vec!["ink! storage definition"],
+            ),
+            // Missing constructors and messages.
+            (
+                quote_as_str! {
+                    #[ink::contract]
+                    mod my_contract {
+                        #[ink(storage)]
+                        pub struct MyContract {}
+                    }
+                },
+                true,
+                false,
+                false,
+                vec!["at least one ink! constructor", "at least one ink! message"],
+            ),
+            // Empty contract.
+            (
+                quote_as_str! {
+                    #[ink::contract]
+                    mod my_contract {
+                    }
+                },
+                false,
+                false,
+                false,
+                vec![
+                    "ink! storage definition",
+                    "at least one ink! constructor",
+                    "at least one ink! message",
+                ],
+            ),
+        ] {
+            let contract = Contract::cast(parse_first_syntax_node(code)).unwrap();
+
+            assert_eq!(
+                contract.verify_completeness(),
+                ContractCompleteness {
+                    has_storage: expected_has_storage,
+                    has_constructor: expected_has_constructor,
+                    has_message: expected_has_message,
+                    missing: expected_missing,
+                },
+                "code: {code}"
+            );
+        }
+    }
+
+    #[test]
+    fn constructor_invocation_snippet_works() {
+        for (constructor, expected_snippet) in [
+            // Zero arguments.
+            (
+                quote_as_string! {
+                    #[ink(constructor)]
+                    pub fn new() -> Self {}
+                },
+                "MyContractRef::new()",
+            ),
+            // One argument.
+            (
+                quote_as_string! {
+                    #[ink(constructor)]
+                    pub fn new(init_value: bool) -> Self {}
+                },
+                "MyContractRef::new(init_value)",
+            ),
+            // Two named arguments.
+            (
+                quote_as_string! {
+                    #[ink(constructor)]
+                    pub fn with_values(total_supply: Balance, owner: AccountId) -> Self {}
+                },
+                "MyContractRef::with_values(total_supply, owner)",
+            ),
+        ] {
+            let code = format!(
+                "#[ink::contract]\nmod my_contract {{\n#[ink(storage)]\npub struct MyContract {{}}\nimpl MyContract {{\n{constructor}\n}}\n}}"
+            );
+            let contract: Contract = first_ink_entity_of_type(&code);
+            let constructor = &contract.constructors()[0];
+
+            assert_eq!(
+                contract.constructor_invocation_snippet(constructor),
+                format!("{expected_snippet}.code_hash(code_hash).instantiate()")
+            );
+        }
+    }
+
+    #[test]
+    fn selector_tables_works() {
+        let code = test_utils::read_source_code("contracts/erc20");
+        let contract: Contract = first_ink_entity_of_type(&code);
+
+        // Verifies message selectors.
+        let message_table = contract.message_selector_table();
+        assert_eq!(message_table.len(), 6);
+        for (selector, expected_name) in [
+            (0xdb6375a8_u32, "total_supply"),
+            (0x0f755a56, "balance_of"),
+            (0x84a15da1, "transfer"),
+            (0x0b396f18, "transfer_from"),
+        ] {
+            let message = message_table.get(&selector.to_be_bytes()).unwrap();
+            assert_eq!(
+                message
+                    .fn_item()
+                    .and_then(HasName::name)
+                    .unwrap()
+                    .to_string(),
+                expected_name
+            );
+        }
+
+        // Verifies constructor selectors.
+        let constructor_table = contract.constructor_selector_table();
+        assert_eq!(constructor_table.len(), 1);
+        let constructor = constructor_table
+            .get(&0x9bae9d5e_u32.to_be_bytes())
+            .unwrap();
+        assert_eq!(
+            constructor
+                .fn_item()
+                .and_then(HasName::name)
+                .unwrap()
+                .to_string(),
+            "new"
+        );
+    }
+
+    #[test]
+    fn selector_maps_works() {
+        for source in ["contracts/flipper", "contracts/erc20"] {
+            let code = test_utils::read_source_code(source);
+            let contract: Contract = first_ink_entity_of_type(&code);
+
+            let message_map = contract.message_selector_map();
+            assert_eq!(message_map, contract.message_selector_table());
+            assert_eq!(message_map.len(), contract.messages().len());
+            for message in contract.messages() {
+                let selector = message.composed_selector().unwrap().to_bytes();
+                assert_eq!(message_map.get(&selector), Some(&message));
+            }
+
+            let constructor_map = contract.constructor_selector_map();
+            assert_eq!(constructor_map, contract.constructor_selector_table());
+            assert_eq!(constructor_map.len(), contract.constructors().len());
+            for constructor in contract.constructors() {
+                let selector = constructor.composed_selector().unwrap().to_bytes();
+                assert_eq!(constructor_map.get(&selector), Some(&constructor));
+            }
+        }
+    }
+
+    #[test]
+    fn selector_tables_match_computed_selectors() {
+        for source in ["contracts/flipper", "contracts/erc20"] {
+            let code = test_utils::read_source_code(source);
+            let contract: Contract = first_ink_entity_of_type(&code);
+
+            // None of the callables in these contracts have manual selectors,
+            // so composed and computed selectors are the same.
+            let message_table = contract.message_selector_table();
+            assert_eq!(message_table.len(), contract.messages().len());
+            for message in contract.messages() {
+                let selector = message.computed_selector().unwrap();
+                assert_eq!(message_table.get(&selector), Some(&message));
+            }
+
+            let constructor_table = contract.constructor_selector_table();
+            assert_eq!(constructor_table.len(), contract.constructors().len());
+            for constructor in contract.constructors() {
+                let selector = constructor.computed_selector().unwrap();
+                assert_eq!(constructor_table.get(&selector), Some(&constructor));
+            }
+        }
+
+        // Manual selectors take precedence over computed selectors.
+        let code = quote_as_string! {
+            #[ink::contract]
+            mod my_contract {
+                impl MyContract {
+                    #[ink(message, selector=0xA)]
+                    pub fn my_message(&self) {}
+                }
+            }
+        };
+        let contract: Contract = first_ink_entity_of_type(&code);
+        let message = contract.messages().first().unwrap();
+        let message_table = contract.message_selector_table();
+        assert_eq!(message_table.get(&[0, 0, 0, 0xA]), Some(&message));
+        assert!(!message_table.contains_key(&message.computed_selector().unwrap()));
+    }
+
+    #[test]
+    fn selector_tables_keep_first_overlapping_callable() {
+        let code = quote_as_string! {
+            #[ink::contract]
+            mod my_contract {
+                impl MyContract {
+                    #[ink(constructor, selector=1)]
+                    pub fn my_constructor() -> Self {}
+
+                    #[ink(constructor, selector=1)]
+                    pub fn my_constructor2() -> Self {}
+
+                    #[ink(message, selector=1)]
+                    pub fn my_message(&self) {}
+
+                    #[ink(message, selector=1)]
+                    pub fn my_message2(&self) {}
+                }
+            }
+        };
+        let contract: Contract = first_ink_entity_of_type(&code);
+
+        let message_table = contract.message_selector_table();
+        assert_eq!(message_table.len(), 1);
+        assert_eq!(
+            message_table.get(&[0, 0, 0, 1]),
+            contract.messages().first().as_ref()
+        );
+
+        let constructor_table = contract.constructor_selector_table();
+        assert_eq!(constructor_table.len(), 1);
+        assert_eq!(
+            constructor_table.get(&[0, 0, 0, 1]),
+            contract.constructors().first().as_ref()
+        );
+    }
+
+    #[test]
+    fn find_callable_by_selector_works() {
+        let code = test_utils::read_source_code("contracts/erc20");
+        let contract: Contract = first_ink_entity_of_type(&code);
+        let unused_contract = contract.clone();
+
+        let name = |fn_item: Option<&ast::Fn>| fn_item.and_then(HasName::name).unwrap().to_string();
+        for (selector, expected_name) in [
+            (0xdb6375a8_u32, "total_supply"),
+            (0x0f755a56, "balance_of"),
+            (0x84a15da1, "transfer"),
+            (0x0b396f18, "transfer_from"),
+        ] {
+            let message = contract
+                .find_message_by_selector(selector.to_be_bytes())
+                .unwrap();
+            assert_eq!(name(message.fn_item()), expected_name);
+        }
+        let constructor = contract
+            .find_constructor_by_selector(0x9bae9d5e_u32.to_be_bytes())
+            .unwrap();
+        assert_eq!(name(constructor.fn_item()), "new");
+
+        // Constructor and message selectors are looked up separately.
+        assert!(contract
+            .find_message_by_selector(0x9bae9d5e_u32.to_be_bytes())
+            .is_none());
+        assert!(contract
+            .find_constructor_by_selector(0x84a15da1_u32.to_be_bytes())
+            .is_none());
+
+        // Lookups agree with the selector tables.
+        for message in contract.messages() {
+            let selector = message.composed_selector().unwrap().to_bytes();
+            assert_eq!(
+                contract.find_message_by_selector(selector),
+                contract.message_selector_table().get(&selector).copied()
+            );
+        }
+
+        // Building the lookup tables doesn't affect equality.
+        assert_eq!(contract, unused_contract);
+    }
+
+    #[test]
+    fn find_callable_by_selector_keeps_first_overlapping_callable() {
+        let code = quote_as_string! {
+            #[ink::contract]
+            mod my_contract {
+                impl MyContract {
+                    #[ink(constructor, selector=1)]
+                    pub fn my_constructor() -> Self {}
+
+                    #[ink(constructor, selector=1)]
+                    pub fn my_constructor2() -> Self {}
+
+                    #[ink(message, selector=1)]
+                    pub fn my_message(&self) {}
+
+                    #[ink(message, selector=1)]
+                    pub fn my_message2(&self) {}
+                }
+            }
+        };
+        let contract: Contract = first_ink_entity_of_type(&code);
+
+        assert_eq!(
+            contract.find_message_by_selector([0, 0, 0, 1]),
+            contract.messages().first()
+        );
+        assert_eq!(
+            contract.find_constructor_by_selector([0, 0, 0, 1]),
+            contract.constructors().first()
+        );
+        assert!(contract.find_message_by_selector([0, 0, 0, 2]).is_none());
+    }
+
+    #[test]
+    fn events_in_scope_works() {
+        // ink! 4.x contract.
+        let code = test_utils::read_source_code("contracts/erc20");
+        let file = InkFile::parse(&code);
+        let contract = &file.contracts()[0];
+
+        // Verifies parity with ink! events defined in the ink! contract.
+        assert_eq!(contract.events_in_scope(&file), contract.events());
+
+        // ink! events defined outside the ink! contract.
+        let code = quote_as_string! {
+            #[ink(event)]
+            pub struct ExternalEvent {}
+
+            #[ink(event)]
+            pub struct UnusedEvent {}
+
+            #[ink::contract]
+            mod my_contract {
+                #[ink(event)]
+                pub struct MyEvent {}
+
+                impl MyContract {
+                    #[ink(message)]
+                    pub fn my_message(&self) {
+                        self.env().emit_event(crate::ExternalEvent {});
+                    }
+                }
+            }
+        };
+        let file = InkFile::parse(&code);
+        let contract = &file.contracts()[0];
+
+        // Verifies that only ink! events referenced in the ink! contract are included.
+        let names: Vec<String> = contract
+            .events_in_scope(&file)
+            .iter()
+            .filter_map(|event| event.struct_item().and_then(HasName::name))
+            .map(|name| name.to_string())
+            .collect();
+        assert_eq!(names, ["MyEvent", "ExternalEvent"]);
+    }
+
+    #[test]
+    fn source_hash_works() {
+        let code = quote_as_string! {
+            #[ink::contract]
+            mod my_contract {
+                #[ink(storage)]
+                pub struct MyContract {}
+
+                impl MyContract {
+                    #[ink(constructor)]
+                    pub fn new(init_value: bool) -> Self {}
+
+                    #[ink(message)]
+                    pub fn get(&self) -> Option<u8> {}
+
+                    #[ink(message, selector=1)]
+                    pub fn set(&mut self, value: Vec<u8>) {}
+                }
+            }
+        };
+        let contract: Contract = first_ink_entity_of_type(&code);
+        let hash = contract.source_hash();
+
+        // Different formatting, comments, parameter names, bodies and ink! callable order.
+        let reformatted_code = r#"
+#[ink::contract]
+mod my_contract {
+    #[ink(storage)]
+    pub struct MyContract {
+        value: u8,
+    }
+
+    impl MyContract {
+        #[ink(message, selector = 1)]
+        pub fn set(&mut self, new_value: Vec< u8 >) {
+            // A comment.
+            todo!()
+        }
+
+        #[ink(constructor)]
+        pub fn new(value: bool) -> Self {
+            todo!()
+        }
+
+        /// Docs.
+        #[ink(message)]
+        pub fn get(&self) -> Option</* comment */ u8> {
+            todo!()
+        }
+    }
+}
+"#;
+        let reformatted_contract: Contract = first_ink_entity_of_type(reformatted_code);
+        assert_eq!(reformatted_contract.source_hash(), hash);
+
+        // Interface changes.
+        for (from, to) in [
+            // Added parameter.
+            (
+                "pub fn new(value: bool)",
+                "pub fn new(value: bool, other: u8)",
+            ),
+            // Changed return type.
+            ("-> Option</* comment */ u8>", "-> Option<u16>"),
+            // Changed selector.
+            ("selector = 1", "selector = 2"),
+            // Changed receiver.
+            ("pub fn get(&self)", "pub fn get(&mut self)"),
+        ] {
+            let changed_code = reformatted_code.replace(from, to);
+            let changed_contract: Contract = first_ink_entity_of_type(&changed_code);
+            assert_ne!(changed_contract.source_hash(), hash, "change: {to}");
+        }
+    }
+
+    #[test]
+    fn impl_for_trait_works() {
+        for (impls, expected_n_impls) in [
+            // No implementations.
+            ("", 0),
+            (
+                "impl OtherTrait for MyContract {
+                    fn other_method(&self) {}
+                }",
+                0,
+            ),
+            // One implementation.
+            (
+                "impl MyTrait for MyContract {
+                    #[ink(message)]
+                    fn my_message(&self) {}
+                }",
+                1,
+            ),
+            // Two implementations.
+            (
+                "impl MyTrait for MyContract {
+                    #[ink(message)]
+                    fn my_message(&self) {}
+                }
+
+                impl self::MyTrait for MyContract {
+                    #[ink(message)]
+                    fn my_message(&self) {}
+                }",
+                2,
+            ),
+        ] {
+            let code = format!(
+                r#"
+                #[ink::contract]
+                mod my_contract {{
+                    #[ink::trait_definition]
+                    pub trait MyTrait {{
+                        #[ink(message)]
+                        fn my_message(&self);
+                    }}
+
+                    #[ink(storage)]
+                    pub struct MyContract {{}}
+
+                    impl MyContract {{
+                        #[ink(constructor)]
+                        pub fn new() -> Self {{}}
+                    }}
+
+                    {impls}
+                }}
+                "#
+            );
+            let file = InkFile::parse(&code);
+            let contract = &file.contracts()[0];
+            let trait_definition = &file.trait_definitions()[0];
+
+            let impls_for_trait = contract.impls_for_trait(trait_definition);
+            assert_eq!(impls_for_trait.len(), expected_n_impls, "impls: {impls}");
+            assert!(impls_for_trait
+                .iter()
+                .all(|ink_impl| ink_impl.trait_type().is_some()));
+            assert_eq!(
+                contract.impl_for_trait(trait_definition),
+                impls_for_trait.first().copied(),
+                "impls: {impls}"
+            );
+        }
+    }
+
+    #[test]
+    fn callable_selector_conflicts_works() {
+        for (code, expected_conflicts) in [
+            // (code, Vec<(selector, first_name, second_name, second_index)>)
+            (
+                quote_as_string! {
+                    impl MyContract {
+                        #[ink(constructor)]
+                        pub fn new() -> Self {}
+
+                        #[ink(message)]
+                        pub fn my_message(&self) {}
+                    }
+                },
+                vec![],
+            ),
+            // Constructors and messages don't conflict with each other.
+            (
+                quote_as_string! {
+                    impl MyContract {
+                        #[ink(constructor, selector=1)]
+                        pub fn new() -> Self {}
+
+                        #[ink(message, selector=1)]
+                        pub fn my_message(&self) {}
+                    }
+                },
+                vec![],
+            ),
+            (
+                quote_as_string! {
+                    impl MyContract {
+                        #[ink(constructor, selector=1)]
+                        pub fn new() -> Self {}
+
+                        #[ink(constructor, selector=0x1)]
+                        pub fn new2() -> Self {}
+                    }
+                },
+                vec![([0, 0, 0, 1], "new", "new2", 1)],
+            ),
+            (
+                quote_as_string! {
+                    impl MyContract {
+                        #[ink(message, selector=0xA)]
+                        pub fn my_message(&self) {}
+
+                        #[ink(message, selector=10)]
+                        pub fn my_message2(&self) {}
+
+                        #[ink(message, selector=0xA)]
+                        pub fn my_message3(&self) {}
+                    }
+                },
+                vec![
+                    ([0, 0, 0, 10], "my_message", "my_message2", 1),
+                    ([0, 0, 0, 10], "my_message", "my_message3", 2),
+                ],
+            ),
+            (
+                quote_as_string! {
+                    impl MyContract {
+                        #[ink(constructor, selector=2)]
+                        pub fn new() -> Self {}
+
+                        #[ink(constructor, selector=2)]
+                        pub fn new2() -> Self {}
+
+                        #[ink(message, selector=3)]
+                        pub fn my_message(&self) {}
+
+                        #[ink(message, selector=3)]
+                        pub fn my_message2(&self) {}
+                    }
+                },
+                vec![
+                    ([0, 0, 0, 2], "new", "new2", 1),
+                    ([0, 0, 0, 3], "my_message", "my_message2", 1),
+                ],
+            ),
+        ] {
+            let code = format!(
+                r#"
+                #[ink::contract]
+                mod my_contract {{
+                    #[ink(storage)]
+                    pub struct MyContract {{}}
+
+                    {code}
+                }}
+                "#
+            );
+            let file = InkFile::parse(&code);
+            let contract = &file.contracts()[0];
+
+            let callable_name = |callable_ref: &CallableRef| {
+                let fn_item = match callable_ref {
+                    CallableRef::Constructor(constructor) => constructor.fn_item(),
+                    CallableRef::Message(message) => message.fn_item(),
+                };
+                fn_item
+                    .and_then(HasName::name)
+                    .map(|name| name.to_string())
+                    .unwrap()
+            };
+            let results: Vec<_> = contract
+                .callable_selector_conflicts()
+                .iter()
+                .map(|conflict| {
+                    (
+                        conflict.selector,
+                        callable_name(&conflict.first),
+                        callable_name(&conflict.second),
+                        conflict.second_index,
+                    )
+                })
+                .collect();
+            assert_eq!(
+                results,
+                expected_conflicts
+                    .into_iter()
+                    .map(|(selector, first, second, second_index)| (
+                        selector,
+                        first.to_string(),
+                        second.to_string(),
+                        second_index
+                    ))
+                    .collect::<Vec<_>>(),
+                "code: {code}"
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn generate_abi_json_works() {
+        let code = test_utils::read_source_code("contracts/flipper");
+        let contract: Contract = first_ink_entity_of_type(&code);
+
+        let abi: serde_json::Value = serde_json::from_str(&contract.generate_abi_json()).unwrap();
+        assert_eq!(abi["version"], "4");
+        assert_eq!(abi["contract"]["name"], "flipper");
+
+        // Verifies constructors.
+        let constructors = abi["spec"]["constructors"].as_array().unwrap();
+        assert_eq!(constructors.len(), 2);
+        assert_eq!(
+            constructors[0],
+            serde_json::json!({
+                "label": "new",
+                "selector": "0x9bae9d5e",
+                "payable": false,
+                "default": false,
+                "args": [{
+                    "label": "init_value",
+                    "type": { "displayName": ["bool"], "type": "bool" },
+                }],
+                "returnType": { "displayName": ["Self"], "type": "Self" },
+                "docs": [" Creates a new flipper smart contract initialized with the given value."],
+            })
+        );
+        assert_eq!(constructors[1]["label"], "new_default");
+        assert_eq!(constructors[1]["args"], serde_json::json!([]));
+        assert!(constructors[1].get("mutates").is_none());
+
+        // Verifies messages.
+        let messages = abi["spec"]["messages"].as_array().unwrap();
+        assert_eq!(messages.len(), 2);
+        assert_eq!(messages[0]["label"], "flip");
+        assert_eq!(messages[0]["selector"], "0x633aa551");
+        assert_eq!(messages[0]["mutates"], true);
+        assert_eq!(messages[0]["returnType"], serde_json::Value::Null);
+        assert_eq!(messages[1]["label"], "get");
+        assert_eq!(messages[1]["selector"], "0x2f865bd9");
+        assert_eq!(messages[1]["mutates"], false);
+        assert_eq!(
+            messages[1]["returnType"],
+            serde_json::json!({ "displayName": ["bool"], "type": "bool" })
+        );
+
+        // Verifies explicit selectors and flags.
+        let code = r#"
+            #[ink::contract]
+            mod my_contract {
+                impl MyContract {
+                    #[ink(constructor, payable, default, selector = 0xA)]
+                    pub fn new() -> Self {}
+
+                    #[ink(message, selector = 0xCAFEBABE)]
+                    pub fn my_message(&self) {}
+                }
+            }
+        "#;
+        let contract: Contract = first_ink_entity_of_type(code);
+
+        let abi: serde_json::Value = serde_json::from_str(&contract.generate_abi_json()).unwrap();
+        let constructor = &abi["spec"]["constructors"][0];
+        assert_eq!(constructor["selector"], "0x0000000a");
+        assert_eq!(constructor["payable"], true);
+        assert_eq!(constructor["default"], true);
+        let message = &abi["spec"]["messages"][0];
+        assert_eq!(message["selector"], "0xcafebabe");
+        assert_eq!(message["payable"], false);
+        assert_eq!(message["mutates"], false);
+    }
 }
@@ -79,6 +79,22 @@ mod tests {
                 false,
                 2,
             ),
+            (
+                quote_as_str! {
+                    #[ink(event)]
+                    pub struct MyEvent {
+                        #[ink(topic)]
+                        value: i32,
+                        value2: bool,
+                        #[ink(topic)]
+                        value3: u8,
+                        #[ink(topic)]
+                        value4: u128,
+                    }
+                },
+                false,
+                3,
+            ),
         ] {
             let node = parse_first_syntax_node(code);
 
@@ -15,7 +15,7 @@ impl_ast_type_trait!(Extension, IsInkFn);
 impl Extension {
     /// Returns the extension id (if any).
     pub fn id(&self) -> Option<u32> {
-        self.extension_arg()?.value()?.as_u32()
+        self.extension_arg()?.value_as_u32()
     }
 
     impl_pub_ink_arg_getter!(extension_arg, Extension, extension);
@@ -1,8 +1,21 @@
 //! ink! source file IR.
 
-use ra_ap_syntax::SourceFile;
+use ra_ap_parser::LexedStr;
+use ra_ap_syntax::ast::HasVisibility;
+use ra_ap_syntax::{
+    ast, AstNode, NodeOrToken, SourceFile, SyntaxElement, SyntaxKind, SyntaxNode, TextRange,
+    TextSize, T,
+};
+use std::collections::HashMap;
+use std::ops::Deref;
+use std::sync::OnceLock;
 
-use crate::{ChainExtension, Contract, InkE2ETest, InkTest, StorageItem, TraitDefinition};
+use crate::traits::{InkEntity, IsInkFn};
+use crate::tree::utils;
+use crate::{
+    ChainExtension, Constructor, Contract, InkArgKind, InkAttribute, InkAttributeKind, InkE2ETest,
+    InkImpl, InkMacroKind, InkTest, StorageItem, TraitDefinition,
+};
 
 /// An ink! file.
 #[ink_analyzer_macro::entity]
@@ -25,6 +38,10 @@ pub struct InkFile {
     tests: Vec<InkTest>,
     // ink! e2e tests.
     e2e_tests: Vec<InkE2ETest>,
+    // Lazily resolved ink! impls that implement ink! trait definitions
+    // (paired with the implemented ink! trait definition).
+    #[initializer(default)]
+    trait_definition_impls: TraitDefinitionImpls,
 }
 
 impl InkFile {
@@ -32,12 +49,481 @@ impl InkFile {
     pub fn parse(code: &str) -> Self {
         <Self as From<SourceFile>>::from(SourceFile::parse(code).tree())
     }
+
+    /// Parses ink! file from source code, and retains the source code
+    /// (e.g. for converting offsets to line/column positions).
+    pub fn parse_with_source(code: String) -> ParsedInkFile {
+        ParsedInkFile {
+            file: Self::parse(&code),
+            source: code,
+        }
+    }
+
+    /// Parses ink! file from source code, and returns it with a line lookup table
+    /// (i.e. the offset of the start of each line) for converting between offsets and line/column positions
+    /// (see [`offset_to_line_col`] and [`line_col_to_offset`]).
+    pub fn parse_with_offset_info(code: &str) -> (Self, Vec<TextSize>) {
+        (Self::parse(code), line_starts(code))
+    }
+
+    /// Returns the number of ink! attributes in the file for each ink! attribute kind.
+    ///
+    /// **NOTE**: ink! attributes are counted by their "primary" kind
+    /// (i.e. `#[ink(message, payable)]` is only counted as an ink! message attribute).
+    pub fn ink_attr_count_by_kind(&self) -> HashMap<InkAttributeKind, usize> {
+        let mut counts = HashMap::new();
+        for attr in self.tree().ink_attrs_in_scope() {
+            *counts.entry(*attr.kind()).or_insert(0) += 1;
+        }
+        counts
+    }
+
+    /// Returns the most frequent ink! attribute kind in the file (if any).
+    ///
+    /// **NOTE**: Ties are broken in favor of the kind with the highest priority
+    /// (see `InkAttributeKind` ordering), and then by name.
+    pub fn top_ink_attr_kind(&self) -> Option<InkAttributeKind> {
+        self.ink_attr_count_by_kind()
+            .into_iter()
+            .max_by(|(kind_a, count_a), (kind_b, count_b)| {
+                count_a
+                    .cmp(count_b)
+                    .then_with(|| kind_b.cmp(kind_a))
+                    .then_with(|| kind_b.to_string().cmp(&kind_a.to_string()))
+            })
+            .map(|(kind, _)| kind)
+    }
+
+    /// Returns ink! attributes whose parent item isn't a valid target for their ink! attribute kind
+    /// (e.g. `#[ink(message)]` applied to a `struct` or `#[ink(storage)]` applied to an `fn`).
+    ///
+    /// **NOTE**: ink! attributes are checked by their "primary" kind
+    /// (see [`crate::valid_ink_args_by_syntax_kind`] and [`crate::valid_ink_macros_by_syntax_kind`]),
+    /// and unknown ink! attributes are ignored.
+    pub fn detect_orphaned_ink_attrs(&self) -> Vec<InkAttribute> {
+        self.tree()
+            .ink_attrs_in_scope()
+            .filter(|attr| {
+                let Some(parent_kind) = attr.syntax().parent().map(|parent| parent.kind()) else {
+                    return false;
+                };
+                match attr.kind() {
+                    InkAttributeKind::Macro(InkMacroKind::Unknown)
+                    | InkAttributeKind::Arg(InkArgKind::Unknown) => false,
+                    InkAttributeKind::Macro(macro_kind) => {
+                        !crate::valid_ink_macros_by_syntax_kind(parent_kind).contains(macro_kind)
+                    }
+                    InkAttributeKind::Arg(arg_kind) => {
+                        !crate::valid_ink_args_by_syntax_kind(parent_kind).contains(arg_kind)
+                    }
+                }
+            })
+            .collect()
+    }
+
+    /// Returns the ink! contracts that are visible from the given syntax node.
+    ///
+    /// An ink! contract is visible if the node is inside its `mod` item, or if the `mod` item
+    /// (and all its ancestor modules) are visible from the node based on their visibility
+    /// (i.e. private items are visible in their parent module, `pub(super)` items in the grandparent module
+    /// and `pub`, `pub(crate)` and `pub(in ..)` items in the entire file).
+    pub fn contracts_visible_from(&self, node: &SyntaxNode) -> Vec<&Contract> {
+        let range = node.text_range();
+        self.contracts()
+            .iter()
+            .filter(|contract| {
+                contract.syntax().text_range().contains_range(range)
+                    || contract
+                        .module()
+                        .is_some_and(|module| is_module_visible_from(module, range))
+            })
+            .collect()
+    }
+
+    /// Returns hints about the ink! version used by the file, based on the ink! features it uses.
+    ///
+    /// **NOTE**: The likely major version is a heuristic, ink! 5.x is detected by
+    /// the presence of `#[ink::scale_derive(..)]` and `#[ink::event]` attribute macros and
+    /// `signature_topic` arguments, ink! 3.x by references to the `ink_lang` and `ink_storage` crates,
+    /// and any other usage of ink! features (e.g. ink! e2e tests or fallible constructors) is assumed to be ink! 4.x.
+    pub fn used_ink_version_features(&self) -> InkVersionHints {
+        let attr_paths: Vec<String> = self
+            .syntax()
+            .descendants()
+            .filter_map(ast::Attr::cast)
+            .filter_map(|attr| attr.path())
+            .map(|path| path.to_string().replace(' ', ""))
+            .collect();
+        let has_scale_derive = attr_paths.iter().any(|path| path == "ink::scale_derive");
+        let has_e2e_tests = utils::ink_descendants::<InkE2ETest>(self.syntax())
+            .next()
+            .is_some();
+        let has_fallible_constructors = utils::ink_descendants::<Constructor>(self.syntax())
+            .any(|constructor| is_fallible_constructor(&constructor));
+
+        let has_v5_features = || {
+            has_scale_derive
+                || attr_paths.iter().any(|path| path == "ink::event")
+                || self.tree().ink_attrs_descendants().any(|attr| {
+                    attr.args().iter().any(|arg| {
+                        arg.name()
+                            .is_some_and(|name| name.to_string() == "signature_topic")
+                    })
+                })
+        };
+        let has_v3_features = || {
+            self.syntax()
+                .descendants()
+                .filter_map(ast::Path::cast)
+                .filter(|path| path.qualifier().is_none())
+                .any(|path| {
+                    path.segment().is_some_and(|segment| {
+                        matches!(segment.to_string().as_str(), "ink_lang" | "ink_storage")
+                    })
+                })
+        };
+        let has_ink_features = || {
+            has_e2e_tests
+                || has_fallible_constructors
+                || self.tree().ink_attrs_descendants().next().is_some()
+        };
+        let likely_major = if has_v5_features() {
+            Some(5)
+        } else if has_v3_features() {
+            Some(3)
+        } else if has_ink_features() {
+            Some(4)
+        } else {
+            None
+        };
+
+        InkVersionHints {
+            likely_major,
+            has_e2e_tests,
+            has_scale_derive,
+            has_fallible_constructors,
+        }
+    }
+
+    /// Returns the ink! trait definition (if any) implemented by the ink! impl.
+    ///
+    /// Unlike [`InkImpl::trait_definition`], this doesn't resolve the trait definition on each call
+    /// (i.e. ink! impl to ink! trait definition pairs are resolved on first use and reused by subsequent calls).
+    ///
+    /// **NOTE**: The ink! impl must be from this file (i.e. not from a re-parsed copy of it).
+    pub fn trait_definitions_for_impl(&self, impl_block: &InkImpl) -> Option<&TraitDefinition> {
+        self.trait_definition_impls
+            .0
+            .get_or_init(|| trait_definition_impls(self.syntax()).collect())
+            .iter()
+            .find(|(ink_impl, _)| ink_impl.syntax() == impl_block.syntax())
+            .map(|(_, trait_definition)| trait_definition)
+    }
+
+    /// Returns a new ink! file with the text edit (i.e. replacing the text range with the given text)
+    /// applied to the old ink! file.
+    ///
+    /// Only the smallest item that strictly contains the edited text range is re-parsed (if possible),
+    /// while all other syntax subtrees are reused from the old file.
+    /// Falls back to re-parsing the entire source code if no such item exists
+    /// (e.g. for edits at item boundaries), if the edited item's text has lexer errors
+    /// (e.g. an unterminated string literal) or unbalanced delimiters (e.g. an unclosed `{`),
+    /// or if re-parsing the item's text yields a different item kind.
+    pub fn parse_incremental(old_file: &InkFile, range: TextRange, text: &str) -> Self {
+        incremental_reparse(old_file.syntax(), range, text).map_or_else(
+            || {
+                let mut code = old_file.syntax().to_string();
+                code.replace_range(usize::from(range.start())..usize::from(range.end()), text);
+                Self::parse(&code)
+            },
+            <Self as From<SourceFile>>::from,
+        )
+    }
+
+    /// Returns a new ink! file with the text edit (i.e. replacing the text range with the given text) applied,
+    /// and the text range (in the new file) of the smallest syntax node that covers all syntax tree changes.
+    ///
+    /// The returned text range can be used by callers to only invalidate affected parts of cached results.
+    ///
+    /// **NOTE**: The returned text range is computed by comparing the old and new syntax trees,
+    /// and it's not necessarily minimal (e.g. it covers an entire node if more than one of its children changed).
+    /// The new file itself is equivalent to fully re-parsing the edited source code (see also [`InkFile::parse_incremental`]).
+    pub fn reparse_with_edit(&self, range: TextRange, text: &str) -> (Self, TextRange) {
+        let file = Self::parse_incremental(self, range, text);
+        let reparsed_range = changed_range(self.syntax(), file.syntax());
+        (file, reparsed_range)
+    }
+}
+
+/// Lazily resolved ink! trait definition implementations paired with the implemented ink! trait definition
+/// (see [`InkFile::trait_definitions_for_impl`]).
+#[derive(Debug, Clone, Default)]
+struct TraitDefinitionImpls(OnceLock<Vec<(InkImpl, TraitDefinition)>>);
+
+// Trait definition implementations are derived from the ink! file's syntax tree,
+// so they're ignored when comparing ink! files.
+impl PartialEq for TraitDefinitionImpls {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for TraitDefinitionImpls {}
+
+/// Returns ink! trait definition implementations paired with the implemented ink! trait definition.
+fn trait_definition_impls(node: &SyntaxNode) -> impl Iterator<Item = (InkImpl, TraitDefinition)> {
+    node.descendants()
+        .filter(|it| {
+            ast::Impl::cast(it.clone()).is_some_and(|impl_item| impl_item.trait_().is_some())
+        })
+        .filter_map(InkImpl::cast)
+        .filter_map(|ink_impl| {
+            let trait_definition = ink_impl.trait_definition()?;
+            Some((ink_impl, trait_definition))
+        })
+}
+
+/// Returns true if the `mod` item (and all its ancestor modules) are visible from the text range.
+fn is_module_visible_from(module: &ast::Module, range: TextRange) -> bool {
+    let mut parent_modules = module
+        .syntax()
+        .ancestors()
+        .skip(1)
+        .filter_map(ast::Module::cast);
+    let parent_module = parent_modules.next();
+    let visibility_scope = match module.visibility() {
+        // Private items are visible in the parent module.
+        None => parent_module.clone(),
+        Some(visibility) => match visibility.path().map(|path| path.to_string()).as_deref() {
+            Some("self") if visibility.in_token().is_none() => parent_module.clone(),
+            Some("super") if visibility.in_token().is_none() => parent_modules.next(),
+            // `pub`, `pub(crate)` and `pub(in ..)` (approximated) items are visible in the entire file.
+            _ => None,
+        },
+    };
+    let is_in_scope = visibility_scope.map_or(true, |scope| {
+        scope.syntax().text_range().contains_range(range)
+    });
+    is_in_scope
+        && parent_module.map_or(true, |parent_module| {
+            is_module_visible_from(&parent_module, range)
+        })
+}
+
+/// Returns a new source file with the text edit applied by only re-parsing the smallest item
+/// that strictly contains the edited text range (if possible).
+fn incremental_reparse(root: &SyntaxNode, range: TextRange, text: &str) -> Option<SourceFile> {
+    // Finds the smallest item that strictly contains the edited text range
+    // (i.e. edits at item boundaries can change surrounding items).
+    let covering_node = match root.covering_element(range) {
+        NodeOrToken::Node(node) => node,
+        NodeOrToken::Token(token) => token.parent()?,
+    };
+    let item = covering_node
+        .ancestors()
+        .filter_map(ast::Item::cast)
+        .find(|item| {
+            let item_range = item.syntax().text_range();
+            item_range.start() < range.start() && range.end() < item_range.end()
+        })?;
+
+    // Re-parses the item's text with the text edit applied.
+    let mut item_text = item.syntax().to_string();
+    let edit_range = range - item.syntax().text_range().start();
+    item_text.replace_range(
+        usize::from(edit_range.start())..usize::from(edit_range.end()),
+        text,
+    );
+
+    // Bails if the edited item's text has lexer errors (e.g. an unterminated string literal)
+    // or unbalanced delimiters (e.g. an unclosed `{`), because re-parsing it in isolation
+    // can then differ from re-parsing the entire source code (i.e. the edit can "leak" into subsequent items).
+    let lexed = LexedStr::new(&item_text);
+    if lexed.errors().next().is_some() || !is_balanced(&lexed) {
+        return None;
+    }
+    let reparsed_file = SourceFile::parse(&item_text).tree();
+
+    // Only replaces the item if re-parsing yields exactly one item of the same kind with the same text.
+    let mut children = reparsed_file.syntax().children_with_tokens();
+    let reparsed_item = match (children.next(), children.next()) {
+        (Some(NodeOrToken::Node(node)), None) => Some(node),
+        _ => None,
+    }
+    .filter(|node| {
+        node.kind() == item.syntax().kind()
+            && node.text_range() == reparsed_file.syntax().text_range()
+    })?;
+
+    // Replaces the item in the old syntax tree (reusing all other subtrees).
+    let green = item
+        .syntax()
+        .replace_with(reparsed_item.green().into_owned());
+    SourceFile::cast(SyntaxNode::new_root(green))
+}
+
+/// Returns true if all delimiters (i.e. `{}`, `()` and `[]`) in the lexed text are balanced.
+fn is_balanced(lexed: &LexedStr) -> bool {
+    let mut open_delimiters: Vec<SyntaxKind> = Vec::new();
+    for idx in 0..lexed.len() {
+        let expected_open_delimiter = match lexed.kind(idx) {
+            kind @ (T!['{'] | T!['('] | T!['[']) => {
+                open_delimiters.push(kind);
+                continue;
+            }
+            T!['}'] => T!['{'],
+            T![')'] => T!['('],
+            T![']'] => T!['['],
+            _ => continue,
+        };
+        if open_delimiters.pop() != Some(expected_open_delimiter) {
+            return false;
+        }
+    }
+    open_delimiters.is_empty()
+}
+
+/// Hints about the ink! version used by an ink! file (see [`InkFile::used_ink_version_features`]).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct InkVersionHints {
+    /// The likely ink! major version (if it can be determined).
+    pub likely_major: Option<u8>,
+    /// Whether the file has ink! e2e tests (i.e. ink! 4.x or newer).
+    pub has_e2e_tests: bool,
+    /// Whether the file uses the `#[ink::scale_derive(..)]` attribute macro (i.e. ink! 5.x or newer).
+    pub has_scale_derive: bool,
+    /// Whether the file has ink! constructors that return a `Result` (i.e. ink! 4.x or newer).
+    pub has_fallible_constructors: bool,
+}
+
+/// An ink! file and the source code it was parsed from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParsedInkFile {
+    /// The ink! file.
+    pub file: InkFile,
+    /// The source code of the ink! file.
+    pub source: String,
+}
+
+impl Deref for ParsedInkFile {
+    type Target = InkFile;
+
+    fn deref(&self) -> &Self::Target {
+        &self.file
+    }
+}
+
+/// A zero-based line/column position in source code.
+///
+/// **NOTE**: Columns are byte offsets from the start of the line.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct LineCol {
+    /// The zero-based line number.
+    pub line: u32,
+    /// The zero-based column (i.e. byte offset from the start of the line).
+    pub col: u32,
+}
+
+/// Converts an offset into a line/column position using a line lookup table
+/// (see [`line_starts`] and [`InkFile::parse_with_offset_info`]).
+pub fn offset_to_line_col(offset: TextSize, table: &[TextSize]) -> LineCol {
+    let line = table
+        .partition_point(|line_start| *line_start <= offset)
+        .saturating_sub(1);
+    let line_start = table.get(line).copied().unwrap_or_default();
+    LineCol {
+        line: line as u32,
+        col: u32::from(offset.checked_sub(line_start).unwrap_or_default()),
+    }
+}
+
+/// Converts a line/column position into an offset using a line lookup table
+/// (see [`line_starts`] and [`InkFile::parse_with_offset_info`]).
+///
+/// **NOTE**: Lines past the end of the table are treated as positions on the last line.
+pub fn line_col_to_offset(line_col: LineCol, table: &[TextSize]) -> TextSize {
+    let line_start = table
+        .get(line_col.line as usize)
+        .or(table.last())
+        .copied()
+        .unwrap_or_default();
+    line_start + TextSize::from(line_col.col)
+}
+
+/// Returns the offset of the start of each line in the source code
+/// (i.e. a line lookup table for [`offset_to_line_col`] and [`line_col_to_offset`]).
+pub fn line_starts(code: &str) -> Vec<TextSize> {
+    std::iter::once(TextSize::from(0))
+        .chain(
+            code.match_indices('\n')
+                .map(|(idx, _)| TextSize::from(idx as u32 + 1)),
+        )
+        .collect()
+}
+
+/// Returns true if the ink! constructor returns a `Result` (e.g. `Result<Self, Error>`).
+fn is_fallible_constructor(constructor: &Constructor) -> bool {
+    let ret_ty = constructor
+        .fn_item()
+        .and_then(ast::Fn::ret_type)
+        .and_then(|ret_type| ret_type.ty());
+    match ret_ty {
+        Some(ast::Type::PathType(path_type)) => path_type
+            .path()
+            .and_then(|path| path.segment())
+            .and_then(|segment| segment.name_ref())
+            .is_some_and(|name_ref| name_ref.text() == "Result"),
+        _ => false,
+    }
+}
+
+/// Returns the text range (in the new syntax tree) of the smallest syntax node
+/// that covers all changes between the old and new syntax trees.
+fn changed_range(old_node: &SyntaxNode, new_node: &SyntaxNode) -> TextRange {
+    let old_children: Vec<SyntaxElement> = old_node.children_with_tokens().collect();
+    let new_children: Vec<SyntaxElement> = new_node.children_with_tokens().collect();
+    if old_children.len() == new_children.len() {
+        let mut changed_children = old_children
+            .iter()
+            .zip(new_children.iter())
+            .filter(|(old_child, new_child)| !is_green_eq(old_child, new_child));
+        // Narrows down the range only if exactly one child changed.
+        if let (Some((old_child, new_child)), None) =
+            (changed_children.next(), changed_children.next())
+        {
+            return match (old_child, new_child) {
+                (NodeOrToken::Node(old_child), NodeOrToken::Node(new_child))
+                    if old_child.kind() == new_child.kind() =>
+                {
+                    changed_range(old_child, new_child)
+                }
+                _ => new_child.text_range(),
+            };
+        }
+    }
+    new_node.text_range()
+}
+
+/// Returns true if both syntax elements are structurally identical.
+fn is_green_eq(old_elem: &SyntaxElement, new_elem: &SyntaxElement) -> bool {
+    match (old_elem, new_elem) {
+        (NodeOrToken::Node(old_node), NodeOrToken::Node(new_node)) => {
+            old_node.green() == new_node.green()
+        }
+        (NodeOrToken::Token(old_token), NodeOrToken::Token(new_token)) => {
+            old_token.green() == new_token.green()
+        }
+        _ => false,
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use test_utils::quote_as_str;
+    use crate::IsInkTrait;
+    use ra_ap_syntax::ast::HasName;
+    use ra_ap_syntax::TextSize;
+    use test_utils::{quote_as_str, quote_as_string};
 
     #[test]
     fn parse_works() {
@@ -89,4 +575,615 @@ mod tests {
         // 2 tests.
         assert_eq!(file.tests().len(), 2);
     }
+
+    #[test]
+    fn parse_with_source_works() {
+        let code = test_utils::read_source_code("contracts/erc20");
+        let parsed_file = InkFile::parse_with_source(code.clone());
+
+        // Verifies that the source code is retained.
+        assert_eq!(parsed_file.source, code);
+
+        // Verifies `Deref` implementation.
+        assert_eq!(parsed_file.contracts().len(), 1);
+        assert_eq!(parsed_file.syntax().to_string(), code);
+    }
+
+    #[test]
+    fn parse_with_offset_info_works() {
+        let code = "#[ink::contract]\nmod my_contract {\n\n    #[ink(storage)]\n}";
+        let (file, table) = InkFile::parse_with_offset_info(code);
+        assert_eq!(file.contracts().len(), 1);
+        assert_eq!(
+            table,
+            [0, 17, 35, 36, 56]
+                .into_iter()
+                .map(TextSize::from)
+                .collect::<Vec<_>>()
+        );
+
+        for (offset, line, col) in [
+            // Start of file.
+            (0, 0, 0),
+            // End of first line (i.e. the newline character).
+            (16, 0, 16),
+            // Start of second line.
+            (17, 1, 0),
+            (21, 1, 4),
+            // Empty line.
+            (35, 2, 0),
+            (40, 3, 4),
+            // End of file.
+            (57, 4, 1),
+        ] {
+            let offset = TextSize::from(offset);
+            let line_col = LineCol { line, col };
+            assert_eq!(offset_to_line_col(offset, &table), line_col);
+            assert_eq!(line_col_to_offset(line_col, &table), offset);
+        }
+
+        // Empty source code.
+        let (_, table) = InkFile::parse_with_offset_info("");
+        assert_eq!(table, vec![TextSize::from(0)]);
+        assert_eq!(
+            offset_to_line_col(TextSize::from(0), &table),
+            LineCol::default()
+        );
+        assert_eq!(
+            line_col_to_offset(LineCol::default(), &table),
+            TextSize::from(0)
+        );
+    }
+
+    #[test]
+    fn parse_incremental_works() {
+        let code = quote_as_string! {
+            #[ink::contract]
+            mod my_contract {
+                #[ink(storage)]
+                pub struct MyContract {
+                    value: bool,
+                }
+
+                impl MyContract {
+                    #[ink(constructor)]
+                    pub fn new() -> Self {
+                        Self { value: false }
+                    }
+
+                    #[ink(message)]
+                    pub fn get(&self) -> bool {
+                        self.value
+                    }
+                }
+            }
+
+            #[ink::trait_definition]
+            pub trait MyTrait {
+            }
+        };
+        let file = InkFile::parse(&code);
+
+        for (pat, text, is_incremental, n_contracts, n_messages, n_trait_definitions) in [
+            // Single character insertion in a message body.
+            ("self . value }", "self . values }", true, 1, 1, 1),
+            // Message name replacement.
+            ("pub fn get", "pub fn get_value", true, 1, 1, 1),
+            // New message in the `impl` block.
+            (
+                "self . value } }",
+                "self . value } # [ink (message)] pub fn set (& mut self) {} }",
+                true,
+                1,
+                2,
+                1,
+            ),
+            // Removes the ink! message attribute.
+            ("# [ink (message)] ", "", true, 1, 0, 1),
+            // Unbalanced delimiters and unterminated string literals (i.e. requires a full re-parse).
+            ("-> bool {", "-> bool { {", false, 1, 1, 1),
+            ("self . value }", "self . value \" }", false, 1, 1, 0),
+            // Edits at item boundaries (i.e. requires a full re-parse).
+            ("# [ink :: contract] ", "", false, 0, 0, 1),
+            (
+                "# [ink :: trait_definition]",
+                "# [ink :: contract] mod other {} # [ink :: trait_definition]",
+                false,
+                2,
+                1,
+                1,
+            ),
+        ] {
+            let start_offset = code.find(pat).unwrap();
+            let end_offset = start_offset + pat.len();
+            let range = TextRange::new(
+                TextSize::from(start_offset as u32),
+                TextSize::from(end_offset as u32),
+            );
+            let new_file = InkFile::parse_incremental(&file, range, text);
+
+            // Verifies that only edits inside an item are incrementally re-parsed.
+            assert_eq!(
+                incremental_reparse(file.syntax(), range, text).is_some(),
+                is_incremental
+            );
+
+            // Verifies that the new file is equivalent to fully re-parsing the edited source code.
+            let mut expected_code = code.to_string();
+            expected_code.replace_range(start_offset..end_offset, text);
+            let expected_file = InkFile::parse(&expected_code);
+            assert_eq!(new_file.syntax().to_string(), expected_code);
+            assert_eq!(new_file.syntax().green(), expected_file.syntax().green());
+
+            // Verifies the ink! entities.
+            assert_eq!(
+                new_file.contracts().len(),
+                n_contracts,
+                "code: {expected_code}"
+            );
+            assert_eq!(
+                new_file
+                    .contracts()
+                    .iter()
+                    .map(|contract| contract.messages().len())
+                    .sum::<usize>(),
+                n_messages,
+                "code: {expected_code}"
+            );
+            assert_eq!(
+                new_file.trait_definitions().len(),
+                n_trait_definitions,
+                "code: {expected_code}"
+            );
+        }
+    }
+
+    #[test]
+    fn reparse_with_edit_works() {
+        let code = quote_as_string! {
+            #[ink::contract]
+            mod my_contract {
+                #[ink(storage)]
+                pub struct MyContract {
+                    value: bool,
+                }
+
+                impl MyContract {
+                    #[ink(constructor)]
+                    pub fn new() -> Self {
+                        Self { value: false }
+                    }
+
+                    #[ink(message)]
+                    pub fn get(&self) -> bool {
+                        self.value
+                    }
+                }
+            }
+        };
+        let file = InkFile::parse(&code);
+
+        for (pat, text, expected_reparsed_text) in [
+            // Single character insertion in a message body.
+            ("self . value", "s", "values"),
+            // Statement insertion in a message body.
+            ("-> bool {", " let a = 1;", "{ let a = 1;"),
+            // New item in the contract.
+            (
+                "pub struct MyContract { value : bool , }",
+                " struct Other;",
+                "pub struct MyContract { value : bool , } struct Other;",
+            ),
+            // Unclosed block in a message body.
+            ("-> bool {", " {", "{ { self . value }"),
+        ] {
+            let offset = TextSize::from((code.find(pat).unwrap() + pat.len()) as u32);
+            let (new_file, reparsed_range) =
+                file.reparse_with_edit(TextRange::new(offset, offset), text);
+
+            // Verifies that the new file is equivalent to fully re-parsing the edited source code.
+            let mut expected_code = code.to_string();
+            expected_code.insert_str(usize::from(offset), text);
+            assert_eq!(new_file.syntax().to_string(), expected_code);
+            assert_eq!(
+                new_file.syntax().green(),
+                InkFile::parse(&expected_code).syntax().green()
+            );
+
+            // Verifies that the reparsed range covers the edit but not the entire file.
+            let reparsed_text = &expected_code[reparsed_range];
+            assert!(
+                reparsed_text.contains(expected_reparsed_text),
+                "{reparsed_text}"
+            );
+            assert!(reparsed_range.contains(offset));
+            assert!(reparsed_range.len() < new_file.syntax().text_range().len());
+
+            // Verifies that unchanged regions (i.e. nodes before the edit) are structurally identical.
+            let old_storage = file.contracts()[0].storage().unwrap().syntax().clone();
+            let new_storage = new_file.contracts()[0].storage().unwrap().syntax().clone();
+            if old_storage.text_range().end() < offset {
+                assert_eq!(old_storage.green(), new_storage.green());
+            }
+        }
+    }
+
+    #[test]
+    fn ink_attr_count_by_kind_works() {
+        let file = InkFile::parse(&test_utils::read_source_code("contracts/erc20"));
+
+        let counts = file.ink_attr_count_by_kind();
+        for (kind, expected_count) in [
+            (InkAttributeKind::Macro(InkMacroKind::Contract), 1),
+            (InkAttributeKind::Arg(InkArgKind::Storage), 1),
+            (InkAttributeKind::Arg(InkArgKind::Event), 2),
+            (InkAttributeKind::Arg(InkArgKind::Topic), 4),
+            (InkAttributeKind::Arg(InkArgKind::Constructor), 1),
+            (InkAttributeKind::Arg(InkArgKind::Message), 6),
+        ] {
+            assert_eq!(counts.get(&kind), Some(&expected_count), "kind: {kind}");
+        }
+
+        // ink! message is the most frequent ink! attribute argument kind.
+        let top_arg_kind = counts
+            .iter()
+            .filter(|(kind, _)| matches!(kind, InkAttributeKind::Arg(_)))
+            .max_by_key(|(_, count)| **count)
+            .map(|(kind, _)| *kind);
+        assert_eq!(
+            top_arg_kind,
+            Some(InkAttributeKind::Arg(InkArgKind::Message))
+        );
+        // ink! test is the most frequent ink! attribute kind overall.
+        assert_eq!(
+            file.top_ink_attr_kind(),
+            Some(InkAttributeKind::Macro(InkMacroKind::Test))
+        );
+        let max_count = counts.values().max().unwrap();
+        assert_eq!(
+            counts.get(&InkAttributeKind::Macro(InkMacroKind::Test)),
+            Some(max_count)
+        );
+
+        // Empty file.
+        let file = InkFile::parse("");
+        assert!(file.ink_attr_count_by_kind().is_empty());
+        assert!(file.top_ink_attr_kind().is_none());
+    }
+
+    #[test]
+    fn detect_orphaned_ink_attrs_works() {
+        for (code, expected_orphans) in [
+            // (code, [attr]) where:
+            // code = source code,
+            // attr = the text of an expected orphaned ink! attribute.
+
+            // Valid targets.
+            (test_utils::read_source_code("contracts/erc20"), vec![]),
+            (test_utils::read_source_code("contracts/mother"), vec![]),
+            (
+                test_utils::read_source_code("trait_definitions/erc20_trait"),
+                vec![],
+            ),
+            (
+                test_utils::read_source_code("chain_extensions/psp22_extension"),
+                vec![],
+            ),
+            (
+                test_utils::read_source_code("storage_items/complex_packed_struct"),
+                vec![],
+            ),
+            // Invalid targets.
+            (
+                quote_as_string! {
+                    #[ink(message)]
+                    pub struct MyStruct {}
+                },
+                vec!["#[ink(message)]"],
+            ),
+            (
+                quote_as_string! {
+                    #[ink(storage)]
+                    pub fn my_fn() {}
+                },
+                vec!["#[ink(storage)]"],
+            ),
+            (
+                quote_as_string! {
+                    #[ink::contract]
+                    pub struct MyContract {}
+                },
+                vec!["#[ink::contract]"],
+            ),
+            (
+                quote_as_string! {
+                    #[ink::trait_definition]
+                    mod my_mod {
+                        #[ink(topic)]
+                        pub fn my_fn() {}
+
+                        #[ink(event)]
+                        pub enum MyEvent {}
+
+                        #[ink::test]
+                        pub struct MyTest {}
+                    }
+                },
+                vec![
+                    "#[ink::trait_definition]",
+                    "#[ink(topic)]",
+                    "#[ink(event)]",
+                    "#[ink::test]",
+                ],
+            ),
+            // Unknown ink! attributes are ignored.
+            (
+                quote_as_string! {
+                    #[ink(unknown)]
+                    pub struct MyStruct {}
+
+                    #[ink::unknown]
+                    pub fn my_fn() {}
+                },
+                vec![],
+            ),
+        ] {
+            let file = InkFile::parse(&code);
+
+            let results: Vec<String> = file
+                .detect_orphaned_ink_attrs()
+                .iter()
+                .map(|attr| attr.syntax().to_string().replace(' ', ""))
+                .collect();
+            assert_eq!(
+                results,
+                expected_orphans
+                    .into_iter()
+                    .map(|attr| attr.replace(' ', ""))
+                    .collect::<Vec<_>>(),
+                "code: {code}"
+            );
+        }
+    }
+
+    #[test]
+    fn contracts_visible_from_works() {
+        let code = quote_as_string! {
+            mod a {
+                #[ink::contract]
+                mod private_contract {
+                    fn in_private_contract() {}
+                }
+
+                #[ink::contract]
+                pub mod pub_contract {}
+
+                mod b {
+                    #[ink::contract]
+                    pub(super) mod super_contract {}
+
+                    #[ink::contract]
+                    pub mod nested_pub_contract {}
+
+                    #[ink::contract]
+                    pub(self) mod self_contract {}
+
+                    fn in_b() {}
+                }
+
+                fn in_a() {}
+            }
+
+            #[ink::contract]
+            mod root_contract {}
+
+            fn in_root() {}
+        };
+        let file = InkFile::parse(&code);
+
+        for (fn_name, expected_contracts) in [
+            ("in_root", vec!["pub_contract", "root_contract"]),
+            (
+                "in_a",
+                vec![
+                    "private_contract",
+                    "pub_contract",
+                    "super_contract",
+                    "nested_pub_contract",
+                    "root_contract",
+                ],
+            ),
+            (
+                "in_b",
+                vec![
+                    "private_contract",
+                    "pub_contract",
+                    "super_contract",
+                    "nested_pub_contract",
+                    "self_contract",
+                    "root_contract",
+                ],
+            ),
+            (
+                "in_private_contract",
+                vec![
+                    "private_contract",
+                    "pub_contract",
+                    "super_contract",
+                    "nested_pub_contract",
+                    "root_contract",
+                ],
+            ),
+        ] {
+            let fn_item = file
+                .syntax()
+                .descendants()
+                .filter_map(ast::Fn::cast)
+                .find(|fn_item| {
+                    fn_item
+                        .name()
+                        .is_some_and(|name| name.to_string() == fn_name)
+                })
+                .unwrap();
+
+            let results: Vec<String> = file
+                .contracts_visible_from(fn_item.syntax())
+                .iter()
+                .filter_map(|contract| contract.module()?.name())
+                .map(|name| name.to_string())
+                .collect();
+            assert_eq!(results, expected_contracts, "fn: {fn_name}");
+        }
+    }
+
+    #[test]
+    fn trait_definitions_for_impl_works() {
+        let file = InkFile::parse(&test_utils::read_source_code(
+            "trait_definitions/erc20_trait",
+        ));
+        let ink_impls: Vec<InkImpl> = file
+            .syntax()
+            .descendants()
+            .filter_map(InkImpl::cast)
+            .collect();
+        assert!(!ink_impls.is_empty());
+
+        for ink_impl in &ink_impls {
+            // Cached and uncached results are equivalent.
+            assert_eq!(
+                file.trait_definitions_for_impl(ink_impl),
+                ink_impl.trait_definition().as_ref()
+            );
+        }
+        let trait_impl = ink_impls
+            .iter()
+            .find(|ink_impl| ink_impl.trait_type().is_some())
+            .unwrap();
+        assert_eq!(
+            file.trait_definitions_for_impl(trait_impl)
+                .and_then(|trait_definition| trait_definition.trait_item()?.name())
+                .map(|name| name.to_string())
+                .as_deref(),
+            Some("BaseErc20")
+        );
+
+        // Non-trait ink! impls don't have a trait definition.
+        let file = InkFile::parse(quote_as_str! {
+            #[ink::trait_definition]
+            pub trait MyTrait {
+                #[ink(message)]
+                fn my_message(&self);
+            }
+
+            #[ink(impl)]
+            impl MyContract {
+                #[ink(message)]
+                pub fn my_message(&self) {}
+            }
+        });
+        let ink_impl = file.syntax().descendants().find_map(InkImpl::cast).unwrap();
+        assert!(file.trait_definitions_for_impl(&ink_impl).is_none());
+    }
+
+    #[test]
+    fn used_ink_version_features_works() {
+        // ink! 4.x fixtures.
+        for (location, has_e2e_tests, has_fallible_constructors) in [
+            ("contracts/erc20", true, false),
+            ("contracts/flipper", true, false),
+            ("contracts/mother", false, true),
+            ("chain_extensions/psp22_extension", false, false),
+        ] {
+            let file = InkFile::parse(&test_utils::read_source_code(location));
+            assert_eq!(
+                file.used_ink_version_features(),
+                InkVersionHints {
+                    likely_major: Some(4),
+                    has_e2e_tests,
+                    has_scale_derive: false,
+                    has_fallible_constructors,
+                },
+                "fixture: {location}"
+            );
+        }
+
+        for (code, expected_hints) in [
+            // No ink! features.
+            (
+                quote_as_str! {
+                    struct MyStruct {}
+                },
+                InkVersionHints::default(),
+            ),
+            // ink! 3.x.
+            (
+                quote_as_str! {
+                    use ink_lang as ink;
+
+                    #[ink::contract]
+                    mod my_contract {
+                        #[ink(storage)]
+                        #[derive(ink_storage::traits::SpreadAllocate)]
+                        pub struct MyContract {}
+                    }
+                },
+                InkVersionHints {
+                    likely_major: Some(3),
+                    ..Default::default()
+                },
+            ),
+            // ink! 5.x.
+            (
+                quote_as_str! {
+                    #[ink::contract]
+                    mod my_contract {
+                        #[ink(storage)]
+                        pub struct MyContract {}
+
+                        #[ink(event)]
+                        #[ink::scale_derive(Encode, Decode, TypeInfo)]
+                        pub struct MyEvent {}
+
+                        impl MyContract {
+                            #[ink(constructor)]
+                            pub fn new() -> Result<Self, Error> {}
+                        }
+                    }
+                },
+                InkVersionHints {
+                    likely_major: Some(5),
+                    has_e2e_tests: false,
+                    has_scale_derive: true,
+                    has_fallible_constructors: true,
+                },
+            ),
+            (
+                quote_as_str! {
+                    #[ink::event]
+                    pub struct MyEvent {}
+                },
+                InkVersionHints {
+                    likely_major: Some(5),
+                    ..Default::default()
+                },
+            ),
+            (
+                quote_as_str! {
+                    #[ink(event, signature_topic = "1111111111111111111111111111111111111111111111111111111111111111")]
+                    pub struct MyEvent {}
+                },
+                InkVersionHints {
+                    likely_major: Some(5),
+                    ..Default::default()
+                },
+            ),
+        ] {
+            let file = InkFile::parse(code);
+            assert_eq!(
+                file.used_ink_version_features(),
+                expected_hints,
+                "code: {code}"
+            );
+        }
+    }
 }
@@ -1,8 +1,10 @@
 //! ink! impl IR.
 
+use itertools::Either;
+use ra_ap_syntax::ast::HasName;
 use ra_ap_syntax::{ast, AstNode, SyntaxNode};
 
-use crate::traits::InkEntity;
+use crate::traits::{InkEntity, IsInkFn};
 use crate::tree::{ast_ext, utils};
 use crate::{Constructor, InkArgKind, InkAttribute, InkAttributeKind, Message, TraitDefinition};
 
@@ -21,6 +23,16 @@ pub struct InkImpl {
 impl InkImpl {
     impl_pub_ast_type_getter!(impl_item, Impl);
 
+    /// Returns true if the syntax node is explicitly annotated as an ink! impl
+    /// (i.e. has an ink! `impl` attribute).
+    ///
+    /// **NOTE**: Unlike [`InkEntity::can_cast`], `impl` items are not inferred to be ink! impls
+    /// based on having ink! constructor or ink! message descendants
+    /// or implementing an ink! trait definition.
+    pub fn can_cast_strict(node: &SyntaxNode) -> bool {
+        utils::ink_attrs(node).any(|attr| *attr.kind() == InkAttributeKind::Arg(InkArgKind::Impl))
+    }
+
     /// Returns the trait type (if any).
     pub fn trait_type(&self) -> Option<ast::Type> {
         self.impl_item().and_then(ast::Impl::trait_)
@@ -35,10 +47,95 @@ impl InkImpl {
 
     impl_pub_ink_arg_getter!(namespace_arg, Namespace, namespace);
 
+    /// Returns the namespace (if any) used when composing the selectors of the ink! impl's callables.
+    ///
+    /// The namespace is resolved (in priority order) from the ink! impl's own `namespace` argument
+    /// (i.e. `#[ink(impl, namespace="my_namespace")]` or `#[ink(namespace="my_namespace")]`)
+    /// or the `namespace` argument of the implemented ink! trait definition (if any)
+    /// (i.e. `#[ink::trait_definition(namespace="my_namespace")]`).
+    pub fn namespace_effective(&self) -> Option<String> {
+        self.namespace_arg()
+            .and_then(|arg| arg.value()?.as_string())
+            .or_else(|| {
+                self.trait_definition()?
+                    .namespace_arg()?
+                    .value()?
+                    .as_string()
+            })
+    }
+
     /// Returns the ink! trait definition (if any).
     pub fn trait_definition(&self) -> Option<TraitDefinition> {
         self.impl_item().and_then(trait_definition)
     }
+
+    /// Returns an iterator over the ink! constructors and ink! messages of the ink! impl
+    /// (i.e. all ink! constructors first, followed by all ink! messages).
+    pub fn all_callables(&self) -> impl Iterator<Item = Either<&Constructor, &Message>> {
+        self.constructors
+            .iter()
+            .map(Either::Left)
+            .chain(self.messages.iter().map(Either::Right))
+    }
+
+    /// Returns `(message_name, mismatch_description)` pairs for methods whose parameter list
+    /// doesn't match the equivalent message declaration in the ink! trait definition (if any).
+    ///
+    /// **NOTE**: Parameter lists are compared with trivia (i.e. whitespace and comments) removed,
+    /// but they're described as written.
+    pub fn verify_message_signatures_match_trait(&self) -> Vec<(String, String)> {
+        let Some((impl_item, trait_definition)) = self.impl_item().zip(self.trait_definition())
+        else {
+            return Vec::new();
+        };
+        let declarations: Vec<(String, Option<ast::ParamList>)> = trait_definition
+            .messages()
+            .iter()
+            .filter_map(|message| {
+                let fn_item = message.fn_item()?;
+                Some((fn_item.name()?.to_string(), fn_item.param_list()))
+            })
+            .collect();
+
+        impl_item
+            .assoc_item_list()
+            .into_iter()
+            .flat_map(|item_list| item_list.assoc_items())
+            .filter_map(|item| match item {
+                ast::AssocItem::Fn(fn_item) => Some(fn_item),
+                _ => None,
+            })
+            .filter_map(|fn_item| {
+                let name = fn_item.name()?.to_string();
+                let (_, declared) = declarations
+                    .iter()
+                    .find(|(declared_name, _)| *declared_name == name)?;
+                let implemented = fn_item.param_list();
+                let is_match = match (declared, &implemented) {
+                    (Some(declared), Some(implemented)) => {
+                        ast_ext::strip_trivia(declared.syntax())
+                            == ast_ext::strip_trivia(implemented.syntax())
+                    }
+                    (None, None) => true,
+                    _ => false,
+                };
+                (!is_match).then(|| {
+                    let description = format!(
+                        "expected parameter list `{}`, found `{}`",
+                        declared
+                            .as_ref()
+                            .map(ToString::to_string)
+                            .unwrap_or_default(),
+                        implemented
+                            .as_ref()
+                            .map(ToString::to_string)
+                            .unwrap_or_default()
+                    );
+                    (name, description)
+                })
+            })
+            .collect()
+    }
 }
 
 // Returns true if the syntax node can be converted into an ink! impl item.
@@ -61,8 +158,7 @@ fn can_cast(node: &SyntaxNode) -> bool {
     };
 
     // Has ink! impl attribute.
-    utils::ink_attrs(node)
-        .any(|attr| *attr.kind() == InkAttributeKind::Arg(InkArgKind::Impl))
+    InkImpl::can_cast_strict(node)
         // Is an `impl` item, and either
         // has any ink! constructor or ink! message annotated descendants or
         // is a trait definition implementation.
@@ -86,7 +182,9 @@ fn trait_definition(impl_item: &ast::Impl) -> Option<TraitDefinition> {
 mod tests {
     use super::*;
     use crate::test_utils::parse_first_ast_node_of_type;
-    use test_utils::quote_as_str;
+    use crate::traits::IsInkTrait;
+    use crate::InkFile;
+    use test_utils::{quote_as_str, quote_as_string};
 
     #[test]
     fn cast_works() {
@@ -430,4 +528,382 @@ mod tests {
             assert_eq!(ink_impl.trait_definition().is_some(), has_trait_definition);
         }
     }
+
+    #[test]
+    fn all_callables_works() {
+        let code = quote_as_string! {
+            impl MyContract {
+                #[ink(message)]
+                pub fn my_message(&self) {}
+
+                #[ink(constructor)]
+                pub fn my_constructor() -> Self {}
+
+                #[ink(message)]
+                pub fn my_message2(&mut self) {}
+
+                #[ink(constructor)]
+                pub fn my_constructor2() -> Self {}
+
+                #[ink(message)]
+                pub fn my_message3(&self) {}
+            }
+        };
+        let node = parse_first_ast_node_of_type::<ast::Impl>(&code);
+        let ink_impl = InkImpl::cast(node.syntax().clone()).unwrap();
+
+        // Constructors are yielded first (in source order), followed by messages (in source order).
+        let callables: Vec<(bool, String)> = ink_impl
+            .all_callables()
+            .map(|callable| {
+                let (is_constructor, fn_item) = match callable {
+                    Either::Left(constructor) => (true, constructor.fn_item()),
+                    Either::Right(message) => (false, message.fn_item()),
+                };
+                (is_constructor, fn_item.unwrap().name().unwrap().to_string())
+            })
+            .collect();
+        assert_eq!(
+            callables,
+            [
+                (true, "my_constructor"),
+                (true, "my_constructor2"),
+                (false, "my_message"),
+                (false, "my_message2"),
+                (false, "my_message3"),
+            ]
+            .into_iter()
+            .map(|(is_constructor, name)| (is_constructor, name.to_string()))
+            .collect::<Vec<_>>()
+        );
+
+        // No callables.
+        let node = parse_first_ast_node_of_type::<ast::Impl>(quote_as_str! {
+            #[ink(impl)]
+            impl MyContract {}
+        });
+        let ink_impl = InkImpl::cast(node.syntax().clone()).unwrap();
+        assert_eq!(ink_impl.all_callables().count(), 0);
+    }
+
+    #[test]
+    fn namespace_effective_works() {
+        let trait_definition_code = |namespace_arg: &str| {
+            format!(
+                r#"
+                #[ink::trait_definition{namespace_arg}]
+                pub trait MyTrait {{
+                    #[ink(message)]
+                    fn my_message(&self);
+                }}
+                "#
+            )
+        };
+        for (code, expected_namespace) in [
+            (
+                quote_as_string! {
+                    impl MyContract {
+                        #[ink(message)]
+                        pub fn my_message(&self) {}
+                    }
+                },
+                None,
+            ),
+            (
+                quote_as_string! {
+                    #[ink(namespace="my_namespace")]
+                    impl MyContract {
+                        #[ink(message)]
+                        pub fn my_message(&self) {}
+                    }
+                },
+                Some("my_namespace"),
+            ),
+            (
+                quote_as_string! {
+                    #[ink(impl, namespace="my_namespace")]
+                    impl MyContract {}
+                },
+                Some("my_namespace"),
+            ),
+            // Missing namespace value.
+            (
+                quote_as_string! {
+                    #[ink(namespace)]
+                    impl MyContract {
+                        #[ink(message)]
+                        pub fn my_message(&self) {}
+                    }
+                },
+                None,
+            ),
+            // Namespace from the ink! trait definition.
+            (
+                format!(
+                    "{}\n{}",
+                    trait_definition_code(r#"(namespace="my_trait_namespace")"#),
+                    quote_as_str! {
+                        impl MyTrait for MyContract {
+                            #[ink(message)]
+                            fn my_message(&self) {}
+                        }
+                    }
+                ),
+                Some("my_trait_namespace"),
+            ),
+            // The ink! impl's namespace takes priority over the ink! trait definition's namespace.
+            (
+                format!(
+                    "{}\n{}",
+                    trait_definition_code(r#"(namespace="my_trait_namespace")"#),
+                    quote_as_str! {
+                        #[ink(namespace="my_namespace")]
+                        impl MyTrait for MyContract {
+                            #[ink(message)]
+                            fn my_message(&self) {}
+                        }
+                    }
+                ),
+                Some("my_namespace"),
+            ),
+            // ink! trait definition without a namespace.
+            (
+                format!(
+                    "{}\n{}",
+                    trait_definition_code(""),
+                    quote_as_str! {
+                        impl MyTrait for MyContract {
+                            #[ink(message)]
+                            fn my_message(&self) {}
+                        }
+                    }
+                ),
+                None,
+            ),
+        ] {
+            let impl_item: ast::Impl = parse_first_ast_node_of_type(&code);
+            let ink_impl = InkImpl::cast(impl_item.syntax().clone()).unwrap();
+
+            assert_eq!(
+                ink_impl.namespace_effective().as_deref(),
+                expected_namespace,
+                "code: {code}"
+            );
+        }
+    }
+
+    #[test]
+    fn trait_definition_works() {
+        let trait_code = quote_as_string! {
+            #[ink::trait_definition]
+            pub trait MyTrait {
+                #[ink(message)]
+                fn my_message(&self);
+            }
+
+            pub trait MyPlainTrait {
+                fn my_message(&self);
+            }
+        };
+        for (trait_path, expected_name) in [
+            // Nested modules.
+            ("crate::traits::nested::MyTrait", Some("MyTrait")),
+            ("super::super::traits::nested::MyTrait", Some("MyTrait")),
+            (
+                "self::super::super::traits::nested::MyTrait",
+                Some("MyTrait"),
+            ),
+            // `use` aliases.
+            ("MyAlias", Some("MyTrait")),
+            // Not an ink! trait definition.
+            ("crate::traits::nested::MyPlainTrait", None),
+            // Unknown items in the current crate.
+            ("crate::traits::MyTrait", None),
+            ("crate::traits::nested::OtherTrait", None),
+            ("super::MyTrait", None),
+            // External crate traits.
+            ("other_crate::MyTrait", None),
+            ("::other_crate::traits::MyTrait", None),
+            ("ink::traits::MyTrait", None),
+        ] {
+            let code = format!(
+                r#"
+                mod traits {{
+                    mod nested {{
+                        {trait_code}
+                    }}
+                }}
+
+                mod contract {{
+                    mod my_contract {{
+                        use crate::traits::nested::MyTrait as MyAlias;
+
+                        #[ink(impl)]
+                        impl {trait_path} for MyContract {{}}
+                    }}
+                }}
+                "#
+            );
+            let impl_item: ast::Impl = parse_first_ast_node_of_type(&code);
+            let ink_impl = InkImpl::cast(impl_item.syntax().clone()).unwrap();
+
+            assert_eq!(
+                ink_impl
+                    .trait_definition()
+                    .and_then(|trait_definition| trait_definition.trait_item()?.name())
+                    .map(|name| name.to_string())
+                    .as_deref(),
+                expected_name,
+                "path: {trait_path}"
+            );
+        }
+    }
+
+    #[test]
+    fn verify_message_signatures_match_trait_works() {
+        let original_code = test_utils::read_source_code("trait_definitions/erc20_trait");
+        for (replacements, expected_mismatches) in [
+            // Unmodified trait definition implementation.
+            (vec![], vec![]),
+            // Trivia is ignored.
+            (
+                vec![(
+                    "fn balance_of(&self, owner: AccountId) -> Balance {",
+                    "fn balance_of( &self , owner : AccountId /* owner */ ) -> Balance {",
+                )],
+                vec![],
+            ),
+            // Mismatched parameter type.
+            (
+                vec![(
+                    "fn transfer(&mut self, to: AccountId, value: Balance) -> Result<()> {",
+                    "fn transfer(&mut self, to: AccountId, value: u8) -> Result<()> {",
+                )],
+                vec![(
+                    "transfer",
+                    "expected parameter list `(&mut self, to: AccountId, value: Balance)`, \
+                    found `(&mut self, to: AccountId, value: u8)`",
+                )],
+            ),
+            // Mismatched `self` receiver and missing parameter.
+            (
+                vec![
+                    (
+                        "fn total_supply(&self) -> Balance {",
+                        "fn total_supply(&mut self) -> Balance {",
+                    ),
+                    (
+                        "fn balance_of(&self, owner: AccountId) -> Balance {",
+                        "fn balance_of(&self) -> Balance {",
+                    ),
+                ],
+                vec![
+                    (
+                        "total_supply",
+                        "expected parameter list `(&self)`, found `(&mut self)`",
+                    ),
+                    (
+                        "balance_of",
+                        "expected parameter list `(&self, owner: AccountId)`, found `(&self)`",
+                    ),
+                ],
+            ),
+        ] {
+            let mut code = original_code.clone();
+            for (from, to) in &replacements {
+                assert!(code.contains(from));
+                code = code.replace(from, to);
+            }
+            let ink_impl = InkFile::parse(&code)
+                .contracts()
+                .first()
+                .unwrap()
+                .impls()
+                .iter()
+                .find(|ink_impl| ink_impl.trait_type().is_some())
+                .unwrap()
+                .clone();
+
+            let mismatches = ink_impl.verify_message_signatures_match_trait();
+            assert_eq!(
+                mismatches
+                    .iter()
+                    .map(|(name, description)| (name.as_str(), description.as_str()))
+                    .collect::<Vec<_>>(),
+                expected_mismatches,
+                "replacements: {replacements:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn can_cast_strict_works() {
+        for (code, expected_can_cast, expected_can_cast_strict) in [
+            (
+                quote_as_str! {
+                    #[ink(impl)]
+                    impl MyContract {
+                    }
+                },
+                true,
+                true,
+            ),
+            (
+                quote_as_str! {
+                    #[ink(impl, namespace="my_namespace")]
+                    impl MyContract {
+                        #[ink(message)]
+                        pub fn my_message(&self) {}
+                    }
+                },
+                true,
+                true,
+            ),
+            (
+                quote_as_str! {
+                    impl MyContract {
+                        #[ink(constructor)]
+                        pub fn my_constructor() -> Self {}
+
+                        #[ink(message)]
+                        pub fn my_message(&self) {}
+                    }
+                },
+                true,
+                false,
+            ),
+            (
+                quote_as_str! {
+                    #[ink(namespace="my_namespace")]
+                    impl MyContract {
+                        #[ink(message)]
+                        pub fn my_message(&self) {}
+                    }
+                },
+                true,
+                false,
+            ),
+            (
+                quote_as_str! {
+                    impl MyContract {
+                    }
+                },
+                false,
+                false,
+            ),
+        ] {
+            let impl_item: ast::Impl = parse_first_ast_node_of_type(code);
+
+            assert_eq!(
+                InkImpl::can_cast(impl_item.syntax()),
+                expected_can_cast,
+                "code: {code}"
+            );
+            assert_eq!(
+                InkImpl::can_cast_strict(impl_item.syntax()),
+                expected_can_cast_strict,
+                "code: {code}"
+            );
+        }
+    }
 }
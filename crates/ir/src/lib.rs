@@ -68,21 +68,25 @@ mod test_utils;
 
 pub use self::{
     attrs::{
-        meta, InkArg, InkArgKind, InkArgValueKind, InkArgValuePathKind, InkArgValueStringKind,
-        InkAttribute, InkAttributeKind, InkMacroKind,
+        meta, valid_ink_args_by_syntax_kind, valid_ink_macros_by_syntax_kind, InkArg, InkArgKind,
+        InkArgValueKind, InkArgValuePathKind, InkArgValueStringKind, InkAttribute,
+        InkAttributeKind, InkMacroKind,
     },
     chain_extension::ChainExtension,
     constructor::Constructor,
-    contract::Contract,
+    contract::{CallableRef, Contract, ContractCompleteness, SelectorConflict},
     environment::{EnvArg, Environment},
     event::Event,
     extension::Extension,
-    file::InkFile,
+    file::{
+        line_col_to_offset, line_starts, offset_to_line_col, InkFile, InkVersionHints, LineCol,
+        ParsedInkFile,
+    },
     ink_e2e_test::InkE2ETest,
     ink_impl::InkImpl,
     ink_test::InkTest,
     message::Message,
-    selector::{Selector, SelectorArg, SelectorArgKind},
+    selector::{format_selector, Selector, SelectorArg, SelectorArgKind},
     storage::Storage,
     storage_item::StorageItem,
     topic::Topic,
@@ -92,16 +96,17 @@ pub use self::{
         IsInkTrait, IsSyntax,
     },
     tree::ast_ext::{
-        closest_ancestor_ast_type, closest_item_which, closest_non_trivia_token, parent_ast_item,
-        path_from_str, path_from_type, path_to_string, resolve_current_module, resolve_item,
-        resolve_qualifier, simple_use_paths_and_aliases_in_scope,
+        closest_ancestor_ast_type, closest_item_which, closest_non_trivia_token, fn_param_names,
+        parent_ast_item, path_from_str, path_from_type, path_to_string, resolve_current_module,
+        resolve_item, resolve_qualifier, simple_use_paths_and_aliases_in_scope, strip_trivia,
     },
     tree::utils::{
-        attrs, ink_ancestors, ink_arg_by_kind, ink_args, ink_args_by_kind, ink_attr_to_entity,
-        ink_attrs, ink_attrs_ancestors, ink_attrs_closest_ancestors, ink_attrs_closest_descendants,
-        ink_attrs_descendants, ink_attrs_in_scope, ink_callable_closest_descendants,
-        ink_closest_ancestors, ink_closest_descendants, ink_descendants,
-        ink_impl_closest_descendants, ink_parent, ink_peekable_quasi_closest_descendants,
+        attrs, ink_ancestor_of_kind, ink_ancestors, ink_arg_by_kind, ink_args, ink_args_by_kind,
+        ink_attr_to_entity, ink_attrs, ink_attrs_ancestors, ink_attrs_closest_ancestors,
+        ink_attrs_closest_descendants, ink_attrs_descendants, ink_attrs_in_scope,
+        ink_callable_closest_descendants, ink_closest_ancestors, ink_closest_descendants,
+        ink_descendants, ink_impl_closest_descendants, ink_parent,
+        ink_peekable_quasi_closest_descendants,
     },
     tree::{InkTree, ItemAtOffset},
 };
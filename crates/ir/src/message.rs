@@ -1,8 +1,10 @@
 //! ink! message IR.
 
 use ra_ap_syntax::ast;
+use ra_ap_syntax::ast::HasName;
 
-use crate::traits::IsInkCallable;
+use crate::traits::{IsInkCallable, IsInkFn};
+use crate::tree::ast_ext;
 
 /// An ink! message.
 #[ink_analyzer_macro::entity(arg_kind = Message)]
@@ -16,6 +18,59 @@ impl_ast_type_trait!(Message, IsInkFn);
 
 impl IsInkCallable for Message {}
 
+impl Message {
+    /// Returns true if the ink! message (probably) mutates contract state
+    /// (i.e. if its receiver is `&mut self`).
+    ///
+    /// Returns false for `&self` receivers, non-reference receivers and `fn`s without a self receiver.
+    pub fn is_mutating(&self) -> bool {
+        self.fn_item()
+            .and_then(ast::Fn::param_list)
+            .and_then(|param_list| param_list.self_param())
+            .is_some_and(|self_param| {
+                self_param.amp_token().is_some() && self_param.mut_token().is_some()
+            })
+    }
+
+    /// Returns a snippet (with tab stops for each argument) for calling the ink! message
+    /// from another ink! contract via a `ContractRef` (e.g. `contract_ref.my_message(${1:a}, ${2:b})`).
+    ///
+    /// **NOTE**: Payable ink! messages are called using the call builder
+    /// (e.g. `contract_ref.call_mut().my_message(${1:a}).transferred_value(${2:value}).invoke()`),
+    /// so that the transferred value can be specified.
+    ///
+    /// Ref: <https://use.ink/basics/cross-contract-calling#contractref>.
+    ///
+    /// Ref: <https://github.com/paritytech/ink/blob/v4.1.0/crates/ink/codegen/src/generator/as_dependency/call_builder.rs#L356-L365>.
+    pub fn invocation_snippet(&self) -> String {
+        let fn_item = self.fn_item();
+        let name = fn_item
+            .and_then(HasName::name)
+            .map_or("message".to_string(), |name| name.to_string());
+        let param_names = fn_item.map(ast_ext::fn_param_names).unwrap_or_default();
+        let args = param_names
+            .iter()
+            .enumerate()
+            .map(|(idx, param_name)| format!("${{{}:{param_name}}}", idx + 1))
+            .collect::<Vec<String>>()
+            .join(", ");
+
+        if self.payable_arg().is_some() {
+            format!(
+                "contract_ref.{}().{name}({args}).transferred_value(${{{}:value}}).invoke()",
+                if self.is_mutating() {
+                    "call_mut"
+                } else {
+                    "call"
+                },
+                param_names.len() + 1,
+            )
+        } else {
+            format!("contract_ref.{name}({args})")
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -105,4 +160,157 @@ mod tests {
             assert!(message.fn_item().is_some());
         }
     }
+
+    #[test]
+    fn is_mutating_works() {
+        for (code, expected) in [
+            (
+                quote_as_str! {
+                    #[ink(message)]
+                    pub fn my_message(&mut self) {}
+                },
+                true,
+            ),
+            (
+                quote_as_str! {
+                    #[ink(message)]
+                    pub fn my_message(&mut self, a: u8) {}
+                },
+                true,
+            ),
+            (
+                quote_as_str! {
+                    #[ink(message)]
+                    pub fn my_message(&self) {}
+                },
+                false,
+            ),
+            (
+                quote_as_str! {
+                    #[ink(message)]
+                    pub fn my_message(mut self) {}
+                },
+                false,
+            ),
+            (
+                quote_as_str! {
+                    #[ink(message)]
+                    pub fn my_message() {}
+                },
+                false,
+            ),
+        ] {
+            let message = Message::cast(parse_first_syntax_node(code)).unwrap();
+            assert_eq!(message.is_mutating(), expected, "code: {code}");
+        }
+    }
+
+    #[test]
+    fn invocation_snippet_works() {
+        for (code, expected_snippet) in [
+            // Zero arguments.
+            (
+                quote_as_str! {
+                    #[ink(message)]
+                    pub fn my_message(&self) {}
+                },
+                "contract_ref.my_message()",
+            ),
+            // Two arguments.
+            (
+                quote_as_str! {
+                    #[ink(message)]
+                    pub fn transfer(&mut self, to: AccountId, value: Balance) {}
+                },
+                "contract_ref.transfer(${1:to}, ${2:value})",
+            ),
+            // Payable.
+            (
+                quote_as_str! {
+                    #[ink(message, payable)]
+                    pub fn my_message(&self) {}
+                },
+                "contract_ref.call().my_message().transferred_value(${1:value}).invoke()",
+            ),
+            (
+                quote_as_str! {
+                    #[ink(message, payable)]
+                    pub fn deposit(&mut self, memo: String) {}
+                },
+                "contract_ref.call_mut().deposit(${1:memo}).transferred_value(${2:value}).invoke()",
+            ),
+        ] {
+            let message = Message::cast(parse_first_syntax_node(code)).unwrap();
+
+            assert_eq!(
+                message.invocation_snippet(),
+                expected_snippet,
+                "code: {code}"
+            );
+        }
+    }
+
+    #[test]
+    fn computed_selector_works() {
+        for (code, expected_selector) in [
+            (
+                quote_as_str! {
+                    impl MyContract {
+                        #[ink(message)]
+                        pub fn my_message(&self) {}
+                    }
+                },
+                // First 4-bytes of Blake2b-256 hash of "my_message".
+                Some([0x6A, 0x46, 0x9E, 0x03]),
+            ),
+            (
+                quote_as_str! {
+                    impl MyContract {
+                        #[ink(message, selector=1)]
+                        pub fn my_message(&self) {}
+                    }
+                },
+                // Manually provided selectors are ignored.
+                Some([0x6A, 0x46, 0x9E, 0x03]),
+            ),
+            (
+                quote_as_str! {
+                    #[ink(namespace="my_namespace")]
+                    impl MyContract {
+                        #[ink(message)]
+                        pub fn my_message(&self) {}
+                    }
+                },
+                // First 4-bytes of Blake2b-256 hash of "my_namespace::my_message".
+                Some([0xAB, 0xE8, 0x9C, 0x04]),
+            ),
+            (
+                quote_as_str! {
+                    impl MyTrait for MyContract {
+                        #[ink(message)]
+                        fn my_message(&self) {}
+                    }
+                },
+                // First 4-bytes of Blake2b-256 hash of "MyTrait::my_message".
+                Some([0x04, 0xC4, 0x94, 0x46]),
+            ),
+            (
+                quote_as_str! {
+                    impl MyContract {
+                        #[ink(message, selector=_)]
+                        pub fn my_message(&self) {}
+                    }
+                },
+                // Wildcard selectors have no computed selector.
+                None,
+            ),
+        ] {
+            let message: Message = first_ink_entity_of_type(code);
+            assert_eq!(
+                message.computed_selector(),
+                expected_selector,
+                "code: {code}"
+            );
+        }
+    }
 }
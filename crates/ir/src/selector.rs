@@ -6,9 +6,8 @@ use blake2::Blake2b;
 use ra_ap_syntax::ast::HasName;
 use ra_ap_syntax::{ast, AstNode, SyntaxKind, TextRange};
 
-use crate::traits::{HasInkImplParent, IsInkCallable};
-use crate::tree::utils;
-use crate::{InkArg, InkArgKind};
+use crate::traits::{HasInkImplParent, InkEntity, IsInkCallable};
+use crate::{InkArg, InkArgKind, InkImpl};
 
 /// The selector of an ink! callable entity.
 ///
@@ -28,38 +27,56 @@ impl Selector {
     where
         T: IsInkCallable,
     {
-        let selector_bytes: Option<[u8; 4]> = match Self::provided_int_selector(callable) {
+        match Self::provided_int_selector(callable) {
             // Manually provided integer selector is converted into bytes.
-            Some(manual_int_selector) => Some(manual_int_selector.to_be_bytes()),
+            Some(manual_int_selector) => Some(Self(manual_int_selector.to_be_bytes())),
             // Otherwise the selector has to be computed, but only if the callable is a valid `fn` item.
-            None => {
-                Self::ident(callable).map(|callable_ident| {
-                    let trait_ident = Self::trait_ident(callable);
-                    let namespace = Self::namespace(callable);
-
-                    let pre_hash_bytes = [namespace, trait_ident, Some(callable_ident)]
-                        .into_iter()
-                        .flatten()
-                        .collect::<Vec<String>>()
-                        .join("::")
-                        .into_bytes();
-
-                    // Computes the BLAKE-2b 256-bit hash for the given input and stores it in output.
-                    let mut hasher = <Blake2b<U32>>::new();
-                    hasher.update(pre_hash_bytes);
-                    let hashed_bytes = hasher.finalize();
-
-                    [
-                        hashed_bytes[0],
-                        hashed_bytes[1],
-                        hashed_bytes[2],
-                        hashed_bytes[3],
-                    ]
-                })
-            }
-        };
+            None => Self::compute(callable),
+        }
+    }
+
+    /// Returns the selector computed from the identifier of the ink! callable entity
+    /// (i.e. ignoring any manually provided selector), as generated by ink! codegen.
+    ///
+    /// Ref: <https://github.com/paritytech/ink/blob/master/crates/ink/ir/src/ir/item_impl/callable.rs#L203-L371>.
+    pub(crate) fn compute<T>(callable: &T) -> Option<Self>
+    where
+        T: IsInkCallable,
+    {
+        Self::ident(callable).map(|callable_ident| {
+            Self::compute_from_parts(
+                Self::namespace(callable).as_deref(),
+                Self::trait_ident(callable).as_deref(),
+                &callable_ident,
+            )
+        })
+    }
 
-        selector_bytes.map(Self)
+    /// Returns the selector computed from the namespace (if any), trait identifier (if any)
+    /// and identifier of a callable.
+    pub(crate) fn compute_from_parts(
+        namespace: Option<&str>,
+        trait_ident: Option<&str>,
+        callable_ident: &str,
+    ) -> Self {
+        let pre_hash_bytes = [namespace, trait_ident, Some(callable_ident)]
+            .into_iter()
+            .flatten()
+            .collect::<Vec<&str>>()
+            .join("::")
+            .into_bytes();
+
+        // Computes the BLAKE-2b 256-bit hash for the given input and stores it in output.
+        let mut hasher = <Blake2b<U32>>::new();
+        hasher.update(pre_hash_bytes);
+        let hashed_bytes = hasher.finalize();
+
+        Self([
+            hashed_bytes[0],
+            hashed_bytes[1],
+            hashed_bytes[2],
+            hashed_bytes[3],
+        ])
     }
 
     /// Returns the underlying four bytes.
@@ -117,17 +134,21 @@ impl Selector {
         }
     }
 
-    /// Returns the identifier for callable's parent ink! impl namespace argument (if any).
+    /// Returns the effective namespace for callable's parent ink! impl (if any),
+    /// see [`InkImpl::namespace_effective`] doc.
     fn namespace<T>(callable: &T) -> Option<String>
     where
         T: IsInkCallable,
     {
-        utils::ink_arg_by_kind(callable.parent_impl_item()?.syntax(), InkArgKind::Namespace)?
-            .value()?
-            .as_string()
+        InkImpl::cast(callable.parent_impl_item()?.syntax().clone())?.namespace_effective()
     }
 }
 
+/// Formats a selector value as a `0x` prefixed, zero-padded, lowercase hex string (e.g. `0x0000000a`).
+pub fn format_selector(value: u32) -> String {
+    format!("{value:#010x}")
+}
+
 /// An ink! selector argument.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct SelectorArg {
@@ -178,7 +199,7 @@ impl SelectorArg {
 
     /// Converts the value if it's an integer literal (decimal or hexadecimal) into a `u32`.
     pub fn as_u32(&self) -> Option<u32> {
-        self.arg.value()?.as_u32()
+        self.arg.value_as_u32()
     }
 
     /// Returns the text range of the ink! selector argument.
@@ -323,6 +344,26 @@ mod tests {
                 0x3a739109, // First 4-bytes of Blake2b-256 hash of "my_namespace::my_constructor"
                 0xABE89C04, // First 4-bytes of Blake2b-256 hash of "my_namespace::my_message"
             ),
+            // Namespace from the ink! trait definition.
+            (
+                quote_as_str! {
+                    impl MyTrait for MyContract {
+                        #[ink(constructor)]
+                        fn my_constructor() -> Self {}
+
+                        #[ink(message)]
+                        fn my_message(&self) {}
+                    }
+
+                    #[ink::trait_definition(namespace="my_namespace")]
+                    pub trait MyTrait {
+                        #[ink(message)]
+                        fn my_message(&self);
+                    }
+                },
+                0xA5FB88F2, // First 4-bytes of Blake2b-256 hash of "my_namespace::MyTrait::my_constructor"
+                0x84452B5E, // First 4-bytes of Blake2b-256 hash of "my_namespace::MyTrait::my_message"
+            ),
         ] {
             // Parse ink! constructor and ink! message.
             let constructor: Constructor = first_ink_entity_of_type(code);
@@ -1,6 +1,10 @@
 //! ink! storage item IR.
 
-use ra_ap_syntax::ast;
+use blake2::digest::consts::U32;
+use blake2::digest::Digest;
+use blake2::Blake2b;
+use ra_ap_syntax::ast::HasName;
+use ra_ap_syntax::{ast, AstNode};
 
 /// An ink! storage item.
 #[ink_analyzer_macro::entity(macro_kind = StorageItem)]
@@ -14,6 +18,35 @@ impl StorageItem {
     impl_pub_ast_type_getter!(adt, Adt);
 
     impl_pub_ink_arg_getter!(derive_arg, Derive, derive);
+
+    /// Returns the storage key derived from the fully qualified type name of the ink! storage item
+    /// (i.e. the BLAKE-2b 256-bit hash of the `::` separated names of the enclosing modules and the type),
+    /// or `None` if storage key derivation is disabled (i.e. `derive = false`).
+    pub fn storage_key(&self) -> Option<[u8; 32]> {
+        // `derive` defaults to `true`.
+        if self
+            .derive_arg()
+            .is_some_and(|arg| arg.value_as_bool() == Some(false))
+        {
+            return None;
+        }
+
+        let adt = self.adt()?;
+        let mut path_segments: Vec<String> = adt
+            .syntax()
+            .ancestors()
+            .skip(1)
+            .filter_map(ast::Module::cast)
+            .filter_map(|module| module.name().map(|name| name.to_string()))
+            .collect();
+        path_segments.reverse();
+        path_segments.push(adt.name()?.to_string());
+
+        // Computes the BLAKE-2b 256-bit hash of the fully qualified type name.
+        let mut hasher = <Blake2b<U32>>::new();
+        hasher.update(path_segments.join("::").into_bytes());
+        Some(hasher.finalize().into())
+    }
 }
 
 #[cfg(test)]
@@ -54,4 +87,76 @@ mod tests {
             assert!(storage_item.adt().is_some());
         }
     }
+
+    #[test]
+    fn storage_key_works() {
+        for (code, expected_key) in [
+            (
+                quote_as_str! {
+                    #[ink::storage_item]
+                    struct MyStorageItem {
+                    }
+                },
+                Some([
+                    0xbe, 0x80, 0x56, 0xf7, 0x9b, 0xf1, 0x75, 0xc7, 0xee, 0x3e, 0xb3, 0xa6, 0xee,
+                    0x4c, 0x0e, 0x66, 0x09, 0x18, 0x2b, 0xc7, 0xf0, 0x20, 0x0b, 0x99, 0xf5, 0x89,
+                    0x5c, 0x58, 0x0c, 0x50, 0x6c, 0x51,
+                ]),
+            ),
+            (
+                quote_as_str! {
+                    #[ink::storage_item(derive=true)]
+                    enum MyStorageItem {
+                    }
+                },
+                Some([
+                    0xbe, 0x80, 0x56, 0xf7, 0x9b, 0xf1, 0x75, 0xc7, 0xee, 0x3e, 0xb3, 0xa6, 0xee,
+                    0x4c, 0x0e, 0x66, 0x09, 0x18, 0x2b, 0xc7, 0xf0, 0x20, 0x0b, 0x99, 0xf5, 0x89,
+                    0x5c, 0x58, 0x0c, 0x50, 0x6c, 0x51,
+                ]),
+            ),
+            (
+                quote_as_str! {
+                    mod my_mod {
+                        #[ink::storage_item]
+                        struct MyStorageItem {
+                        }
+                    }
+                },
+                Some([
+                    0xd6, 0xe8, 0x2e, 0x7e, 0x39, 0xc7, 0x2b, 0x54, 0x76, 0x65, 0xdb, 0x43, 0x5a,
+                    0x89, 0xab, 0xaf, 0x88, 0xcf, 0x9a, 0x0f, 0x3b, 0x64, 0xf1, 0xd3, 0x3c, 0x50,
+                    0xa4, 0x9d, 0x94, 0xd0, 0xe4, 0xb0,
+                ]),
+            ),
+            (
+                quote_as_str! {
+                    mod a {
+                        mod b {
+                            #[ink::storage_item]
+                            struct MyStorageItem {
+                            }
+                        }
+                    }
+                },
+                Some([
+                    0xc8, 0xf9, 0x78, 0x4b, 0x9f, 0x47, 0x4d, 0x25, 0x19, 0xe1, 0xae, 0xd2, 0xb7,
+                    0xd5, 0x71, 0x82, 0x72, 0x0f, 0xcc, 0x57, 0xd2, 0x64, 0xd0, 0x12, 0x32, 0x3c,
+                    0x25, 0x07, 0xa9, 0xb6, 0x13, 0x7e,
+                ]),
+            ),
+            (
+                quote_as_str! {
+                    #[ink::storage_item(derive=false)]
+                    struct MyStorageItem {
+                    }
+                },
+                None,
+            ),
+        ] {
+            let storage_item: StorageItem = first_ink_entity_of_type(code);
+
+            assert_eq!(storage_item.storage_key(), expected_key, "code: {code}");
+        }
+    }
 }
@@ -1,8 +1,11 @@
 //! ink! trait definition IR.
 
-use ra_ap_syntax::ast;
+use ra_ap_syntax::ast::HasName;
+use ra_ap_syntax::{ast, AstNode};
+use std::collections::HashSet;
 
-use crate::Message;
+use crate::traits::{InkEntity, IsInkFn, IsInkTrait};
+use crate::{InkImpl, Message, Selector};
 
 /// An ink! trait definition.
 #[ink_analyzer_macro::entity(macro_kind = TraitDefinition)]
@@ -20,13 +23,78 @@ impl TraitDefinition {
     impl_pub_ink_arg_getter!(namespace_arg, Namespace, namespace);
 
     impl_pub_ink_arg_getter!(keep_attr_arg, KeepAttr, keep_attr);
+
+    /// Returns the ink! impls (in the same file) that implement the ink! trait definition.
+    pub fn implementors(&self) -> Vec<InkImpl> {
+        let Some(root) = self.syntax().ancestors().last() else {
+            return Vec::new();
+        };
+        root.descendants()
+            .filter(|node| ast::Impl::can_cast(node.kind()))
+            .filter_map(InkImpl::cast)
+            .filter(|ink_impl| ink_impl.trait_definition().as_ref() == Some(self))
+            .collect()
+    }
+
+    /// Returns the ink! messages of the ink! trait definition that have no corresponding method
+    /// in the given ink! impl (returns an empty list if the ink! impl doesn't implement the ink! trait definition).
+    ///
+    /// Messages and methods are matched by their selectors
+    /// (i.e. computed from the namespace, the trait identifier and the method identifier).
+    pub fn unimplemented_messages(&self, impl_block: &InkImpl) -> Vec<&Message> {
+        let Some(impl_item) = impl_block.impl_item() else {
+            return Vec::new();
+        };
+        let is_implementor = impl_block
+            .trait_definition()
+            .is_some_and(|trait_definition| trait_definition.syntax() == self.syntax());
+        if !is_implementor {
+            return Vec::new();
+        }
+
+        let namespace = self
+            .namespace_arg()
+            .and_then(|arg| arg.value().and_then(|value| value.as_string()));
+        let trait_ident = self
+            .trait_item()
+            .and_then(HasName::name)
+            .map(|name| name.to_string());
+        let selector = |fn_item: &ast::Fn| {
+            fn_item.name().map(|name| {
+                Selector::compute_from_parts(
+                    namespace.as_deref(),
+                    trait_ident.as_deref(),
+                    &name.to_string(),
+                )
+            })
+        };
+        let implemented: HashSet<Selector> = impl_item
+            .assoc_item_list()
+            .into_iter()
+            .flat_map(|item_list| item_list.assoc_items())
+            .filter_map(|item| match item {
+                ast::AssocItem::Fn(fn_item) => selector(&fn_item),
+                _ => None,
+            })
+            .collect();
+        self.messages()
+            .iter()
+            .filter(|message| {
+                message
+                    .fn_item()
+                    .and_then(selector)
+                    .is_some_and(|selector| !implemented.contains(&selector))
+            })
+            .collect()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::test_utils::*;
-    use crate::traits::{InkEntity, IsInkTrait};
+    use crate::traits::IsInkTrait;
+    use crate::InkFile;
     use test_utils::quote_as_str;
 
     #[test]
@@ -56,4 +124,166 @@ mod tests {
         // `trait` item exists.
         assert!(trait_definition.trait_item().is_some());
     }
+
+    #[test]
+    fn implementors_works() {
+        for (code, expected_n_implementors) in [
+            // No implementations.
+            (
+                quote_as_str! {
+                    #[ink::trait_definition]
+                    pub trait MyTrait {
+                        #[ink(message)]
+                        fn my_message(&self);
+                    }
+
+                    impl MyContract {
+                        #[ink(message)]
+                        pub fn my_message(&self) {}
+                    }
+                },
+                0,
+            ),
+            // One implementation.
+            (
+                quote_as_str! {
+                    #[ink::trait_definition]
+                    pub trait MyTrait {
+                        #[ink(message)]
+                        fn my_message(&self);
+                    }
+
+                    impl MyTrait for MyContract {
+                        #[ink(message)]
+                        fn my_message(&self) {}
+                    }
+
+                    impl OtherTrait for MyContract {
+                        fn other_method(&self) {}
+                    }
+                },
+                1,
+            ),
+            // Multiple implementations (including in a nested module).
+            (
+                quote_as_str! {
+                    #[ink::trait_definition]
+                    pub trait MyTrait {
+                        #[ink(message)]
+                        fn my_message(&self);
+                    }
+
+                    impl MyTrait for MyContract {
+                        #[ink(message)]
+                        fn my_message(&self) {}
+                    }
+
+                    mod my_contract {
+                        impl super::MyTrait for MyContract {
+                            #[ink(message)]
+                            fn my_message(&self) {}
+                        }
+                    }
+                },
+                2,
+            ),
+        ] {
+            let trait_definition = TraitDefinition::cast(parse_first_syntax_node(code)).unwrap();
+
+            let implementors = trait_definition.implementors();
+            assert_eq!(implementors.len(), expected_n_implementors, "code: {code}");
+            assert!(implementors
+                .iter()
+                .all(|ink_impl| ink_impl.trait_definition().as_ref() == Some(&trait_definition)));
+        }
+    }
+
+    #[test]
+    fn unimplemented_messages_works() {
+        for (impl_code, expected_unimplemented) in [
+            // No methods implemented.
+            (
+                quote_as_str! {
+                    impl MyTrait for MyContract {}
+                },
+                vec!["my_message", "my_message_2", "my_message_3"],
+            ),
+            // 1 method implemented.
+            (
+                quote_as_str! {
+                    impl MyTrait for MyContract {
+                        #[ink(message)]
+                        fn my_message_2(&self) {}
+                    }
+                },
+                vec!["my_message", "my_message_3"],
+            ),
+            // 2 methods implemented (including one without an ink! message attribute).
+            (
+                quote_as_str! {
+                    impl MyTrait for MyContract {
+                        #[ink(message)]
+                        fn my_message(&self) {}
+
+                        fn my_message_3(&mut self) {}
+                    }
+                },
+                vec!["my_message_2"],
+            ),
+            // All methods implemented.
+            (
+                quote_as_str! {
+                    impl MyTrait for MyContract {
+                        #[ink(message)]
+                        fn my_message(&self) {}
+
+                        #[ink(message)]
+                        fn my_message_2(&self) {}
+
+                        #[ink(message)]
+                        fn my_message_3(&mut self) {}
+                    }
+                },
+                vec![],
+            ),
+            // Not an implementation of the ink! trait definition.
+            (
+                quote_as_str! {
+                    impl MyContract {
+                        #[ink(message)]
+                        pub fn my_message(&self) {}
+                    }
+                },
+                vec![],
+            ),
+        ] {
+            let code = format!(
+                "{}\n{impl_code}",
+                quote_as_str! {
+                    #[ink::trait_definition(namespace="my_namespace")]
+                    pub trait MyTrait {
+                        #[ink(message)]
+                        fn my_message(&self);
+
+                        #[ink(message)]
+                        fn my_message_2(&self);
+
+                        #[ink(message)]
+                        fn my_message_3(&mut self);
+                    }
+                }
+            );
+            let file = InkFile::parse(&code);
+            let trait_definition = &file.trait_definitions()[0];
+            let ink_impl = file.syntax().descendants().find_map(InkImpl::cast).unwrap();
+
+            let unimplemented: Vec<String> = trait_definition
+                .unimplemented_messages(&ink_impl)
+                .iter()
+                .filter_map(|message| message.fn_item()?.name())
+                .map(|name| name.to_string())
+                .collect();
+            assert_eq!(unimplemented, expected_unimplemented, "code: {code}");
+        }
+    }
 }
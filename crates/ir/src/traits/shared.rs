@@ -24,6 +24,25 @@ pub trait IsInkCallable: IsInkFn {
     {
         Selector::compose(self)
     }
+
+    /// Returns the selector bytes computed from the callable's name
+    /// (and the namespace and trait name of its parent ink! impl, if any),
+    /// ignoring any manually provided selector.
+    ///
+    /// Returns `None` for wildcard selectors (i.e. `selector=_`).
+    fn computed_selector(&self) -> Option<[u8; 4]>
+    where
+        Self: Sized,
+    {
+        if self
+            .selector_arg()
+            .as_ref()
+            .map_or(false, SelectorArg::is_wildcard)
+        {
+            return None;
+        }
+        Selector::compute(self).as_ref().map(Selector::to_bytes)
+    }
 }
 
 /// Implemented by ink! entities that accept an `Environment` configuration
@@ -165,7 +165,7 @@ pub fn resolve_qualifier(path: &ast::Path, ref_node: &SyntaxNode) -> Option<Synt
     // Resolves next child module.
     let resolve_next_child_module = |root: &SyntaxNode, name: &ast::NameRef| {
         let resolve_child = || {
-            root.children().find(|it| {
+            resolve_item_list_root(root).children().find(|it| {
                 ast::Module::can_cast(it.kind())
                     && ast::Module::cast(it.clone())
                         .and_then(|module| module.name())
@@ -230,9 +230,16 @@ pub fn resolve_qualifier(path: &ast::Path, ref_node: &SyntaxNode) -> Option<Synt
 
     // Resolves the remaining qualifier segments (if any).
     while let Some((node, segment)) = resolution_root_option.as_ref().zip(path_segments.next()) {
-        resolution_root_option = segment
-            .name_ref()
-            .and_then(|name| resolve_next_child_module(node, &name));
+        resolution_root_option = if segment.super_token().is_some() {
+            // Resolves parent module (e.g. for `super::super::MyItem`).
+            ast::Module::can_cast(node.kind())
+                .then(|| node.parent().as_ref().and_then(resolve_current_module))
+                .flatten()
+        } else {
+            segment
+                .name_ref()
+                .and_then(|name| resolve_next_child_module(node, &name))
+        };
     }
 
     resolution_root_option
@@ -305,6 +312,38 @@ pub fn path_to_string(path: &ast::Path) -> String {
     remove_whitespace(&path.to_string())
 }
 
+/// Returns the text of the syntax node with trivia (i.e. whitespace and comments) removed.
+pub fn strip_trivia(node: &SyntaxNode) -> String {
+    node.descendants_with_tokens()
+        .filter_map(|elem| elem.into_token())
+        .filter(|token| !token.kind().is_trivia())
+        .map(|token| token.text().to_string())
+        .collect()
+}
+
+/// Returns the names of the parameters (excluding the `self` parameter) of a function.
+///
+/// **NOTE**: Uses the source text for parameter patterns that aren't simple identifiers
+/// (e.g. destructuring patterns), and an `argN` placeholder for parameters without a pattern.
+pub fn fn_param_names(fn_item: &ast::Fn) -> Vec<String> {
+    fn_item
+        .param_list()
+        .map(|param_list| {
+            param_list
+                .params()
+                .enumerate()
+                .map(|(idx, param)| match param.pat() {
+                    Some(ast::Pat::IdentPat(ident_pat)) => ident_pat
+                        .name()
+                        .map_or(format!("arg{idx}"), |name| name.to_string()),
+                    Some(pat) => remove_whitespace(&pat.to_string()),
+                    None => format!("arg{idx}"),
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
 // Returns the item list syntax node for the given syntax node.
 // NOTE: defaults to return the syntax node itself (item lists that can contain use statements - for now).
 fn resolve_item_list_root(node: &SyntaxNode) -> SyntaxNode {
@@ -509,6 +548,58 @@ mod tests {
                 },
                 quote_as_str! { ::my_items::MyItem },
             ),
+            // Nested modules.
+            (
+                quote_as_str! {
+                    mod my_items {
+                        mod my_nested_items {
+                            #item
+                        }
+                    }
+                },
+                quote_as_str! { my_items::my_nested_items::MyItem },
+            ),
+            (
+                quote_as_str! {
+                    mod my_items {
+                        mod my_nested_items {
+                            #item
+                        }
+                    }
+
+                    mod #ref_name {
+                    }
+                },
+                quote_as_str! { crate::my_items::my_nested_items::MyItem },
+            ),
+            (
+                quote_as_str! {
+                    mod my_items {
+                        mod my_nested_items {
+                            #item
+                        }
+                    }
+
+                    mod my_refs {
+                        mod #ref_name {
+                        }
+                    }
+                },
+                quote_as_str! { super::super::my_items::my_nested_items::MyItem },
+            ),
+            (
+                quote_as_str! {
+                    mod my_items {
+                        #item
+                    }
+
+                    mod my_refs {
+                        mod #ref_name {
+                        }
+                    }
+                },
+                quote_as_str! { self::super::super::my_items::MyItem },
+            ),
             // Scoped paths.
             (
                 quote_as_str! {
@@ -957,4 +1048,19 @@ mod tests {
             );
         }
     }
+    #[test]
+    fn strip_trivia_works() {
+        for (code, expected) in [
+            ("(&self, value: u8)", "(&self,value:u8)"),
+            (
+                "( &mut self , value : u8 /* value */ )",
+                "(&mutself,value:u8)",
+            ),
+            ("(\n    // `self` receiver.\n    &self,\n)", "(&self,)"),
+        ] {
+            let param_list: ast::ParamList =
+                parse_first_ast_node_of_type(&format!("fn my_fn{code} {{}}"));
+            assert_eq!(strip_trivia(param_list.syntax()), expected, "code: {code}");
+        }
+    }
 }
@@ -151,6 +151,17 @@ where
         .and_then(|parent| ink_attrs(parent.syntax()).find_map(ink_attr_to_entity))
 }
 
+/// Returns the syntax node's closest ancestor ink! entity of IR type `T` (if any).
+///
+/// **NOTE**: Unlike [`ink_parent`], ink! entities of other IR types
+/// (and non-ink! items) between the node and the ancestor are skipped.
+pub fn ink_ancestor_of_kind<T>(node: &SyntaxNode) -> Option<T>
+where
+    T: InkEntity,
+{
+    ink_ancestors(node).next()
+}
+
 /// Returns the syntax node's ancestor ink! entities of IR type `T`.
 pub fn ink_ancestors<'a, T>(node: &'a SyntaxNode) -> impl Iterator<Item = T> + 'a
 where
@@ -258,7 +269,9 @@ where
 mod tests {
     use super::*;
     use crate::test_utils::*;
-    use test_utils::quote_as_str;
+    use crate::{Contract, Storage};
+    use ra_ap_syntax::ast::HasName;
+    use test_utils::{quote_as_str, quote_as_string};
 
     #[test]
     fn ink_attrs_works() {
@@ -460,6 +473,45 @@ mod tests {
         }
     }
 
+    #[test]
+    fn ink_ancestors_works() {
+        let code = quote_as_string! {
+            #[ink::contract]
+            mod outer_contract {
+                #[ink::contract]
+                mod inner_contract {
+                    #[ink(impl)]
+                    impl MyContract {
+                        #[ink(message)]
+                        pub fn my_message(&self) {}
+                    }
+                }
+            }
+        };
+        let fn_item: ast::Fn = parse_first_ast_node_of_type(&code);
+        let node = fn_item.syntax();
+
+        // Collects ancestors at different depths (closest first).
+        let contracts: Vec<Contract> = ink_ancestors(node).collect();
+        assert_eq!(contracts.len(), 2);
+        assert_eq!(
+            contracts
+                .iter()
+                .filter_map(|contract| contract.module()?.name())
+                .map(|name| name.to_string())
+                .collect::<Vec<_>>(),
+            vec!["inner_contract", "outer_contract"]
+        );
+
+        // Closest ancestor skips the `impl` item, while parent doesn't.
+        assert_eq!(
+            ink_ancestor_of_kind::<Contract>(node).as_ref(),
+            contracts.first()
+        );
+        assert!(ink_parent::<Contract>(node).is_none());
+        assert!(ink_ancestor_of_kind::<Storage>(node).is_none());
+    }
+
     #[test]
     fn ink_attrs_closest_ancestors_works() {
         for (node, n_attrs) in [
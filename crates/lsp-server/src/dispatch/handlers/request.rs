@@ -366,7 +366,7 @@ mod tests {
         }
         .unwrap()
         .title
-        .contains("Add ink! contract"));
+        .contains("Add `#[ink::contract]`"));
     }
 
     #[test]
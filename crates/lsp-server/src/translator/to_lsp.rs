@@ -58,6 +58,7 @@ pub fn diagnostic(
         severity: Some(match diagnostic.severity {
             ink_analyzer::Severity::Error => lsp_types::DiagnosticSeverity::ERROR,
             ink_analyzer::Severity::Warning => lsp_types::DiagnosticSeverity::WARNING,
+            ink_analyzer::Severity::Hint => lsp_types::DiagnosticSeverity::HINT,
         }),
         ..Default::default()
     })
@@ -172,8 +173,14 @@ pub fn inlay_hint(
 ) -> Option<lsp_types::InlayHint> {
     position(hint.position, context).map(|position| lsp_types::InlayHint {
         position,
-        label: lsp_types::InlayHintLabel::String(format!(": {}", hint.label)),
-        kind: Some(lsp_types::InlayHintKind::TYPE),
+        label: lsp_types::InlayHintLabel::String(match hint.kind {
+            ink_analyzer::InlayHintKind::Type => format!(": {}", hint.label),
+            ink_analyzer::InlayHintKind::Selector => hint.label,
+        }),
+        kind: match hint.kind {
+            ink_analyzer::InlayHintKind::Type => Some(lsp_types::InlayHintKind::TYPE),
+            ink_analyzer::InlayHintKind::Selector => None,
+        },
         text_edits: None,
         tooltip: hint.detail.map(lsp_types::InlayHintTooltip::String),
         padding_left: Some(true),
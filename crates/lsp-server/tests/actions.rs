@@ -165,7 +165,7 @@ fn verify_actions(test_groups: impl Iterator<Item = TestGroup>, quickfixes_only:
                         .map(|edits| (
                             PartialMatchStr::from(code_action.title.as_str()),
                             edits
-                                .into_iter()
+                                .iter()
                                 .map(|edit| (
                                     PartialMatchStr::from(edit.new_text.as_str()),
                                     edit.range
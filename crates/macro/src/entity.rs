@@ -64,6 +64,16 @@ pub fn impl_entity(args: TokenStream, item: TokenStream) -> Result<TokenStream,
                             #field_name: #field_type
                         });
 
+                        // Fields with a `default` initializer don't represent ink! entity descendants,
+                        // so they're initialized with their default value and don't get getters.
+                        let field_config = FieldConfig::build(field)?;
+                        if let FieldConfig::DefaultValue = field_config {
+                            initializers.push(quote! {
+                                #field_name: ::core::default::Default::default()
+                            });
+                            continue;
+                        }
+
                         // Creates initializer, return type and expressions for getters.
                         match &field_type {
                             syn::Type::Path(type_path) => {
@@ -79,7 +89,6 @@ pub fn impl_entity(args: TokenStream, item: TokenStream) -> Result<TokenStream,
                                     });
                                 match field_type_info {
                                     Some((base_type, base_field_type)) => {
-                                        let field_config = FieldConfig::build(field)?;
                                         let initializer = match &field_config {
                                             FieldConfig::Closest => quote! {
                                                 #ir_crate_path::ink_closest_descendants(&root_node)
@@ -100,6 +109,7 @@ pub fn impl_entity(args: TokenStream, item: TokenStream) -> Result<TokenStream,
                                             FieldConfig::Call(fn_call) => quote! {
                                                 #fn_call(&root_node)
                                             },
+                                            FieldConfig::DefaultValue => unreachable!(),
                                         };
 
                                         let result = if base_type == "Vec" {
@@ -305,7 +315,7 @@ const ITEM_KIND_ERROR: &str =
 const ARGUMENT_ERROR: &str =
     "`#[ink_analyzer_macro::entity(..)]` takes only a single argument of either `ast`, `macro_kind`, `arg_kind` or `fn`.";
 const FIELD_ARGUMENT_ERROR: &str =
-    "`#[initializer(..)]` takes only a single argument of either `closest`, `peek_macro`, `peek_arg` or `fn`.";
+    "`#[initializer(..)]` takes only a single argument of either `closest`, `peek_macro`, `peek_arg`, `fn` or `default`.";
 const FIELD_TYPE_ERROR: &str =
     "Field types must be of the form `Vec<T>` or `Option<T>` where `T: InkEntity`";
 
@@ -328,6 +338,7 @@ enum FieldConfig {
     PeekMacro(syn::Ident),
     PeekArg(syn::Ident),
     Call(syn::Path),
+    DefaultValue,
 }
 
 impl FieldConfig {
@@ -340,14 +351,16 @@ impl FieldConfig {
             field_config.peek_macro,
             field_config.peek_arg,
             field_config.call,
+            field_config.default.is_present(),
         ) {
             // Default.
-            (false, None, None, None) => Ok(Self::default()),
+            (false, None, None, None, false) => Ok(Self::default()),
             // Exactly one argument set.
-            (true, None, None, None) => Ok(Self::Closest),
-            (false, Some(name), None, None) => Ok(Self::PeekMacro(name)),
-            (false, None, Some(name), None) => Ok(Self::PeekArg(name)),
-            (false, None, None, Some(path)) => Ok(Self::Call(path)),
+            (true, None, None, None, false) => Ok(Self::Closest),
+            (false, Some(name), None, None, false) => Ok(Self::PeekMacro(name)),
+            (false, None, Some(name), None, false) => Ok(Self::PeekArg(name)),
+            (false, None, None, Some(path), false) => Ok(Self::Call(path)),
+            (false, None, None, None, true) => Ok(Self::DefaultValue),
             // More than one argument set.
             _ => Err(Error::Syn(syn::Error::new(
                 field.span(),
@@ -364,6 +377,7 @@ struct FieldConfigInternal {
     peek_macro: Option<syn::Ident>,
     peek_arg: Option<syn::Ident>,
     call: Option<syn::Path>,
+    default: darling::util::Flag,
 }
 
 #[cfg(test)]
@@ -456,6 +470,8 @@ mod tests {
                 // For demo purpose.
                 #[initializer(peek_macro = TraitDefinition)]
                 tests: Vec<ink_analyzer_ir::InkTest>,
+                #[initializer(default)]
+                cache: std::sync::OnceLock<u8>,
             }
         };
         let result = impl_entity(quote! {}, item);
@@ -529,6 +545,9 @@ mod tests {
             quote! {
                 closest, call = self::init
             },
+            quote! {
+                closest, default
+            },
         ] {
             let result = impl_entity(
                 quote! {},
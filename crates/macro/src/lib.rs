@@ -72,6 +72,9 @@ use proc_macro::TokenStream;
 /// - `call = F` - collects all ink! entities returned by the function `F`
 ///                where `F: Fn(&SyntaxNode) -> impl Iterator<Item = T>` and `T: InkEntity`
 ///                and `T` also matches the ink! entity type for the field.
+/// - `default` - initializes the field with its default value (i.e. `Default::default()`).
+///               Fields with this strategy can be of any type that implements `Default`
+///               (e.g. a cache for lazily computed values), and no getter is generated for them.
 ///
 /// # Example
 /// ```
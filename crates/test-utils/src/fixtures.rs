@@ -1862,18 +1862,31 @@ pub fn diagnostics_fixtures() -> Vec<TestGroup> {
                     }]),
                     params: None,
                     results: TestCaseResults::Diagnostic {
-                        n: 1,
-                        quickfixes: vec![(
-                            vec![TestResultAction {
-                                label: "Remove",
-                                edits: vec![TestResultTextRange {
-                                    text: "",
-                                    start_pat: Some("<-, payable"),
-                                    end_pat: Some(", payable"),
+                        n: 2,
+                        quickfixes: vec![
+                            (
+                                vec![TestResultAction {
+                                    label: "Remove",
+                                    edits: vec![TestResultTextRange {
+                                        text: "",
+                                        start_pat: Some("<-, payable"),
+                                        end_pat: Some(", payable"),
+                                    }],
                                 }],
-                            }],
-                            Some(", payable"),
-                        )],
+                                Some(", payable"),
+                            ),
+                            (
+                                vec![TestResultAction {
+                                    label: "Change to `&mut self`",
+                                    edits: vec![TestResultTextRange {
+                                        text: "mut ",
+                                        start_pat: Some("<-self) -> Balance {"),
+                                        end_pat: Some("<-self) -> Balance {"),
+                                    }],
+                                }],
+                                Some("<-&self) -> Balance {"),
+                            ),
+                        ],
                     },
                 },
             ],
@@ -2067,6 +2080,44 @@ pub fn completions_fixtures() -> Vec<TestGroup> {
                         },
                     ]),
                 },
+                TestCase {
+                    // Defines a custom environment and sets the `env` argument without a value.
+                    modifications: Some(vec![TestCaseModification {
+                        start_pat: Some("<-#[ink::contract]"),
+                        end_pat: Some("#[ink::contract]"),
+                        replacement: "#[derive(Clone)]\npub struct MyEnvironment;\n\nimpl ink::env::Environment for MyEnvironment {}\n\n#[ink::contract(env=)]",
+                    }]),
+                    params: Some(TestCaseParams::Completion(TestParamsOffsetOnly {
+                        pat: Some("#[ink::contract(env="),
+                    })),
+                    results: TestCaseResults::Completion(vec![
+                        TestResultTextRange {
+                            text: "crate::MyEnvironment",
+                            start_pat: Some("#[ink::contract(env="),
+                            end_pat: Some("#[ink::contract(env="),
+                        },
+                        TestResultTextRange {
+                            text: "ink::env::DefaultEnvironment",
+                            start_pat: Some("#[ink::contract(env="),
+                            end_pat: Some("#[ink::contract(env="),
+                        },
+                    ]),
+                },
+                TestCase {
+                    modifications: Some(vec![TestCaseModification {
+                        start_pat: Some("<-#[ink::contract]"),
+                        end_pat: Some("#[ink::contract]"),
+                        replacement: "#[derive(Clone)]\npub struct MyEnvironment;\n\nimpl ink::env::Environment for MyEnvironment {}\n\n#[ink::contract(env = crate::My)]",
+                    }]),
+                    params: Some(TestCaseParams::Completion(TestParamsOffsetOnly {
+                        pat: Some("#[ink::contract(env = crate::My"),
+                    })),
+                    results: TestCaseResults::Completion(vec![TestResultTextRange {
+                        text: "crate::MyEnvironment",
+                        start_pat: Some("<-crate::My)"),
+                        end_pat: Some("#[ink::contract(env = crate::My"),
+                    }]),
+                },
                 TestCase {
                     modifications: Some(vec![TestCaseModification {
                         start_pat: Some("<-#[ink(storage)]"),
@@ -2290,7 +2341,7 @@ pub fn completions_fixtures() -> Vec<TestGroup> {
                             end_pat: Some("#[ink::trait_definition("),
                         },
                         TestResultTextRange {
-                            text: r#"namespace="my_namespace""#,
+                            text: r#"namespace="BaseErc20""#,
                             start_pat: Some("#[ink::trait_definition("),
                             end_pat: Some("#[ink::trait_definition("),
                         },
@@ -3755,7 +3806,60 @@ pub fn inlay_hints_fixtures() -> Vec<TestGroup> {
                         end_pat: Some("->"),
                     }))),
                     // Describes the expected results.
-                    results: TestCaseResults::InlayHints(vec![]),
+                    // Expects the computed selector inlay hints for all ink! constructors and ink! messages
+                    // positioned at the end of each `fn` signature,
+                    // with the `fn` signature (excluding the `pub fn` prefix) being the text range the inlay hint applies to.
+                    results: TestCaseResults::InlayHints(vec![
+                        // new.
+                        TestResultTextOffsetRange {
+                            text: "/* selector: 0x9bae9d5e */",
+                            pos_pat: Some("pub fn new(total_supply: Balance) -> Self"),
+                            range_start_pat: Some("<-new(total_supply: Balance) -> Self"),
+                            range_end_pat: Some("pub fn new(total_supply: Balance) -> Self"),
+                        },
+                        // total_supply.
+                        TestResultTextOffsetRange {
+                            text: "/* selector: 0xdb6375a8 */",
+                            pos_pat: Some("pub fn total_supply(&self) -> Balance"),
+                            range_start_pat: Some("<-total_supply(&self) -> Balance"),
+                            range_end_pat: Some("pub fn total_supply(&self) -> Balance"),
+                        },
+                        // balance_of.
+                        TestResultTextOffsetRange {
+                            text: "/* selector: 0x0f755a56 */",
+                            pos_pat: Some("pub fn balance_of(&self, owner: AccountId) -> Balance"),
+                            range_start_pat: Some("<-balance_of(&self, owner: AccountId) -> Balance"),
+                            range_end_pat: Some("pub fn balance_of(&self, owner: AccountId) -> Balance"),
+                        },
+                        // allowance.
+                        TestResultTextOffsetRange {
+                            text: "/* selector: 0x6a00165e */",
+                            pos_pat: Some("pub fn allowance(&self, owner: AccountId, spender: AccountId) -> Balance"),
+                            range_start_pat: Some("<-allowance(&self, owner: AccountId, spender: AccountId) -> Balance"),
+                            range_end_pat: Some("pub fn allowance(&self, owner: AccountId, spender: AccountId) -> Balance"),
+                        },
+                        // transfer.
+                        TestResultTextOffsetRange {
+                            text: "/* selector: 0x84a15da1 */",
+                            pos_pat: Some("pub fn transfer(&mut self, to: AccountId, value: Balance) -> Result<()>"),
+                            range_start_pat: Some("<-transfer(&mut self, to: AccountId, value: Balance) -> Result<()>"),
+                            range_end_pat: Some("pub fn transfer(&mut self, to: AccountId, value: Balance) -> Result<()>"),
+                        },
+                        // approve.
+                        TestResultTextOffsetRange {
+                            text: "/* selector: 0x681266a0 */",
+                            pos_pat: Some("pub fn approve(&mut self, spender: AccountId, value: Balance) -> Result<()>"),
+                            range_start_pat: Some("<-approve(&mut self, spender: AccountId, value: Balance) -> Result<()>"),
+                            range_end_pat: Some("pub fn approve(&mut self, spender: AccountId, value: Balance) -> Result<()>"),
+                        },
+                        // transfer_from.
+                        TestResultTextOffsetRange {
+                            text: "/* selector: 0x0b396f18 */",
+                            pos_pat: Some("to: AccountId,\n            value: Balance,\n        ) -> Result<()>"),
+                            range_start_pat: Some("<-transfer_from("),
+                            range_end_pat: Some("to: AccountId,\n            value: Balance,\n        ) -> Result<()>"),
+                        },
+                    ]),
                 },
                 TestCase {
                     // Replaces `#[ink::contract]` with
@@ -3768,7 +3872,11 @@ pub fn inlay_hints_fixtures() -> Vec<TestGroup> {
                         env=MyEnvironment, keep_attr=\"foo,bar\"\
                         )]",
                     }]),
-                    params: Some(TestCaseParams::InlayHints(None)),
+                    // Sets the selection range to span the ink! contract attribute.
+                    params: Some(TestCaseParams::InlayHints(Some(TestParamsRangeOnly {
+                        start_pat: Some("<-#[ink::contract("),
+                        end_pat: Some("keep_attr=\"foo,bar\")]"),
+                    }))),
                     // Expects the inlay hints for the `env` and `keep_attr`
                     // ink! attribute arguments positioned at the end of each of argument's name,
                     // with the argument's name being the text range the inlay hint applies to.
@@ -3795,7 +3903,11 @@ pub fn inlay_hints_fixtures() -> Vec<TestGroup> {
                         end_pat: Some("#[ink(message)]"),
                         replacement: "#[ink(message, selector=_)]",
                     }]),
-                    params: Some(TestCaseParams::InlayHints(None)),
+                    // Sets the selection range to span the modified ink! message.
+                    params: Some(TestCaseParams::InlayHints(Some(TestParamsRangeOnly {
+                        start_pat: Some("<-#[ink(message, selector=_)]"),
+                        end_pat: Some("pub fn total_supply(&self) -> Balance"),
+                    }))),
                     results: TestCaseResults::InlayHints(vec![
                         // selector.
                         TestResultTextOffsetRange {
@@ -3804,6 +3916,13 @@ pub fn inlay_hints_fixtures() -> Vec<TestGroup> {
                             range_start_pat: Some("<-selector=_"),
                             range_end_pat: Some("<-=_"),
                         },
+                        // total_supply.
+                        TestResultTextOffsetRange {
+                            text: "/* selector: wildcard */",
+                            pos_pat: Some("pub fn total_supply(&self) -> Balance"),
+                            range_start_pat: Some("<-total_supply(&self) -> Balance"),
+                            range_end_pat: Some("pub fn total_supply(&self) -> Balance"),
+                        },
                     ]),
                 },
                 TestCase {
@@ -3816,7 +3935,11 @@ pub fn inlay_hints_fixtures() -> Vec<TestGroup> {
                         keep_attr=\"foo,bar\"\
                         )]",
                     }]),
-                    params: Some(TestCaseParams::InlayHints(None)),
+                    // Sets the selection range to span the ink! e2e test attribute.
+                    params: Some(TestCaseParams::InlayHints(Some(TestParamsRangeOnly {
+                        start_pat: Some("<-#[ink_e2e::test("),
+                        end_pat: Some("keep_attr=\"foo,bar\")]"),
+                    }))),
                     results: TestCaseResults::InlayHints(vec![
                         // additional_contracts.
                         TestResultTextOffsetRange {
@@ -3875,7 +3998,59 @@ pub fn inlay_hints_fixtures() -> Vec<TestGroup> {
                 TestCase {
                     modifications: None,
                     params: Some(TestCaseParams::InlayHints(None)),
-                    results: TestCaseResults::InlayHints(vec![]),
+                    // Expects computed selector inlay hints for ink! constructors and ink! messages in `impl` blocks
+                    // (i.e. not for ink! messages in the ink! trait definition).
+                    results: TestCaseResults::InlayHints(vec![
+                        // new.
+                        TestResultTextOffsetRange {
+                            text: "/* selector: 0x9bae9d5e */",
+                            pos_pat: Some("pub fn new(total_supply: Balance) -> Self"),
+                            range_start_pat: Some("<-new(total_supply: Balance) -> Self"),
+                            range_end_pat: Some("pub fn new(total_supply: Balance) -> Self"),
+                        },
+                        // total_supply.
+                        TestResultTextOffsetRange {
+                            text: "/* selector: 0x8244a1ad */",
+                            pos_pat: Some("<- {\n            self.total_supply\n"),
+                            range_start_pat: Some("<-total_supply(&self) -> Balance {"),
+                            range_end_pat: Some("<- {\n            self.total_supply\n"),
+                        },
+                        // balance_of.
+                        TestResultTextOffsetRange {
+                            text: "/* selector: 0x933ae3c8 */",
+                            pos_pat: Some("<- {\n            self.balance_of_impl(&owner)"),
+                            range_start_pat: Some("<-balance_of(&self, owner: AccountId) -> Balance {"),
+                            range_end_pat: Some("<- {\n            self.balance_of_impl(&owner)"),
+                        },
+                        // allowance.
+                        TestResultTextOffsetRange {
+                            text: "/* selector: 0x74a27ac8 */",
+                            pos_pat: Some("<- {\n            self.allowance_impl(&owner, &spender)"),
+                            range_start_pat: Some("<-allowance(&self, owner: AccountId, spender: AccountId) -> Balance {"),
+                            range_end_pat: Some("<- {\n            self.allowance_impl(&owner, &spender)"),
+                        },
+                        // transfer.
+                        TestResultTextOffsetRange {
+                            text: "/* selector: 0xfa9833a3 */",
+                            pos_pat: Some("<- {\n            let from = self.env().caller();"),
+                            range_start_pat: Some("<-transfer(&mut self, to: AccountId, value: Balance) -> Result<()> {"),
+                            range_end_pat: Some("<- {\n            let from = self.env().caller();"),
+                        },
+                        // approve.
+                        TestResultTextOffsetRange {
+                            text: "/* selector: 0x922e291f */",
+                            pos_pat: Some("<- {\n            let owner = self.env().caller();"),
+                            range_start_pat: Some("<-approve(&mut self, spender: AccountId, value: Balance) -> Result<()> {"),
+                            range_end_pat: Some("<- {\n            let owner = self.env().caller();"),
+                        },
+                        // transfer_from.
+                        TestResultTextOffsetRange {
+                            text: "/* selector: 0x839f0263 */",
+                            pos_pat: Some("<- {\n            let caller = self.env().caller();"),
+                            range_start_pat: Some("<-transfer_from(\n            &mut self,\n            from: AccountId,\n            to: AccountId,\n            value: Balance,\n        ) -> Result<()> {"),
+                            range_end_pat: Some("<- {\n            let caller = self.env().caller();"),
+                        },
+                    ]),
                 },
                 TestCase {
                     modifications: Some(vec![TestCaseModification {
@@ -3886,7 +4061,10 @@ pub fn inlay_hints_fixtures() -> Vec<TestGroup> {
                         keep_attr=\"foo,bar\"\
                         )]",
                     }]),
-                    params: Some(TestCaseParams::InlayHints(None)),
+                    params: Some(TestCaseParams::InlayHints(Some(TestParamsRangeOnly {
+                        start_pat: Some("<-#[ink::trait_definition("),
+                        end_pat: Some("keep_attr=\"foo,bar\")]"),
+                    }))),
                     results: TestCaseResults::InlayHints(vec![
                         // namespace.
                         TestResultTextOffsetRange {
@@ -3998,6 +4176,13 @@ pub fn inlay_hints_fixtures() -> Vec<TestGroup> {
                         range_start_pat: Some("#[ink::contract("),
                         range_end_pat: Some("#[ink::contract(env"),
                     },
+                    // constructor.
+                    TestResultTextOffsetRange {
+                        text: "/* selector: 0x9bae9d5e */",
+                        pos_pat: Some("pub fn new() -> Self"),
+                        range_start_pat: Some("<-new() -> Self"),
+                        range_end_pat: Some("pub fn new() -> Self"),
+                    },
                     // selectors.
                     TestResultTextOffsetRange {
                         text: "u32 | _",
@@ -4088,6 +4273,34 @@ pub fn inlay_hints_fixtures() -> Vec<TestGroup> {
                         range_start_pat: Some("#[ink::contract("),
                         range_end_pat: Some("#[ink::contract(env"),
                     },
+                    // new.
+                    TestResultTextOffsetRange {
+                        text: "/* selector: 0x9bae9d5e */",
+                        pos_pat: Some("pub fn new(init_value: [u8; 32]) -> Self"),
+                        range_start_pat: Some("<-new(init_value: [u8; 32]) -> Self"),
+                        range_end_pat: Some("pub fn new(init_value: [u8; 32]) -> Self"),
+                    },
+                    // new_default.
+                    TestResultTextOffsetRange {
+                        text: "/* selector: 0x61ef7e3e */",
+                        pos_pat: Some("pub fn new_default() -> Self"),
+                        range_start_pat: Some("<-new_default() -> Self"),
+                        range_end_pat: Some("pub fn new_default() -> Self"),
+                    },
+                    // update.
+                    TestResultTextOffsetRange {
+                        text: "/* selector: 0x5f234f5d */",
+                        pos_pat: Some("pub fn update(&mut self, subject: [u8; 32]) -> Result<(), RandomReadErr>"),
+                        range_start_pat: Some("<-update(&mut self, subject: [u8; 32]) -> Result<(), RandomReadErr>"),
+                        range_end_pat: Some("pub fn update(&mut self, subject: [u8; 32]) -> Result<(), RandomReadErr>"),
+                    },
+                    // get.
+                    TestResultTextOffsetRange {
+                        text: "/* selector: 0x2f865bd9 */",
+                        pos_pat: Some("pub fn get(&self) -> [u8; 32]"),
+                        range_start_pat: Some("<-get(&self) -> [u8; 32]"),
+                        range_end_pat: Some("pub fn get(&self) -> [u8; 32]"),
+                    },
                 ]),
             }],
         },